@@ -0,0 +1,49 @@
+// Soft cloud sprite: unlit textured quad that fades where it intersects scene
+// geometry (depth prepass) and as the camera approaches or enters it.
+
+#import bevy_pbr::{
+    pbr_fragment::pbr_input_from_standard_material,
+    forward_io::{VertexOutput, FragmentOutput},
+    pbr_functions::main_pass_post_lighting_processing,
+    view_transformations::depth_ndc_to_view_z,
+}
+
+#ifdef DEPTH_PREPASS
+#import bevy_pbr::prepass_utils::prepass_depth
+#endif
+
+struct CloudFade {
+    softness: f32,
+    near_fade: f32,
+}
+
+@group(2) @binding(100) var<uniform> cloud_fade: CloudFade;
+
+@fragment
+fn fragment(
+    in: VertexOutput,
+    @builtin(front_facing) is_front: bool,
+) -> FragmentOutput {
+    let pbr_input = pbr_input_from_standard_material(in, is_front);
+    var color = pbr_input.material.base_color;
+
+    // Distance from the camera along the view axis (positive, meters)
+    let sprite_depth = -depth_ndc_to_view_z(in.position.z);
+
+    // Fade out before the camera reaches the sprite so flying through a cloud
+    // never shows a hard quad edge sweeping across the screen
+    var fade = smoothstep(cloud_fade.near_fade * 0.25, cloud_fade.near_fade, sprite_depth);
+
+#ifdef DEPTH_PREPASS
+    // Soft intersection with terrain and anything else opaque behind the sprite
+    let scene_ndc = max(prepass_depth(in.position, 0u), 1e-7);
+    let scene_depth = -depth_ndc_to_view_z(scene_ndc);
+    fade *= clamp((scene_depth - sprite_depth) / cloud_fade.softness, 0.0, 1.0);
+#endif
+
+    color.a *= fade;
+
+    var out: FragmentOutput;
+    out.color = main_pass_post_lighting_processing(pbr_input, color);
+    return out;
+}
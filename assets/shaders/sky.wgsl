@@ -0,0 +1,191 @@
+// Procedural sky: single-scattering Rayleigh + Mie atmosphere around a spherical
+// planet, plus sun disc and stars. The dome is forced to the far plane so it is
+// drawn behind all scene geometry.
+
+#import bevy_pbr::{
+    mesh_functions::{get_world_from_local, mesh_position_local_to_world},
+    mesh_view_bindings::view,
+    view_transformations::position_world_to_clip,
+}
+
+struct Sky {
+    sun: vec4<f32>,
+    horizon_color: vec4<f32>,
+    params: vec4<f32>,
+}
+
+@group(2) @binding(0) var<uniform> sky: Sky;
+
+const PI: f32 = 3.14159265;
+const EARTH_RADIUS: f32 = 6371e3;
+const ATMOSPHERE_RADIUS: f32 = 6471e3;
+const RAYLEIGH_BETA: vec3<f32> = vec3<f32>(5.5e-6, 13.0e-6, 22.4e-6);
+const MIE_BETA: f32 = 21e-6;
+const RAYLEIGH_HEIGHT: f32 = 8e3;
+const MIE_HEIGHT: f32 = 1.2e3;
+const MIE_G: f32 = 0.758;
+const SUN_INTENSITY: f32 = 22.0;
+const PRIMARY_STEPS: i32 = 16;
+const LIGHT_STEPS: i32 = 8;
+const SUN_ANGULAR_RADIUS: f32 = 0.02;
+
+struct VertexInput {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) world_direction: vec3<f32>,
+};
+
+@vertex
+fn vertex(in: VertexInput) -> VertexOutput {
+    let world_from_local = get_world_from_local(in.instance_index);
+    let world_position = mesh_position_local_to_world(world_from_local, vec4<f32>(in.position, 1.0));
+
+    var out: VertexOutput;
+    out.clip_position = position_world_to_clip(world_position.xyz);
+    // Reverse-Z: depth 0 is infinitely far, so only pixels nothing else covered pass
+    out.clip_position.z = 0.0;
+    out.world_direction = world_position.xyz - view.world_position;
+    return out;
+}
+
+// Distances (near, far) along the ray to a sphere at the origin; near > far on a miss
+fn ray_sphere(origin: vec3<f32>, dir: vec3<f32>, radius: f32) -> vec2<f32> {
+    let b = dot(origin, dir);
+    let c = dot(origin, origin) - radius * radius;
+    let d = b * b - c;
+    if d < 0.0 {
+        return vec2<f32>(1e10, -1e10);
+    }
+    let s = sqrt(d);
+    return vec2<f32>(-b - s, -b + s);
+}
+
+fn atmosphere(origin: vec3<f32>, dir: vec3<f32>, sun: vec3<f32>, t_end_limit: f32) -> vec3<f32> {
+    let atmo = ray_sphere(origin, dir, ATMOSPHERE_RADIUS);
+    if atmo.x > atmo.y {
+        return vec3<f32>(0.0);
+    }
+    let t_start = max(atmo.x, 0.0);
+    let t_end = min(atmo.y, t_end_limit);
+    if t_end <= t_start {
+        return vec3<f32>(0.0);
+    }
+    let step = (t_end - t_start) / f32(PRIMARY_STEPS);
+
+    let mu = dot(dir, sun);
+    let mumu = mu * mu;
+    let gg = MIE_G * MIE_G;
+    let phase_r = 3.0 / (16.0 * PI) * (1.0 + mumu);
+    let phase_m = 3.0 / (8.0 * PI) * ((1.0 - gg) * (mumu + 1.0))
+        / (pow(1.0 + gg - 2.0 * mu * MIE_G, 1.5) * (2.0 + gg));
+
+    var total_r = vec3<f32>(0.0);
+    var total_m = vec3<f32>(0.0);
+    var depth_r = 0.0;
+    var depth_m = 0.0;
+
+    for (var i = 0; i < PRIMARY_STEPS; i++) {
+        let pos = origin + dir * (t_start + (f32(i) + 0.5) * step);
+        let height = length(pos) - EARTH_RADIUS;
+        let density_r = exp(-height / RAYLEIGH_HEIGHT) * step;
+        let density_m = exp(-height / MIE_HEIGHT) * step;
+        depth_r += density_r;
+        depth_m += density_m;
+
+        // Sample points in the planet's shadow get no sunlight
+        let ground = ray_sphere(pos, sun, EARTH_RADIUS);
+        if ground.x < ground.y && ground.x > 0.0 {
+            continue;
+        }
+
+        let light = ray_sphere(pos, sun, ATMOSPHERE_RADIUS);
+        let light_step = light.y / f32(LIGHT_STEPS);
+        var light_r = 0.0;
+        var light_m = 0.0;
+        for (var j = 0; j < LIGHT_STEPS; j++) {
+            let light_pos = pos + sun * ((f32(j) + 0.5) * light_step);
+            let light_height = length(light_pos) - EARTH_RADIUS;
+            light_r += exp(-light_height / RAYLEIGH_HEIGHT) * light_step;
+            light_m += exp(-light_height / MIE_HEIGHT) * light_step;
+        }
+
+        let attenuation = exp(-(MIE_BETA * (depth_m + light_m) + RAYLEIGH_BETA * (depth_r + light_r)));
+        total_r += density_r * attenuation;
+        total_m += density_m * attenuation;
+    }
+
+    return SUN_INTENSITY * (phase_r * RAYLEIGH_BETA * total_r + phase_m * MIE_BETA * total_m);
+}
+
+fn hash3(p: vec3<f32>) -> f32 {
+    return fract(sin(dot(p, vec3<f32>(127.1, 311.7, 74.7))) * 43758.5453);
+}
+
+// Sparse point stars on a fixed celestial grid
+fn stars(dir: vec3<f32>) -> f32 {
+    let p = dir * 350.0;
+    let cell = floor(p);
+    let h = hash3(cell);
+    if h < 0.985 {
+        return 0.0;
+    }
+    let star = vec3<f32>(hash3(cell + 1.3), hash3(cell + 7.1), hash3(cell + 3.7)) * 0.6 + 0.2;
+    let d = length(fract(p) - star);
+    return smoothstep(0.12, 0.0, d) * (h - 0.985) / 0.015;
+}
+
+fn luminance(c: vec3<f32>) -> f32 {
+    return dot(c, vec3<f32>(0.2126, 0.7152, 0.0722));
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    let dir = normalize(in.world_direction);
+    let sun = normalize(sky.sun.xyz);
+    let altitude = max(sky.params.x, 1.0);
+    let origin = vec3<f32>(0.0, EARTH_RADIUS + altitude, 0.0);
+
+    // Where the view ray meets the planet (if it does)
+    let ground = ray_sphere(origin, dir, EARTH_RADIUS);
+    let hits_ground = ground.x < ground.y && ground.x > 0.0;
+    let t_ground = select(1e12, ground.x, hits_ground);
+
+    var color = atmosphere(origin, dir, sun, t_ground);
+
+    if hits_ground {
+        // Planet surface seen through the atmosphere (dark land, lit by the sun)
+        let normal = normalize(origin + dir * ground.x);
+        let sunlit = max(dot(normal, sun), 0.0);
+        let air = exp(-ground.x / 60000.0);
+        color += vec3<f32>(0.05, 0.07, 0.04) * sunlit * SUN_INTENSITY * 0.05 * air;
+    } else {
+        // Sun disc, reddened by the same light that colors the sky around it
+        let mu = dot(dir, sun);
+        let disc = smoothstep(cos(SUN_ANGULAR_RADIUS), cos(SUN_ANGULAR_RADIUS * 0.8), mu);
+        let tint = normalize(color + vec3<f32>(0.3, 0.25, 0.2)) * 1.7;
+        color += disc * sky.sun.w * tint;
+    }
+
+    // Overcast: wash the scattering toward a flat grey
+    let overcast = sky.params.z;
+    color = mix(color, vec3<f32>(luminance(color) * 0.8), overcast);
+
+    // Scale from daytime-normalised radiance to the current exposure
+    color *= view.exposure * sky.params.y;
+
+    // Stars wherever the sky is dark enough (night, or above the atmosphere)
+    if !hits_ground {
+        let visibility = sky.params.w * (1.0 - clamp(luminance(color) * 8.0, 0.0, 1.0));
+        color += vec3<f32>(stars(dir) * 3.0 * visibility);
+    }
+
+    // Fade into the distance fog at and below the horizon so terrain edges vanish cleanly
+    let horizon_band = 1.0 - smoothstep(-0.02, 0.15, dir.y);
+    color = mix(color, sky.horizon_color.rgb, horizon_band * sky.horizon_color.a);
+
+    return vec4<f32>(color, 1.0);
+}
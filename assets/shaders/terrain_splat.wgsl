@@ -0,0 +1,67 @@
+// Terrain splat shader: blends the grass texture with flat rock/snow/sand colors.
+// Per-vertex weights arrive in the vertex color as (grass, rock, snow, sand).
+
+#import bevy_pbr::{
+    pbr_fragment::pbr_input_from_standard_material,
+    pbr_functions::alpha_discard,
+    pbr_bindings,
+    pbr_types,
+}
+
+#ifdef PREPASS_PIPELINE
+#import bevy_pbr::{
+    prepass_io::{VertexOutput, FragmentOutput},
+    pbr_deferred_functions::deferred_output,
+}
+#else
+#import bevy_pbr::{
+    forward_io::{VertexOutput, FragmentOutput},
+    pbr_functions::{apply_pbr_lighting, main_pass_post_lighting_processing},
+}
+#endif
+
+struct TerrainSplat {
+    rock_color: vec4<f32>,
+    snow_color: vec4<f32>,
+    sand_color: vec4<f32>,
+}
+
+@group(2) @binding(100) var<uniform> terrain_splat: TerrainSplat;
+
+@fragment
+fn fragment(
+    in: VertexOutput,
+    @builtin(front_facing) is_front: bool,
+) -> FragmentOutput {
+    var pbr_input = pbr_input_from_standard_material(in, is_front);
+
+#ifdef VERTEX_COLORS
+#ifdef VERTEX_UVS_A
+    // The standard path multiplies base color by the vertex color; the weights
+    // are not a tint, so rebuild the albedo from the grass texture instead.
+    let w = in.color;
+    let grass = textureSample(pbr_bindings::base_color_texture, pbr_bindings::base_color_sampler, in.uv);
+    let albedo = grass.rgb * w.r
+        + terrain_splat.rock_color.rgb * w.g
+        + terrain_splat.snow_color.rgb * w.b
+        + terrain_splat.sand_color.rgb * w.a;
+    pbr_input.material.base_color = vec4<f32>(albedo, 1.0);
+#endif
+#endif
+
+    pbr_input.material.base_color = alpha_discard(pbr_input.material, pbr_input.material.base_color);
+
+#ifdef PREPASS_PIPELINE
+    let out = deferred_output(in, pbr_input);
+#else
+    var out: FragmentOutput;
+    if (pbr_input.material.flags & pbr_types::STANDARD_MATERIAL_FLAGS_UNLIT_BIT) == 0u {
+        out.color = apply_pbr_lighting(pbr_input);
+    } else {
+        out.color = pbr_input.material.base_color;
+    }
+    out.color = main_pass_post_lighting_processing(pbr_input, out.color);
+#endif
+
+    return out;
+}
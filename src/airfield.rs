@@ -0,0 +1,410 @@
+use avian3d::prelude::*;
+use bevy::{math::DVec3, prelude::*};
+use crate::{
+    biome::{self, smoothstep},
+    landing_gear::{LandingGear, GEAR_HEIGHT},
+    origin::WorldOrigin,
+    water, GameState, WORLD_SEED,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// The world is split into square cells this size (meters), each holding at most
+/// one airfield. The home field always sits in the cell around the world origin.
+const AIRFIELD_CELL: f64 = 20000.0;
+/// Percent of cells that get an airfield (if the land there is flat enough)
+const AIRFIELD_CHANCE: u64 = 45;
+/// Airfields are only built where the biome selector says flatlands
+const AIRFIELD_MAX_SELECTOR: f32 = 0.3;
+/// How far a field may sit from its cell center; keeps it and its shoulders inside the cell
+const AIRFIELD_MAX_OFFSET: f64 = AIRFIELD_CELL / 2.0 - 2500.0;
+
+/// Runway size (meters)
+const RUNWAY_LENGTH: f32 = 2500.0;
+const RUNWAY_WIDTH: f32 = 45.0;
+/// Graded-flat pad around the runway, wide enough for the apron and for the
+/// 50 m terrain grid to stay flat under the asphalt
+const PAD_HALF_LENGTH: f32 = RUNWAY_LENGTH / 2.0 + 150.0;
+const PAD_HALF_WIDTH: f32 = 150.0;
+/// Distance over which the pad blends back into the natural terrain
+const SHOULDER: f32 = 300.0;
+/// Beyond this distance from the center the terrain is untouched
+const INFLUENCE_RADIUS: f32 = PAD_HALF_LENGTH + SHOULDER + PAD_HALF_WIDTH;
+/// Fields on low ground are built up on an embankment this far above the water
+const MIN_ELEVATION_ABOVE_SEA: f32 = 10.0;
+
+/// Where the takeoff roll starts, measured in from the runway end
+const THRESHOLD_INSET: f32 = 150.0;
+/// Air starts begin this high over the home field, heading down the runway
+const AIR_START_HEIGHT: f32 = 500.0;
+const AIR_START_SPEED: f32 = 100.0;
+
+/// Runway markings
+const CENTERLINE_DASH: f32 = 30.0;
+const CENTERLINE_GAP: f32 = 20.0;
+const THRESHOLD_STRIPES: usize = 8;
+const THRESHOLD_STRIPE_LENGTH: f32 = 30.0;
+const EDGE_LIGHT_SPACING: f32 = 60.0;
+/// Apron beside the runway (across, along)
+const APRON_SIZE: Vec2 = Vec2::new(80.0, 400.0);
+
+// ============================================================================
+// AIRFIELDS
+// ============================================================================
+
+/// A runway graded into the terrain. Positions are absolute world coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Airfield {
+    /// Runway center; `y` is the graded elevation
+    pub center: DVec3,
+    /// Rotation about +Y from -Z (north) to the takeoff direction of the main runway end
+    pub heading: f32,
+    pub home: bool,
+}
+
+impl Airfield {
+    /// Takeoff direction from the threshold the player starts at
+    pub fn direction(&self) -> Vec3 {
+        self.rotation() * Vec3::NEG_Z
+    }
+
+    pub fn rotation(&self) -> Quat {
+        Quat::from_rotation_y(self.heading)
+    }
+
+    pub fn elevation(&self) -> f32 {
+        self.center.y as f32
+    }
+
+    /// Position relative to the runway: distance along the centerline and across it
+    pub fn runway_coords(&self, world_x: f64, world_z: f64) -> (f32, f32) {
+        let offset = Vec3::new((world_x - self.center.x) as f32, 0.0, (world_z - self.center.z) as f32);
+        let right = self.rotation() * Vec3::X;
+        (offset.dot(self.direction()), offset.dot(right))
+    }
+
+    pub fn on_runway(&self, world_x: f64, world_z: f64) -> bool {
+        let (along, across) = self.runway_coords(world_x, world_z);
+        along.abs() <= RUNWAY_LENGTH / 2.0 && across.abs() <= RUNWAY_WIDTH / 2.0
+    }
+
+    fn on_pad(&self, world_x: f64, world_z: f64) -> bool {
+        let (along, across) = self.runway_coords(world_x, world_z);
+        along.abs() <= PAD_HALF_LENGTH && across.abs() <= PAD_HALF_WIDTH
+    }
+
+    /// Start of the takeoff roll (absolute, at runway elevation)
+    pub fn threshold(&self) -> DVec3 {
+        self.center - (self.direction() * (RUNWAY_LENGTH / 2.0 - THRESHOLD_INSET)).as_dvec3()
+    }
+
+    /// Runway number painted on the threshold: compass heading / 10 (north is 36)
+    pub fn designator(&self) -> u32 {
+        let compass = (-self.heading).to_degrees().rem_euclid(360.0);
+        match (compass / 10.0).round() as u32 {
+            0 => 36,
+            n => n,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        if self.home {
+            format!("Home Field RWY {:02}", self.designator())
+        } else {
+            format!("Field {:.0}/{:.0} RWY {:02}",
+                self.center.x / 1000.0, self.center.z / 1000.0, self.designator())
+        }
+    }
+}
+
+/// Seeded hash of a cell coordinate
+fn cell_hash(cell_x: i64, cell_z: i64) -> u64 {
+    let mut h = (cell_x as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (cell_z as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F)
+        ^ WORLD_SEED as u64;
+    h ^= h >> 31;
+    h = h.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    h ^ (h >> 29)
+}
+
+/// 16 bits of the hash as 0..1
+fn hash_unit(hash: u64, shift: u32) -> f64 {
+    ((hash >> shift) & 0xFFFF) as f64 / 65535.0
+}
+
+/// Where a cell's airfield would go (x, z, heading), before checking the land.
+/// Cheap: no noise is sampled.
+fn cell_site(cell_x: i64, cell_z: i64) -> Option<(f64, f64, f32)> {
+    if cell_x == 0 && cell_z == 0 {
+        return Some((0.0, 0.0, 0.0)); // Home field: at the origin, takeoff to the north
+    }
+    let hash = cell_hash(cell_x, cell_z);
+    if hash % 100 >= AIRFIELD_CHANCE {
+        return None;
+    }
+    let x = cell_x as f64 * AIRFIELD_CELL + (hash_unit(hash, 8) * 2.0 - 1.0) * AIRFIELD_MAX_OFFSET;
+    let z = cell_z as f64 * AIRFIELD_CELL + (hash_unit(hash, 24) * 2.0 - 1.0) * AIRFIELD_MAX_OFFSET;
+    // A runway works both ways, so half a turn covers every orientation
+    let heading = (hash_unit(hash, 40) * std::f64::consts::PI) as f32;
+    Some((x, z, heading))
+}
+
+/// Survey a site: only flatlands get an airfield, graded to the ground at its center
+fn survey(cell_x: i64, cell_z: i64, (x, z, heading): (f64, f64, f32)) -> Option<Airfield> {
+    let home = cell_x == 0 && cell_z == 0;
+    if !home && biome::biome_selector(x, z) > AIRFIELD_MAX_SELECTOR {
+        return None;
+    }
+    let elevation = crate::natural_terrain_height(x, z).max(water::SEA_LEVEL + MIN_ELEVATION_ABOVE_SEA);
+    Some(Airfield { center: DVec3::new(x, elevation as f64, z), heading, home })
+}
+
+fn cell_of(world_x: f64, world_z: f64) -> (i64, i64) {
+    ((world_x / AIRFIELD_CELL).round() as i64, (world_z / AIRFIELD_CELL).round() as i64)
+}
+
+/// The airfield whose grading reaches this point, if any
+pub fn airfield_near(world_x: f64, world_z: f64) -> Option<Airfield> {
+    let (cell_x, cell_z) = cell_of(world_x, world_z);
+    let site = cell_site(cell_x, cell_z)?;
+    let (dx, dz) = (world_x - site.0, world_z - site.1);
+    if dx * dx + dz * dz > (INFLUENCE_RADIUS as f64).powi(2) {
+        return None;
+    }
+    survey(cell_x, cell_z, site)
+}
+
+/// The airfield whose flat pad (runway, apron and verges) contains this point
+pub fn airfield_at(world_x: f64, world_z: f64) -> Option<Airfield> {
+    airfield_near(world_x, world_z).filter(|field| field.on_pad(world_x, world_z))
+}
+
+/// The airfield at the world origin, where missions start
+pub fn home_airfield() -> Airfield {
+    survey(0, 0, (0.0, 0.0, 0.0)).expect("the home field is always built")
+}
+
+/// Grade the natural terrain flat around any runway, easing back over the shoulder
+pub fn flatten_terrain(world_x: f64, world_z: f64, natural: f32) -> f32 {
+    let Some(field) = airfield_near(world_x, world_z) else { return natural };
+    let (along, across) = field.runway_coords(world_x, world_z);
+    let outside_along = (along.abs() - PAD_HALF_LENGTH).max(0.0);
+    let outside_across = (across.abs() - PAD_HALF_WIDTH).max(0.0);
+    let blend = smoothstep(0.0, SHOULDER, outside_along.hypot(outside_across));
+    field.elevation() + (natural - field.elevation()) * blend
+}
+
+// ============================================================================
+// MISSION START
+// ============================================================================
+
+/// How a mission puts the player in the world (set by the mission select screen)
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MissionStart {
+    /// Lined up on the home field runway, engine at idle
+    #[default]
+    Runway,
+    /// Already flying over the home field
+    Airborne,
+}
+
+impl MissionStart {
+    pub fn label(self) -> &'static str {
+        match self {
+            MissionStart::Runway => "Runway start",
+            MissionStart::Airborne => "Air start",
+        }
+    }
+}
+
+/// Where the player (re)spawns for the current mission start
+#[derive(Clone, Copy, Debug)]
+pub struct PlayerSpawn {
+    /// Absolute position of the jet's origin
+    pub position: DVec3,
+    pub rotation: Quat,
+    pub speed: f32,
+    pub on_ground: bool,
+}
+
+impl PlayerSpawn {
+    pub fn new(start: MissionStart) -> Self {
+        let home = home_airfield();
+        match start {
+            MissionStart::Runway => Self {
+                position: home.threshold() + DVec3::Y * GEAR_HEIGHT as f64,
+                rotation: home.rotation(),
+                speed: 0.0,
+                on_ground: true,
+            },
+            MissionStart::Airborne => Self {
+                position: home.center + DVec3::Y * AIR_START_HEIGHT as f64,
+                rotation: home.rotation(),
+                speed: AIR_START_SPEED,
+                on_ground: false,
+            },
+        }
+    }
+
+    pub fn velocity(&self) -> Vec3 {
+        self.rotation * Vec3::NEG_Z * self.speed
+    }
+
+    /// Put the jet back on the spawn point, gear set for where it starts
+    pub fn place(
+        &self,
+        origin: &WorldOrigin,
+        transform: &mut Transform,
+        velocity: &mut LinearVelocity,
+        ang_vel: &mut AngularVelocity,
+        gear: &mut LandingGear,
+    ) {
+        transform.translation = origin.to_local(self.position);
+        transform.rotation = self.rotation;
+        velocity.0 = self.velocity();
+        ang_vel.0 = Vec3::ZERO;
+        *gear = LandingGear::new(self.on_ground);
+    }
+}
+
+// ============================================================================
+// RESOURCES & PLUGIN
+// ============================================================================
+
+/// Shared runway meshes and materials
+#[derive(Resource)]
+pub struct AirfieldAssets {
+    runway_mesh: Handle<Mesh>,
+    apron_mesh: Handle<Mesh>,
+    dash_mesh: Handle<Mesh>,
+    stripe_mesh: Handle<Mesh>,
+    light_mesh: Handle<Mesh>,
+    asphalt: Handle<StandardMaterial>,
+    concrete: Handle<StandardMaterial>,
+    paint: Handle<StandardMaterial>,
+    edge_light: Handle<StandardMaterial>,
+}
+
+/// Runway surface and markings (children of the chunk holding the runway center)
+#[derive(Component)]
+pub struct RunwayMarking;
+
+pub struct AirfieldPlugin;
+
+impl Plugin for AirfieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MissionStart>()
+            .add_systems(OnEnter(GameState::Spawning), setup_airfield_assets);
+    }
+}
+
+fn setup_airfield_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let stripe_width = RUNWAY_WIDTH / (THRESHOLD_STRIPES * 2) as f32;
+    commands.insert_resource(AirfieldAssets {
+        runway_mesh: meshes.add(Plane3d::default().mesh().size(RUNWAY_WIDTH, RUNWAY_LENGTH)),
+        apron_mesh: meshes.add(Plane3d::default().mesh().size(APRON_SIZE.x, APRON_SIZE.y)),
+        dash_mesh: meshes.add(Plane3d::default().mesh().size(1.0, CENTERLINE_DASH)),
+        stripe_mesh: meshes.add(Plane3d::default().mesh().size(stripe_width, THRESHOLD_STRIPE_LENGTH)),
+        light_mesh: meshes.add(Cuboid::new(0.5, 0.5, 0.5)),
+        asphalt: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.12, 0.12, 0.13),
+            perceptual_roughness: 0.95,
+            ..default()
+        }),
+        concrete: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.45, 0.45, 0.43),
+            perceptual_roughness: 0.9,
+            ..default()
+        }),
+        paint: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.9, 0.9, 0.88),
+            perceptual_roughness: 0.8,
+            ..default()
+        }),
+        // Glows in the dark so the runway can be found at night
+        edge_light: materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.85, 0.5),
+            unlit: true,
+            ..default()
+        }),
+    });
+}
+
+/// Lay the runway down if this chunk holds an airfield's center. The whole
+/// runway hangs off that one chunk; it is always loaded while any of the runway is close.
+pub fn spawn_airfield_in_chunk(
+    commands: &mut Commands,
+    assets: &AirfieldAssets,
+    chunk_world: DVec3,
+    chunk_size: f32,
+    chunk_entity: Entity,
+) {
+    let (cell_x, cell_z) = cell_of(chunk_world.x, chunk_world.z);
+    let Some(site) = cell_site(cell_x, cell_z) else { return };
+    let half = chunk_size as f64 / 2.0;
+    let inside = |center: f64, value: f64| value >= center - half && value < center + half;
+    if !inside(chunk_world.x, site.0) || !inside(chunk_world.z, site.1) {
+        return;
+    }
+    let Some(field) = survey(cell_x, cell_z, site) else { return };
+
+    // Everything is laid out in runway space, then rotated and dropped onto the chunk
+    let center = Vec3::new((field.center.x - chunk_world.x) as f32, field.elevation(), (field.center.z - chunk_world.z) as f32);
+    let rotation = field.rotation();
+    let place = |along: f32, across: f32, height: f32| {
+        Transform::from_translation(center + rotation * Vec3::new(across, height, -along)).with_rotation(rotation)
+    };
+
+    let mut pieces = vec![
+        (assets.runway_mesh.clone(), assets.asphalt.clone(), place(0.0, 0.0, 0.1)),
+        (assets.apron_mesh.clone(), assets.concrete.clone(), place(0.0, PAD_HALF_WIDTH - APRON_SIZE.x / 2.0 - 10.0, 0.1)),
+    ];
+
+    // Centerline dashes between the threshold markings
+    let marked = RUNWAY_LENGTH / 2.0 - THRESHOLD_STRIPE_LENGTH - 30.0;
+    let mut along = -marked + CENTERLINE_DASH / 2.0;
+    while along < marked - CENTERLINE_DASH / 2.0 {
+        pieces.push((assets.dash_mesh.clone(), assets.paint.clone(), place(along, 0.0, 0.15)));
+        along += CENTERLINE_DASH + CENTERLINE_GAP;
+    }
+
+    // "Piano key" threshold stripes at both ends
+    let stripe_width = RUNWAY_WIDTH / (THRESHOLD_STRIPES * 2) as f32;
+    for end in [-1.0, 1.0] {
+        let along = end * (RUNWAY_LENGTH / 2.0 - THRESHOLD_STRIPE_LENGTH / 2.0 - 10.0);
+        for i in 0..THRESHOLD_STRIPES {
+            let across = (i as f32 - (THRESHOLD_STRIPES - 1) as f32 / 2.0) * stripe_width * 2.0;
+            pieces.push((assets.stripe_mesh.clone(), assets.paint.clone(), place(along, across, 0.15)));
+        }
+    }
+
+    // Edge lights
+    let lights = (RUNWAY_LENGTH / EDGE_LIGHT_SPACING) as usize;
+    for i in 0..=lights {
+        let along = i as f32 * EDGE_LIGHT_SPACING - RUNWAY_LENGTH / 2.0;
+        for side in [-1.0, 1.0] {
+            let across = side * (RUNWAY_WIDTH / 2.0 + 2.0);
+            pieces.push((assets.light_mesh.clone(), assets.edge_light.clone(), place(along, across, 0.25)));
+        }
+    }
+
+    commands.entity(chunk_entity).with_children(|parent| {
+        for (mesh, material, transform) in pieces {
+            parent.spawn((
+                RunwayMarking,
+                crate::ChunkEntity,
+                Mesh3d(mesh),
+                MeshMaterial3d(material),
+                transform,
+            ));
+        }
+    });
+    println!("🛬 AIRFIELD: {} at ({:.0}, {:.0}), elevation {:.0} m",
+        field.name(), field.center.x, field.center.z, field.elevation());
+}
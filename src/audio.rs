@@ -0,0 +1,399 @@
+use avian3d::prelude::*;
+use bevy::{
+    audio::{AudioSinkPlayback, PlaybackMode, SpatialAudioSink, Volume},
+    prelude::*,
+};
+use crate::{camera::CameraRig, origin::WorldOrigin, settings::Settings, GameState, PlayerPlane};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Speed of sound at sea level (m/s)
+pub const SPEED_OF_SOUND: f32 = 343.0;
+/// World sounds closer than this many seconds of travel play straight away
+const MIN_SOUND_DELAY: f32 = 0.05;
+/// Pitch shift per Mach of closing speed (1.0 is physical, half sounds better)
+const DOPPLER_SCALE: f32 = 0.5;
+/// Seconds to blend into full Doppler (keeps a launch's initial punch at its own pitch)
+const DOPPLER_RAMP_TIME: f32 = 0.5;
+/// Gain of outside sounds heard through the canopy. Sinks have no filters, so
+/// "muffled" is just quieter.
+const COCKPIT_MUFFLE: f32 = 0.35;
+/// Gain of a sound with a hill between it and the listener
+const OCCLUDED_GAIN: f32 = 0.3;
+/// Terrain samples along the line of sight when checking occlusion
+const OCCLUSION_SAMPLES: usize = 8;
+/// How fast occlusion fades in and out (per second)
+const OCCLUSION_RATE: f32 = 6.0;
+/// A sound's line of sight is re-tested this often (seconds)...
+const OCCLUSION_RETEST_INTERVAL: f32 = 0.2;
+/// ...or sooner once the listener or the sound has moved this far (meters)
+const OCCLUSION_RETEST_DISTANCE: f32 = 5.0;
+/// Spatial sinks get their emitter parked this far from the listener, in the
+/// sound's direction: rodio only pans, the mixer does the distance rolloff
+const PAN_DISTANCE: f32 = 1.0;
+/// Listener ear gap. With `PAN_DISTANCE` a sound hard to one side is ~7 dB
+/// quieter in the far ear.
+pub const EAR_GAP: f32 = 1.0;
+
+// ============================================================================
+// BUSES & COMPONENTS
+// ============================================================================
+
+/// Mixer bus a sound plays through; each has a volume in the settings, all of
+/// them under the master volume
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AudioBus {
+    /// Engine loops and the afterburner
+    Engine,
+    /// Guns, missiles, explosions and crashes
+    Weapons,
+    /// Wind and airframe noise
+    Ambient,
+    /// Cockpit warnings and callouts
+    Voice,
+}
+
+/// Every sound the mixer drives. Gameplay only sets `volume` (and the sink's
+/// speed for loops); the mixer owns the sink volume and pause state.
+#[derive(Component)]
+pub struct MixerSound {
+    pub bus: AudioBus,
+    /// Level before bus, distance and muffling
+    pub volume: f32,
+    /// Cleared to hold a loop paused while the game runs
+    pub playing: bool,
+}
+
+impl MixerSound {
+    pub fn new(bus: AudioBus, volume: f32) -> Self {
+        Self { bus, volume, playing: true }
+    }
+
+    /// A loop that waits silently until something starts it
+    pub fn stopped(bus: AudioBus) -> Self {
+        Self { bus, volume: 0.0, playing: false }
+    }
+}
+
+/// Sound placed in the world: distance rolloff, Doppler, occlusion and panning
+#[derive(Component)]
+struct WorldSound {
+    /// Distance at which the sound is at half volume
+    reference_distance: f32,
+    /// Silent (and not worth panning) beyond this
+    max_distance: f32,
+    /// Pitch before Doppler
+    speed: f32,
+    age: f32,
+    /// 1.0 clear line of sight, `OCCLUDED_GAIN` behind terrain
+    occlusion: f32,
+    /// Last line-of-sight test; terrain sampling is too costly to redo every frame
+    last_test: Option<OcclusionTest>,
+}
+
+#[derive(Clone, Copy)]
+struct OcclusionTest {
+    clear: bool,
+    /// Sound age when tested
+    age: f32,
+    listener: Vec3,
+    source: Vec3,
+}
+
+impl WorldSound {
+    /// Cached line of sight from `listener` to `source`, re-tested when stale
+    fn line_of_sight(&mut self, origin: &WorldOrigin, listener: Vec3, source: Vec3) -> bool {
+        let stale = self.last_test.is_none_or(|test| {
+            self.age - test.age >= OCCLUSION_RETEST_INTERVAL
+                || test.listener.distance(listener) > OCCLUSION_RETEST_DISTANCE
+                || test.source.distance(source) > OCCLUSION_RETEST_DISTANCE
+        });
+        if stale {
+            let clear = line_of_sight(origin, listener, source);
+            self.last_test = Some(OcclusionTest { clear, age: self.age, listener, source });
+        }
+        self.last_test.is_some_and(|test| test.clear)
+    }
+}
+
+/// Where a sound comes from
+#[derive(Clone, Copy, Debug)]
+pub enum SoundSource {
+    /// Inside the player's jet: no position, no delay
+    Cockpit,
+    /// Fixed point in render space, heard after the sound has travelled
+    At(Vec3),
+    /// Rides along with an entity (e.g. a missile), heard immediately
+    Attached(Entity),
+}
+
+/// Request to play a one-shot sound through the mixer
+#[derive(Event, Clone)]
+pub struct PlaySound {
+    pub sound: Handle<AudioSource>,
+    pub bus: AudioBus,
+    pub volume: f32,
+    pub speed: f32,
+    pub source: SoundSource,
+    pub reference_distance: f32,
+    pub max_distance: f32,
+}
+
+impl PlaySound {
+    pub fn new(sound: Handle<AudioSource>, bus: AudioBus) -> Self {
+        Self {
+            sound,
+            bus,
+            volume: 1.0,
+            speed: 1.0,
+            source: SoundSource::Cockpit,
+            reference_distance: 150.0,
+            max_distance: 8000.0,
+        }
+    }
+
+    pub fn at(mut self, position: Vec3) -> Self {
+        self.source = SoundSource::At(position);
+        self
+    }
+
+    pub fn attached(mut self, entity: Entity) -> Self {
+        self.source = SoundSource::Attached(entity);
+        self
+    }
+
+    pub fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Half volume at `reference_distance`, silent past `max_distance` (meters)
+    pub fn with_range(mut self, reference_distance: f32, max_distance: f32) -> Self {
+        self.reference_distance = reference_distance;
+        self.max_distance = max_distance;
+        self
+    }
+}
+
+/// A sound still on its way to the listener. Root entity with a `Transform`,
+/// so floating-origin shifts carry it along.
+#[derive(Component)]
+struct PendingSound {
+    request: PlaySound,
+    timer: Timer,
+}
+
+pub struct AudioMixerPlugin;
+
+impl Plugin for AudioMixerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PlaySound>()
+            .add_systems(Update, (
+                play_sounds,
+                release_pending_sounds.run_if(in_state(GameState::Playing)),
+            ).chain())
+            // After Bevy has created this frame's sinks and placed spatial emitters
+            // (PostUpdate), so the panning position set here is the one that sticks
+            .add_systems(Last, mix_audio);
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// Start requested sounds, or hold far ones back until the sound reaches the listener
+fn play_sounds(
+    mut commands: Commands,
+    mut requests: EventReader<PlaySound>,
+    listener_query: Query<&GlobalTransform, With<SpatialListener>>,
+) {
+    let listener = listener_query.get_single().ok().map(|t| t.translation());
+
+    for request in requests.read() {
+        match request.source {
+            SoundSource::Cockpit => {
+                spawn_sound(&mut commands, request, Transform::IDENTITY);
+            }
+            SoundSource::At(position) => {
+                let distance = listener.map_or(0.0, |l| l.distance(position));
+                if distance > request.max_distance {
+                    continue;
+                }
+                let delay = distance / SPEED_OF_SOUND;
+                if delay < MIN_SOUND_DELAY {
+                    spawn_sound(&mut commands, request, Transform::from_translation(position));
+                } else {
+                    commands.spawn((
+                        PendingSound {
+                            request: request.clone(),
+                            timer: Timer::from_seconds(delay, TimerMode::Once),
+                        },
+                        Transform::from_translation(position),
+                    ));
+                }
+            }
+            SoundSource::Attached(entity) => {
+                if commands.get_entity(entity).is_none() {
+                    continue;
+                }
+                let sound = spawn_sound(&mut commands, request, Transform::IDENTITY);
+                commands.entity(entity).add_child(sound);
+            }
+        }
+    }
+}
+
+fn release_pending_sounds(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut pending_query: Query<(Entity, &mut PendingSound, &Transform)>,
+) {
+    for (entity, mut pending, transform) in &mut pending_query {
+        if pending.timer.tick(time.delta()).finished() {
+            spawn_sound(&mut commands, &pending.request, *transform);
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn spawn_sound(commands: &mut Commands, request: &PlaySound, transform: Transform) -> Entity {
+    let positional = !matches!(request.source, SoundSource::Cockpit);
+    let mut sound = commands.spawn((
+        AudioPlayer(request.sound.clone()),
+        PlaybackSettings {
+            mode: PlaybackMode::Despawn,
+            volume: Volume::new(0.0), // The mixer sets the level this same frame
+            speed: request.speed,
+            spatial: positional,
+            ..default()
+        },
+        MixerSound::new(request.bus, request.volume),
+        transform,
+        Visibility::default(),
+    ));
+    if positional {
+        sound.insert(WorldSound {
+            reference_distance: request.reference_distance,
+            max_distance: request.max_distance,
+            speed: request.speed,
+            age: 0.0,
+            occlusion: 1.0,
+            last_test: None,
+        });
+    }
+    sound.id()
+}
+
+/// Set every sink's volume from its level, bus, distance, occlusion and the
+/// cockpit muffling, and pause everything while the game isn't running
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn mix_audio(
+    time: Res<Time>,
+    state: Res<State<GameState>>,
+    settings: Res<Settings>,
+    rig: Res<CameraRig>,
+    origin: Res<WorldOrigin>,
+    listener_query: Query<&GlobalTransform, With<SpatialListener>>,
+    player_query: Query<&LinearVelocity, With<PlayerPlane>>,
+    velocity_query: Query<&LinearVelocity>,
+    mut sound_query: Query<(
+        &MixerSound,
+        Option<&mut WorldSound>,
+        &GlobalTransform,
+        Option<&Parent>,
+        Option<&AudioSink>,
+        Option<&SpatialAudioSink>,
+    )>,
+) {
+    let running = *state.get() == GameState::Playing;
+    let in_cockpit = rig.view.is_first_person();
+    let listener = listener_query.get_single().ok();
+    let listener_velocity = player_query.get_single().map_or(Vec3::ZERO, |v| v.0);
+    let dt = time.delta_secs();
+
+    for (mixer, world, transform, parent, sink, spatial_sink) in &mut sound_query {
+        let sink: &dyn AudioSinkPlayback = match (sink, spatial_sink) {
+            (Some(sink), _) => sink,
+            (None, Some(sink)) => sink,
+            (None, None) => continue, // Not started yet
+        };
+
+        // Central pause: menus, pause and replays are silent
+        if !running || !mixer.playing {
+            if !sink.is_paused() {
+                sink.pause();
+            }
+            continue;
+        }
+        if sink.is_paused() {
+            sink.play();
+        }
+
+        let mut gain = mixer.volume * settings.bus_gain(mixer.bus);
+
+        match (world, listener) {
+            (Some(mut world), Some(listener)) => {
+                world.age += dt;
+                let listener_pos = listener.translation();
+                let sound_pos = transform.translation();
+                let distance = listener_pos.distance(sound_pos);
+
+                // Rolloff: ref / (ref + d) fades forever instead of cutting off
+                let rolloff = if distance > world.max_distance {
+                    0.0
+                } else {
+                    world.reference_distance / (world.reference_distance + distance)
+                };
+
+                let target_occlusion = if world.line_of_sight(&origin, listener_pos, sound_pos) { 1.0 } else { OCCLUDED_GAIN };
+                world.occlusion += (target_occlusion - world.occlusion) * (OCCLUSION_RATE * dt).min(1.0);
+
+                gain *= rolloff * world.occlusion;
+                if in_cockpit {
+                    gain *= COCKPIT_MUFFLE;
+                }
+
+                // Doppler from closing speed along the line of sight
+                let source_velocity = parent
+                    .and_then(|p| velocity_query.get(p.get()).ok())
+                    .map_or(Vec3::ZERO, |v| v.0);
+                let toward_listener = (listener_pos - sound_pos).normalize_or_zero();
+                let closing_speed = (source_velocity - listener_velocity).dot(toward_listener);
+                let doppler = (1.0 + closing_speed / SPEED_OF_SOUND * DOPPLER_SCALE).clamp(0.5, 2.0);
+                let ramp = (world.age / DOPPLER_RAMP_TIME).clamp(0.0, 1.0);
+                sink.set_speed(world.speed * (1.0 + (doppler - 1.0) * ramp));
+
+                // Pan only: park the emitter next to the listener in the sound's direction
+                if let Some(spatial_sink) = spatial_sink {
+                    let direction = (sound_pos - listener_pos).try_normalize()
+                        .unwrap_or(listener.forward().as_vec3());
+                    spatial_sink.set_emitter_position(listener_pos + direction * PAN_DISTANCE);
+                }
+            }
+            (Some(_), None) => gain = 0.0, // No one to hear it
+            (None, _) => {
+                if in_cockpit && mixer.bus == AudioBus::Ambient {
+                    gain *= COCKPIT_MUFFLE;
+                }
+            }
+        }
+
+        sink.set_volume(gain);
+    }
+}
+
+/// True when no terrain rises above the straight line between the two points
+fn line_of_sight(origin: &WorldOrigin, from: Vec3, to: Vec3) -> bool {
+    (1..=OCCLUSION_SAMPLES).all(|i| {
+        let point = from.lerp(to, i as f32 / (OCCLUSION_SAMPLES + 1) as f32);
+        origin.terrain_height_at(point) < point.y
+    })
+}
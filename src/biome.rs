@@ -0,0 +1,236 @@
+use bevy::{
+    pbr::{ExtendedMaterial, MaterialExtension},
+    prelude::*,
+    render::render_resource::{AsBindGroup, ShaderRef},
+};
+use noise::{NoiseFn, Perlin};
+
+use crate::{assets::GameAssets, GameState};
+
+// ============================================================================
+// BIOME SAMPLING
+// ============================================================================
+
+/// Seeds for the climate layers (terrain height itself uses seed 42)
+const MOISTURE_SEED: u32 = 4242;
+const TEMPERATURE_SEED: u32 = 4343;
+
+/// Height above which it is always cold enough for snow (before climate offsets)
+const BASE_SNOW_LINE: f32 = 750.0;
+
+/// Dominant terrain type at a world position (mirrors the blend in `get_terrain_height`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Biome {
+    Flatlands,
+    Canyons,
+    Mountains,
+}
+
+/// Everything the world generator needs to know about a spot on the map
+#[derive(Clone, Copy, Debug)]
+pub struct BiomeSample {
+    pub biome: Biome,
+    /// 0.0 = arid, 1.0 = lush
+    pub moisture: f32,
+    /// 0.0 = freezing, 1.0 = hot (already includes altitude lapse)
+    pub temperature: f32,
+    pub height: f32,
+}
+
+/// Biome selector noise in 0..1. Shared with `get_terrain_height` so the
+/// height blend and the biome query can never drift apart.
+pub fn biome_selector(world_x: f64, world_z: f64) -> f32 {
+    let perlin = Perlin::new(crate::WORLD_SEED);
+    let selector_raw = perlin.get([world_x * 0.0002, world_z * 0.0002]) as f32;
+    (selector_raw + 1.0) / 2.0 // Map -1..1 to 0..1
+}
+
+/// Large-scale moisture in 0..1 (rain shadow / river basins)
+pub fn moisture_at(world_x: f64, world_z: f64) -> f32 {
+    let perlin = Perlin::new(MOISTURE_SEED);
+    let raw = perlin.get([world_x * 0.0003, world_z * 0.0003]) as f32;
+    ((raw + 1.0) / 2.0).clamp(0.0, 1.0)
+}
+
+/// Temperature in 0..1, cooling with altitude (~0.1 per 300m)
+pub fn temperature_at(world_x: f64, world_z: f64, height: f32) -> f32 {
+    let perlin = Perlin::new(TEMPERATURE_SEED);
+    let raw = perlin.get([world_x * 0.00015, world_z * 0.00015]) as f32;
+    let sea_level_temp = (raw + 1.0) / 2.0;
+    (sea_level_temp - height.max(0.0) / 3000.0).clamp(0.0, 1.0)
+}
+
+/// Query the biome at an absolute world position (see `origin::WorldOrigin`)
+pub fn biome_at(world_x: f64, world_z: f64) -> BiomeSample {
+    biome_at_height(world_x, world_z, crate::get_terrain_height(world_x, world_z))
+}
+
+/// Same as `biome_at`, for callers that already sampled the terrain height
+pub fn biome_at_height(world_x: f64, world_z: f64, height: f32) -> BiomeSample {
+    let selector = biome_selector(world_x, world_z);
+
+    // Pick the biome that dominates the blend (midpoints of the transition bands)
+    let biome = if selector < 0.175 {
+        Biome::Flatlands
+    } else if selector < 0.5 {
+        Biome::Canyons
+    } else {
+        Biome::Mountains
+    };
+
+    BiomeSample {
+        biome,
+        moisture: moisture_at(world_x, world_z),
+        temperature: temperature_at(world_x, world_z, height),
+        height,
+    }
+}
+
+impl BiomeSample {
+    /// Snow line drops in cold regions and rises in warm ones
+    pub fn snow_line(&self) -> f32 {
+        BASE_SNOW_LINE + (self.temperature - 0.5) * 600.0
+    }
+
+    /// Terrain splat weights `[grass, rock, snow, sand]`, summing to 1.0.
+    /// `slope` is `1.0 - normal.y` (0 = flat, 1 = vertical cliff).
+    pub fn splat_weights(&self, slope: f32) -> [f32; 4] {
+        let snow_line = self.snow_line();
+
+        let rock = smoothstep(0.25, 0.55, slope).max(
+            // Bare rock on high mountain flanks even where it is not steep
+            if self.biome == Biome::Mountains { smoothstep(snow_line - 500.0, snow_line, self.height) * 0.6 } else { 0.0 },
+        );
+        let snow = smoothstep(snow_line - 80.0, snow_line + 80.0, self.height) * (1.0 - smoothstep(0.45, 0.7, slope));
+        // Sandy canyon floors and dry, hot lowlands
+        let canyon_sand = smoothstep(-80.0, -180.0, self.height);
+        let desert_sand = smoothstep(0.35, 0.15, self.moisture) * smoothstep(0.55, 0.75, self.temperature);
+        let sand = canyon_sand.max(desert_sand) * (1.0 - rock) * (1.0 - snow);
+
+        let grass = (1.0 - rock - snow - sand).max(0.0);
+        let total = (grass + rock + snow + sand).max(0.0001);
+        [grass / total, rock / total, snow / total, sand / total]
+    }
+
+    /// Relative tree density in 0..1 for vegetation scattering
+    pub fn vegetation_density(&self) -> f32 {
+        if self.height > self.snow_line() {
+            return 0.0; // Above the tree line
+        }
+        let climate = (self.moisture * 0.7 + (1.0 - (self.temperature - 0.5).abs() * 2.0) * 0.3).clamp(0.0, 1.0);
+        let terrain = match self.biome {
+            Biome::Flatlands => 1.0,
+            Biome::Canyons => 0.4,
+            Biome::Mountains => 0.6,
+        };
+        climate * terrain
+    }
+
+    /// Relative rock density (multiplier on the base rock count)
+    pub fn rock_density(&self) -> f32 {
+        match self.biome {
+            Biome::Flatlands => 0.5 + (1.0 - self.moisture) * 0.5,
+            Biome::Canyons => 1.5,
+            Biome::Mountains => 2.0,
+        }
+    }
+
+    /// Tree models suited to this climate (paths into `fantasy_town/`)
+    pub fn tree_models(&self) -> &'static [&'static str] {
+        if self.temperature < 0.35 || self.biome == Biome::Mountains {
+            // Cold / alpine: tall narrow trees
+            &["fantasy_town/tree-high.glb", "fantasy_town/tree-high-crooked.glb"]
+        } else if self.moisture < 0.4 {
+            // Dry: sparse, twisted trees
+            &["fantasy_town/tree-crooked.glb", "fantasy_town/tree-high-crooked.glb"]
+        } else {
+            // Temperate and lush
+            &["fantasy_town/tree.glb", "fantasy_town/tree-high-round.glb", "fantasy_town/tree-high.glb"]
+        }
+    }
+
+    /// Villages need flat, habitable land
+    pub fn is_habitable(&self) -> bool {
+        self.biome == Biome::Flatlands
+            && self.height > crate::water::SEA_LEVEL + 5.0
+            && self.height < self.snow_line() - 200.0
+            && self.moisture > 0.25
+    }
+}
+
+pub(crate) fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+// ============================================================================
+// TERRAIN SPLAT MATERIAL
+// ============================================================================
+
+/// Ground material: grass texture blended with rock/snow/sand by per-vertex splat weights.
+/// Weights travel in the vertex color attribute as `(grass, rock, snow, sand)`.
+pub type TerrainMaterial = ExtendedMaterial<StandardMaterial, TerrainSplatExtension>;
+
+#[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
+pub struct TerrainSplatExtension {
+    #[uniform(100)]
+    pub rock_color: LinearRgba,
+    #[uniform(100)]
+    pub snow_color: LinearRgba,
+    #[uniform(100)]
+    pub sand_color: LinearRgba,
+}
+
+impl Default for TerrainSplatExtension {
+    fn default() -> Self {
+        Self {
+            rock_color: LinearRgba::from(Color::srgb(0.42, 0.38, 0.34)),
+            snow_color: LinearRgba::from(Color::srgb(0.92, 0.94, 0.97)),
+            sand_color: LinearRgba::from(Color::srgb(0.76, 0.66, 0.46)),
+        }
+    }
+}
+
+impl MaterialExtension for TerrainSplatExtension {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/terrain_splat.wgsl".into()
+    }
+}
+
+/// Resource holding the shared ground material (loaded once at startup)
+#[derive(Resource)]
+pub struct GroundMaterial(pub Handle<TerrainMaterial>);
+
+pub struct BiomePlugin;
+
+impl Plugin for BiomePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(MaterialPlugin::<TerrainMaterial>::default())
+            .add_systems(OnEnter(GameState::Spawning), setup_ground_material);
+    }
+}
+
+/// Create the splat material every chunk's ground shares, using the pre-loaded grass textures
+fn setup_ground_material(
+    mut commands: Commands,
+    mut terrain_materials: ResMut<Assets<TerrainMaterial>>,
+    game_assets: Res<GameAssets>,
+) {
+    // Splat extension blends rock/snow/sand over the grass using per-vertex biome weights
+    let ground_material_handle = terrain_materials.add(TerrainMaterial {
+        base: StandardMaterial {
+            base_color: Color::WHITE, // White so texture shows true colors
+            base_color_texture: Some(game_assets.grass_texture.clone()),
+            normal_map_texture: Some(game_assets.grass_normal.clone()), // Normal map for surface detail
+            perceptual_roughness: 0.9,
+            reflectance: 0.02,
+            metallic: 0.0,
+            unlit: true, // FIX: Unlit ensures grass shows full brightness regardless of shadow cascades
+            ..default()
+        },
+        extension: TerrainSplatExtension::default(),
+    });
+
+    commands.insert_resource(GroundMaterial(ground_material_handle));
+    eprintln!("🌿 STARTUP: Ground material resource created");
+}
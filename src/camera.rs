@@ -0,0 +1,462 @@
+use avian3d::prelude::*;
+use bevy::{
+    input::mouse::{MouseMotion, MouseWheel},
+    prelude::*,
+};
+use crate::{
+    biome::smoothstep,
+    controls::{Action, ActionState},
+    drone::Drone,
+    mouse_aim::MouseAim,
+    origin::OriginShifted,
+    GameState, ModelContainer, PlayerPlane, Projectile,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Pilot's eye relative to the jet's center
+const COCKPIT_EYE: Vec3 = Vec3::new(0.0, 1.3, -2.2);
+/// Radians of head/orbit rotation per pixel of mouse motion
+const MOUSE_SENSITIVITY: f32 = 0.004;
+const HEAD_YAW_LIMIT: f32 = 2.6; // ~150° over each shoulder
+const HEAD_PITCH_UP: f32 = 1.4;
+const HEAD_PITCH_DOWN: f32 = -0.7;
+
+const ORBIT_MIN_DISTANCE: f32 = 10.0;
+const ORBIT_MAX_DISTANCE: f32 = 250.0;
+
+/// Fly-by camera sits this far ahead along the flight path, off to the side
+const FLYBY_LEAD: f32 = 450.0;
+const FLYBY_SIDE: f32 = 35.0;
+
+/// Padlock only grabs targets inside this cone around the nose (cosine of half-angle)
+const PADLOCK_CONE: f32 = 0.5;
+const PADLOCK_RANGE: f32 = 8000.0;
+
+/// How long the weapon camera lingers on the impact point before handing back
+const WEAPON_CAM_LINGER: f32 = 1.5;
+
+/// Sustained G where tunnel vision starts, and where the screen goes fully black
+const BLACKOUT_ONSET: f32 = 6.0;
+const BLACKOUT_FULL: f32 = 9.0;
+const REDOUT_ONSET: f32 = -2.0;
+const REDOUT_FULL: f32 = -4.0;
+/// How quickly the pilot's body catches up with the instantaneous load (1/s)
+const G_TOLERANCE_RATE: f32 = 0.8;
+
+// ============================================================================
+// COMPONENTS & RESOURCES
+// ============================================================================
+
+/// Chase camera settings on the player jet
+#[derive(Component)]
+pub struct FlightCamera {
+    /// Camera position in the jet's frame
+    pub local_offset: Vec3,
+    /// How quickly the camera swings round after the jet (1/s)
+    pub rotation_lag_speed: f32,
+}
+
+impl Default for FlightCamera {
+    fn default() -> Self {
+        Self {
+            local_offset: Vec3::new(0.0, 5.0, 15.0),
+            rotation_lag_speed: 5.0, // Stiffer camera for high speed
+        }
+    }
+}
+
+/// Available camera views; `C` steps through them in this order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum CameraView {
+    #[default]
+    Chase,
+    Cockpit,
+    Orbit,
+    WeaponCam,
+    Padlock,
+    FlyBy,
+}
+
+impl CameraView {
+    const ALL: [CameraView; 6] = [
+        CameraView::Chase,
+        CameraView::Cockpit,
+        CameraView::Orbit,
+        CameraView::WeaponCam,
+        CameraView::Padlock,
+        CameraView::FlyBy,
+    ];
+
+    fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|v| *v == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Views seen through the pilot's eyes (model hidden, G-effects shown)
+    pub fn is_first_person(self) -> bool {
+        matches!(self, CameraView::Cockpit | CameraView::Padlock)
+    }
+}
+
+/// State behind the active camera view
+#[derive(Resource)]
+pub struct CameraRig {
+    pub view: CameraView,
+    head_yaw: f32,
+    head_pitch: f32,
+    orbit_yaw: f32,
+    orbit_pitch: f32,
+    orbit_distance: f32,
+    /// Lagged orientation the chase camera trails behind
+    chase_rotation: Option<Quat>,
+    /// Where the fly-by camera is parked (render space)
+    flyby_point: Option<Vec3>,
+    /// Newest player missile, and where it was last seen
+    weapon: Option<Entity>,
+    weapon_last_seen: Option<Vec3>,
+    weapon_linger: f32,
+    padlock_target: Option<Entity>,
+    /// Velocity last frame, for the load factor
+    previous_velocity: Option<Vec3>,
+    /// Instantaneous and sustained (what the pilot feels) load factor
+    pub g_load: f32,
+    pub g_sustained: f32,
+}
+
+impl Default for CameraRig {
+    fn default() -> Self {
+        Self {
+            view: CameraView::Chase,
+            head_yaw: 0.0,
+            head_pitch: 0.0,
+            orbit_yaw: 0.0,
+            orbit_pitch: -0.25,
+            orbit_distance: 40.0,
+            chase_rotation: None,
+            flyby_point: None,
+            weapon: None,
+            weapon_last_seen: None,
+            weapon_linger: 0.0,
+            padlock_target: None,
+            previous_velocity: None,
+            g_load: 1.0,
+            g_sustained: 1.0,
+        }
+    }
+}
+
+/// Full-screen tint for blackout / redout
+#[derive(Component)]
+struct GForceOverlay;
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraRig>()
+            .add_systems(OnEnter(GameState::Spawning), setup_g_overlay)
+            // The replay viewer flies its own free camera
+            .add_systems(OnEnter(GameState::Replay), release_pilot_view)
+            .add_systems(Update, (
+                camera_controls,
+                track_player_missiles,
+                measure_g_load,
+                update_flight_camera,
+                update_model_visibility,
+                update_g_overlay,
+            ).chain().run_if(in_state(GameState::Playing)));
+    }
+}
+
+fn setup_g_overlay(mut commands: Commands) {
+    commands.spawn((
+        GForceOverlay,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        GlobalZIndex(-1), // Under the HUD text so the pilot can still read instruments
+    ));
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// `C` cycles views; the mouse turns the pilot's head (cockpit) or swings the
+/// orbit camera, the wheel zooms the orbit and middle-click re-centers the head
+fn camera_controls(
+    actions: Res<ActionState>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    mut shifted: EventReader<OriginShifted>,
+    mut rig: ResMut<CameraRig>,
+) {
+    if actions.just_pressed(Action::CycleCamera) {
+        rig.view = rig.view.next();
+        // Fresh state for the new view
+        rig.flyby_point = None;
+        rig.padlock_target = None;
+    }
+
+    // Cached render-space points move with the world
+    for event in shifted.read() {
+        if let Some(point) = rig.flyby_point.as_mut() {
+            *point -= event.delta;
+        }
+        if let Some(point) = rig.weapon_last_seen.as_mut() {
+            *point -= event.delta;
+        }
+    }
+
+    let motion: Vec2 = mouse_motion.read().map(|m| m.delta).sum();
+    let scroll: f32 = mouse_wheel.read().map(|w| w.y).sum();
+
+    match rig.view {
+        CameraView::Cockpit => {
+            rig.head_yaw = (rig.head_yaw - motion.x * MOUSE_SENSITIVITY).clamp(-HEAD_YAW_LIMIT, HEAD_YAW_LIMIT);
+            rig.head_pitch = (rig.head_pitch - motion.y * MOUSE_SENSITIVITY).clamp(HEAD_PITCH_DOWN, HEAD_PITCH_UP);
+            if mouse_buttons.just_pressed(MouseButton::Middle) {
+                rig.head_yaw = 0.0;
+                rig.head_pitch = 0.0;
+            }
+        }
+        CameraView::Orbit => {
+            rig.orbit_yaw -= motion.x * MOUSE_SENSITIVITY;
+            rig.orbit_pitch = (rig.orbit_pitch - motion.y * MOUSE_SENSITIVITY).clamp(-1.5, 1.5);
+            rig.orbit_distance = (rig.orbit_distance * (1.0 - scroll * 0.1)).clamp(ORBIT_MIN_DISTANCE, ORBIT_MAX_DISTANCE);
+        }
+        _ => {}
+    }
+}
+
+/// Remember the newest missile the player fired for the weapon camera
+fn track_player_missiles(
+    time: Res<Time>,
+    mut rig: ResMut<CameraRig>,
+    new_missiles: Query<Entity, Added<Projectile>>,
+    missiles: Query<&Transform, With<Projectile>>,
+) {
+    if let Some(newest) = new_missiles.iter().last() {
+        rig.weapon = Some(newest);
+        rig.weapon_linger = WEAPON_CAM_LINGER;
+    }
+
+    let Some(weapon) = rig.weapon else { return };
+    match missiles.get(weapon) {
+        Ok(transform) => rig.weapon_last_seen = Some(transform.translation),
+        Err(_) => {
+            // Gone: hold on the impact point for a moment
+            rig.weapon_linger -= time.delta_secs();
+            if rig.weapon_linger <= 0.0 {
+                rig.weapon = None;
+                rig.weapon_last_seen = None;
+            }
+        }
+    }
+}
+
+/// Load factor along the jet's up axis, and the lagged value the pilot's body feels
+fn measure_g_load(
+    time: Res<Time>,
+    mut rig: ResMut<CameraRig>,
+    player_query: Query<(&Transform, &LinearVelocity), With<PlayerPlane>>,
+) {
+    let Ok((transform, velocity)) = player_query.get_single() else { return };
+    let dt = time.delta_secs();
+    if dt <= 0.0 {
+        return;
+    }
+
+    let previous = rig.previous_velocity.replace(velocity.0).unwrap_or(velocity.0);
+    let acceleration = (velocity.0 - previous) / dt;
+    // What a seat-of-the-pants accelerometer reads: acceleration plus 1 g holding us up
+    let felt = (acceleration + Vec3::Y * 9.81).dot(transform.up().as_vec3()) / 9.81;
+    if felt.is_finite() {
+        // Frame-to-frame velocity is noisy; smooth the readout a little
+        rig.g_load += (felt.clamp(-15.0, 15.0) - rig.g_load) * (10.0 * dt).min(1.0);
+    }
+    let g_load = rig.g_load;
+    rig.g_sustained += (g_load - rig.g_sustained) * (G_TOLERANCE_RATE * dt).min(1.0);
+}
+
+/// Player missiles the weapon camera can ride
+type MissileQuery<'w, 's> = Query<'w, 's, &'static Transform, (With<Projectile>, Without<Camera3d>, Without<PlayerPlane>)>;
+/// Drones the padlock view can lock onto
+type PadlockQuery<'w, 's> = Query<'w, 's, (Entity, &'static Transform), (With<Drone>, Without<Camera3d>, Without<PlayerPlane>)>;
+
+fn update_flight_camera(
+    time: Res<Time>,
+    mut rig: ResMut<CameraRig>,
+    mut camera_query: Query<&mut Transform, (With<Camera3d>, Without<PlayerPlane>)>,
+    player_query: Query<(&Transform, &FlightCamera, &LinearVelocity), With<PlayerPlane>>,
+    missiles: MissileQuery,
+    drones: PadlockQuery,
+    mouse_aim: Res<MouseAim>,
+) {
+    let (Ok(mut camera_transform), Ok((player_transform, flight_camera, velocity))) =
+        (camera_query.get_single_mut(), player_query.get_single()) else { return };
+    let dt = time.delta_secs();
+    let jet_up = player_transform.up().as_vec3();
+    let aim_rotation = mouse_aim.enabled.then(|| mouse_aim.rotation());
+
+    // Pilot's eye, pushed down into the seat under positive G
+    let eye_sag = Vec3::NEG_Y * (rig.g_load - 1.0).clamp(-3.0, 9.0) * 0.015;
+    let eye = player_transform.transform_point(COCKPIT_EYE + eye_sag);
+
+    match rig.view {
+        CameraView::Chase => {
+            chase(&mut rig, &mut camera_transform, player_transform, flight_camera, aim_rotation, dt);
+        }
+        CameraView::Cockpit => {
+            camera_transform.translation = eye;
+            camera_transform.rotation = player_transform.rotation
+                * Quat::from_euler(EulerRot::YXZ, rig.head_yaw, rig.head_pitch, 0.0);
+        }
+        CameraView::Orbit => {
+            let orbit = Quat::from_euler(EulerRot::YXZ, rig.orbit_yaw, rig.orbit_pitch, 0.0);
+            camera_transform.translation = player_transform.translation + orbit * Vec3::Z * rig.orbit_distance;
+            camera_transform.look_at(player_transform.translation, Vec3::Y);
+        }
+        CameraView::WeaponCam => {
+            let missile = rig.weapon.and_then(|entity| missiles.get(entity).ok());
+            match (missile, rig.weapon_last_seen) {
+                (Some(missile), _) => {
+                    // Riding just behind and above the missile, looking down its flight path
+                    camera_transform.translation = missile.transform_point(Vec3::new(0.0, 1.5, 8.0));
+                    camera_transform.rotation = missile.rotation;
+                }
+                (None, Some(impact)) => {
+                    // Watch the impact from where the camera already is
+                    camera_transform.look_at(impact, Vec3::Y);
+                }
+                (None, None) => chase(&mut rig, &mut camera_transform, player_transform, flight_camera, aim_rotation, dt),
+            }
+        }
+        CameraView::Padlock => {
+            // Keep the locked drone, or grab the one closest to the nose
+            let target = rig
+                .padlock_target
+                .and_then(|entity| drones.get(entity).ok())
+                .or_else(|| {
+                    let nose = player_transform.forward().as_vec3();
+                    drones
+                        .iter()
+                        .filter(|(_, t)| {
+                            let to = t.translation - player_transform.translation;
+                            to.length() < PADLOCK_RANGE && to.normalize_or_zero().dot(nose) > PADLOCK_CONE
+                        })
+                        .max_by(|(_, a), (_, b)| {
+                            let score = |t: &Transform| (t.translation - player_transform.translation).normalize_or_zero().dot(nose);
+                            score(a).total_cmp(&score(b))
+                        })
+                });
+            rig.padlock_target = target.map(|(entity, _)| entity);
+
+            camera_transform.translation = eye;
+            match target {
+                Some((_, target)) => {
+                    let look = Transform::from_translation(eye).looking_at(target.translation, jet_up).rotation;
+                    camera_transform.rotation = camera_transform.rotation.slerp(look, (8.0 * dt).min(1.0));
+                }
+                None => camera_transform.rotation = player_transform.rotation,
+            }
+        }
+        CameraView::FlyBy => {
+            // Park ahead on the flight path; move on once the jet is well past
+            let needs_new_point = match rig.flyby_point {
+                None => true,
+                Some(point) => {
+                    let to_point = point - player_transform.translation;
+                    to_point.dot(velocity.0) < 0.0 && to_point.length() > FLYBY_LEAD
+                }
+            };
+            if needs_new_point {
+                let direction = velocity.0.normalize_or(player_transform.forward().as_vec3());
+                let side = direction.cross(Vec3::Y).normalize_or(Vec3::X);
+                rig.flyby_point = Some(
+                    player_transform.translation + direction * FLYBY_LEAD + side * FLYBY_SIDE + Vec3::Y * 8.0,
+                );
+            }
+            if let Some(point) = rig.flyby_point {
+                camera_transform.translation = point;
+                camera_transform.look_at(player_transform.translation, Vec3::Y);
+            }
+        }
+    }
+}
+
+/// Chase camera driven by the jet's `FlightCamera` settings, trailing a lagged
+/// copy of its orientation so rolls and turns swing the view round smoothly
+fn chase(
+    rig: &mut CameraRig,
+    camera_transform: &mut Transform,
+    player_transform: &Transform,
+    flight_camera: &FlightCamera,
+    aim_rotation: Option<Quat>,
+    dt: f32,
+) {
+    // With mouse aim the camera follows the aim, and the jet chases it into view
+    let target = aim_rotation.unwrap_or(player_transform.rotation);
+    let lagged = rig.chase_rotation.unwrap_or(target);
+    let t = (flight_camera.rotation_lag_speed * dt).min(1.0);
+    let rotation = lagged.slerp(target, t);
+    rig.chase_rotation = Some(rotation);
+
+    camera_transform.translation = player_transform.translation + rotation * flight_camera.local_offset;
+    camera_transform.look_at(player_transform.translation, rotation * Vec3::Y);
+}
+
+/// Hide the jet's model when looking out of its cockpit
+fn update_model_visibility(
+    rig: Res<CameraRig>,
+    mut model_query: Query<&mut Visibility, With<ModelContainer>>,
+) {
+    // Checked every frame (also catches the replay viewer handing the jet back); only
+    // writes on a mismatch so visibility change detection stays quiet
+    let wanted = if rig.view.is_first_person() { Visibility::Hidden } else { Visibility::Inherited };
+    for mut visibility in &mut model_query {
+        if *visibility != wanted {
+            *visibility = wanted;
+        }
+    }
+}
+
+/// Outside views for the replay viewer: show the jet, lift any blackout
+fn release_pilot_view(
+    mut model_query: Query<&mut Visibility, With<ModelContainer>>,
+    mut overlay_query: Query<&mut BackgroundColor, With<GForceOverlay>>,
+) {
+    for mut visibility in &mut model_query {
+        *visibility = Visibility::Inherited;
+    }
+    for mut background in &mut overlay_query {
+        background.0 = Color::NONE;
+    }
+}
+
+/// Grey-out / blackout under sustained positive G, redout under negative G.
+/// Only from the pilot's seat; external views show the jet, not the pilot.
+fn update_g_overlay(
+    rig: Res<CameraRig>,
+    mut overlay_query: Query<&mut BackgroundColor, With<GForceOverlay>>,
+) {
+    let Ok(mut background) = overlay_query.get_single_mut() else { return };
+    let color = if !rig.view.is_first_person() {
+        Color::NONE
+    } else if rig.g_sustained > 0.0 {
+        Color::srgba(0.0, 0.0, 0.0, smoothstep(BLACKOUT_ONSET, BLACKOUT_FULL, rig.g_sustained))
+    } else {
+        Color::srgba(0.6, 0.0, 0.0, smoothstep(-REDOUT_ONSET, -REDOUT_FULL, -rig.g_sustained) * 0.85)
+    };
+    if background.0 != color {
+        background.0 = color;
+    }
+}
@@ -0,0 +1,364 @@
+use bevy::{
+    pbr::{ExtendedMaterial, MaterialExtension, NotShadowCaster},
+    prelude::*,
+    render::render_resource::{AsBindGroup, ShaderRef},
+};
+use rand::{Rng, SeedableRng};
+use crate::{
+    biome::smoothstep,
+    origin::WorldOrigin,
+    particles::{EmitParticles, ParticleType},
+    time_of_day::TimeOfDay,
+    weather::Weather,
+    ChunkCoordinate, ChunkEntity, GameState, world_active, CHUNK_SIZE,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+const CLOUD_TEXTURES: [&str; 10] = [
+    "textures/clouds/FX_CloudAlpha01.png",
+    "textures/clouds/FX_CloudAlpha02.png",
+    "textures/clouds/FX_CloudAlpha03.png",
+    "textures/clouds/FX_CloudAlpha04.png",
+    "textures/clouds/FX_CloudAlpha05.png",
+    "textures/clouds/FX_CloudAlpha06.png",
+    "textures/clouds/FX_CloudAlpha07.png",
+    "textures/clouds/FX_CloudAlpha08.png",
+    "textures/clouds/FX_CloudAlpha09.png",
+    "textures/clouds/FX_CloudAlpha10.png",
+];
+
+/// Candidate cloud puffs per terrain chunk; `Weather::coverage` decides how many are shown
+const CLOUD_SLOTS_PER_CHUNK: usize = 3;
+const PUFF_RADIUS_MIN: f32 = 180.0;
+const PUFF_RADIUS_MAX: f32 = 420.0;
+const SPRITES_PER_PUFF_MIN: usize = 6;
+const SPRITES_PER_PUFF_MAX: usize = 10;
+/// Only the dense core of a puff hides what's behind it from sensors
+const LINE_OF_SIGHT_CORE: f32 = 0.7;
+/// Fog range when fully inside a cloud
+const IN_CLOUD_VISIBILITY: f32 = 150.0;
+/// Canopy droplets per second at full immersion
+const CONDENSATION_RATE: f32 = 40.0;
+
+// ============================================================================
+// CLOUD SPRITE MATERIAL
+// ============================================================================
+
+/// Unlit cloud sprite that fades out where it meets scene geometry (soft particles,
+/// via the camera's depth prepass) and as the camera flies into it.
+pub type CloudMaterial = ExtendedMaterial<StandardMaterial, CloudFadeExtension>;
+
+#[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
+pub struct CloudFadeExtension {
+    /// Depth range (m) over which a sprite fades in front of terrain
+    #[uniform(100)]
+    pub softness: f32,
+    /// Sprites closer than this to the camera fade out (m)
+    #[uniform(100)]
+    pub near_fade: f32,
+}
+
+impl Default for CloudFadeExtension {
+    fn default() -> Self {
+        Self { softness: 60.0, near_fade: 120.0 }
+    }
+}
+
+impl MaterialExtension for CloudFadeExtension {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/cloud_sprite.wgsl".into()
+    }
+}
+
+// ============================================================================
+// RESOURCES & COMPONENTS
+// ============================================================================
+
+/// One cloud: a cluster of camera-facing sprites. Shown when `Weather::coverage`
+/// exceeds `threshold`; `layer_offset` places it within the cloud layer's thickness.
+#[derive(Component)]
+pub struct CloudPuff {
+    threshold: f32,
+    layer_offset: f32,
+    radius: f32,
+}
+
+/// A single soft sprite inside a `CloudPuff`
+#[derive(Component)]
+struct CloudSprite;
+
+/// Shared sprite quad and one material per texture
+#[derive(Resource)]
+pub struct CloudAssets {
+    sprite_mesh: Handle<Mesh>,
+    materials: Vec<Handle<CloudMaterial>>,
+}
+
+/// Where the visible clouds are this frame, for fog, sensors and canopy effects
+#[derive(Resource, Default)]
+pub struct CloudCover {
+    /// (center, radius) of every visible puff
+    puffs: Vec<(Vec3, f32)>,
+    /// How deep the camera is inside a cloud (0 = clear air, 1 = in the core)
+    pub immersion: f32,
+}
+
+impl CloudCover {
+    /// True if a cloud core sits between two points (or swallows either of them).
+    /// Drone radar/visual lock needs a clear line.
+    pub fn blocks_line_of_sight(&self, from: Vec3, to: Vec3) -> bool {
+        let segment = to - from;
+        let length_sq = segment.length_squared();
+        self.puffs.iter().any(|(center, radius)| {
+            let t = if length_sq > 0.0 { ((*center - from).dot(segment) / length_sq).clamp(0.0, 1.0) } else { 0.0 };
+            let closest = from + segment * t;
+            closest.distance_squared(*center) < (radius * LINE_OF_SIGHT_CORE).powi(2)
+        })
+    }
+}
+
+pub struct CloudPlugin;
+
+impl Plugin for CloudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(MaterialPlugin::<CloudMaterial>::default())
+            .init_resource::<CloudCover>()
+            .add_systems(OnEnter(GameState::Spawning), setup_cloud_assets)
+            .add_systems(Update, (
+                update_cloud_layer,
+                update_cloud_materials,
+                face_cloud_sprites,
+                update_cloud_cover,
+                emit_canopy_condensation,
+            ).chain().run_if(world_active));
+    }
+}
+
+// ============================================================================
+// SETUP & SPAWNING
+// ============================================================================
+
+fn setup_cloud_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<CloudMaterial>>,
+) {
+    let cloud_materials = CLOUD_TEXTURES
+        .iter()
+        .map(|path| {
+            materials.add(CloudMaterial {
+                base: StandardMaterial {
+                    base_color_texture: Some(asset_server.load(*path)),
+                    base_color: Color::srgba(1.0, 1.0, 1.0, 0.55),
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    double_sided: true,
+                    cull_mode: None,
+                    ..default()
+                },
+                extension: CloudFadeExtension::default(),
+            })
+        })
+        .collect();
+
+    commands.insert_resource(CloudAssets {
+        sprite_mesh: meshes.add(Rectangle::new(1.0, 1.0)),
+        materials: cloud_materials,
+    });
+    eprintln!("☁️  STARTUP: Cloud assets created ({} soft sprite materials)", CLOUD_TEXTURES.len());
+}
+
+/// Scatter this chunk's candidate cloud puffs. Placement is seeded by the chunk
+/// coordinate so the same sky comes back when a chunk reloads.
+pub fn spawn_clouds_in_chunk(
+    commands: &mut Commands,
+    assets: &CloudAssets,
+    weather: &Weather,
+    origin: &WorldOrigin,
+    chunk_coord: ChunkCoordinate,
+) {
+    let seed = (chunk_coord.x as i64).wrapping_mul(73_856_093) ^ (chunk_coord.z as i64).wrapping_mul(19_349_663);
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed as u64 ^ 0xC10D);
+    let chunk_pos = origin.to_local(chunk_coord.world_position());
+
+    for _ in 0..CLOUD_SLOTS_PER_CHUNK {
+        let puff = CloudPuff {
+            threshold: rng.gen_range(0.0..1.0),
+            layer_offset: rng.gen_range(0.0..1.0),
+            radius: rng.gen_range(PUFF_RADIUS_MIN..PUFF_RADIUS_MAX),
+        };
+        let radius = puff.radius;
+        let x = chunk_pos.x + rng.gen_range(-0.5..0.5) * CHUNK_SIZE;
+        let z = chunk_pos.z + rng.gen_range(-0.5..0.5) * CHUNK_SIZE;
+        let y = weather.cloud_base + puff.layer_offset * weather.cloud_thickness;
+        let visible = weather.coverage > puff.threshold;
+
+        // Top-level (not a chunk child) so sprites billboard in world space;
+        // ChunkEntity + coordinate still let manage_chunks unload it with its chunk.
+        commands.spawn((
+            puff,
+            ChunkEntity,
+            chunk_coord,
+            Transform::from_xyz(x, y, z),
+            if visible { Visibility::Inherited } else { Visibility::Hidden },
+        ))
+        .with_children(|cloud| {
+            // Flattened ellipsoid of overlapping sprites; bigger ones toward the middle
+            for _ in 0..rng.gen_range(SPRITES_PER_PUFF_MIN..=SPRITES_PER_PUFF_MAX) {
+                let offset = Vec3::new(
+                    rng.gen_range(-0.7..0.7) * radius,
+                    rng.gen_range(-0.25..0.3) * radius,
+                    rng.gen_range(-0.7..0.7) * radius,
+                );
+                let centrality = 1.0 - (offset.length() / radius).min(1.0);
+                let size = radius * rng.gen_range(0.8..1.2) * (0.8 + centrality * 0.6);
+                let material = assets.materials[rng.gen_range(0..assets.materials.len())].clone();
+                cloud.spawn((
+                    CloudSprite,
+                    Mesh3d(assets.sprite_mesh.clone()),
+                    MeshMaterial3d(material),
+                    Transform::from_translation(offset).with_scale(Vec3::splat(size)),
+                    NotShadowCaster,
+                ));
+            }
+        });
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// Show/hide puffs for the current coverage and keep them inside the layer
+fn update_cloud_layer(
+    weather: Res<Weather>,
+    mut clouds: Query<(&CloudPuff, &mut Transform, &mut Visibility)>,
+) {
+    if !weather.is_changed() {
+        return;
+    }
+    for (puff, mut transform, mut visibility) in &mut clouds {
+        transform.translation.y = weather.cloud_base + puff.layer_offset * weather.cloud_thickness;
+        let target = if weather.coverage > puff.threshold { Visibility::Inherited } else { Visibility::Hidden };
+        if *visibility != target {
+            *visibility = target;
+        }
+    }
+}
+
+/// Rain clouds turn grey and denser; everything dims at night
+fn update_cloud_materials(
+    weather: Res<Weather>,
+    time_of_day: Res<TimeOfDay>,
+    assets: Option<Res<CloudAssets>>,
+    mut materials: ResMut<Assets<CloudMaterial>>,
+) {
+    let Some(assets) = assets else { return };
+    if !weather.is_changed() && !time_of_day.is_changed() {
+        return;
+    }
+    let light = 0.08 + 0.92 * time_of_day.daylight();
+    let grey = (1.0 - weather.precipitation * 0.55) * light;
+    let alpha = 0.55 + weather.precipitation * 0.2;
+    for handle in &assets.materials {
+        if let Some(material) = materials.get_mut(handle) {
+            material.base.base_color = Color::srgba(grey, grey, grey * 1.03, alpha);
+        }
+    }
+}
+
+/// Turn every visible sprite toward the camera. Puffs are never rotated, so the
+/// world-space facing can be written straight into the local rotation.
+fn face_cloud_sprites(
+    camera_query: Query<&GlobalTransform, With<Camera3d>>,
+    clouds: Query<(&Visibility, &Children), With<CloudPuff>>,
+    mut sprites: Query<(&GlobalTransform, &mut Transform), With<CloudSprite>>,
+) {
+    let Ok(camera) = camera_query.get_single() else { return };
+    let camera_pos = camera.translation();
+
+    for (visibility, children) in &clouds {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+        for &child in children {
+            let Ok((global, mut transform)) = sprites.get_mut(child) else { continue };
+            let to_camera = camera_pos - global.translation();
+            if to_camera.length_squared() > 1.0 {
+                transform.rotation = Transform::default().looking_to(-to_camera, Vec3::Y).rotation;
+            }
+        }
+    }
+}
+
+fn update_cloud_cover(
+    mut cover: ResMut<CloudCover>,
+    camera_query: Query<&GlobalTransform, With<Camera3d>>,
+    clouds: Query<(&CloudPuff, &Transform, &Visibility)>,
+) {
+    cover.puffs.clear();
+    cover.puffs.extend(
+        clouds
+            .iter()
+            .filter(|(_, _, visibility)| **visibility != Visibility::Hidden)
+            .map(|(puff, transform, _)| (transform.translation, puff.radius)),
+    );
+
+    let Ok(camera) = camera_query.get_single() else { return };
+    let camera_pos = camera.translation();
+    cover.immersion = cover
+        .puffs
+        .iter()
+        .map(|(center, radius)| 1.0 - smoothstep(radius * 0.4, *radius, camera_pos.distance(*center)))
+        .fold(0.0, f32::max);
+}
+
+/// Beads of moisture collecting on the canopy while inside a cloud
+fn emit_canopy_condensation(
+    time: Res<Time>,
+    cover: Res<CloudCover>,
+    camera_query: Query<Entity, With<Camera3d>>,
+    mut particles: EventWriter<EmitParticles>,
+    mut accumulator: Local<f32>,
+) {
+    if cover.immersion < 0.2 {
+        *accumulator = 0.0;
+        return;
+    }
+    let Ok(camera) = camera_query.get_single() else { return };
+
+    *accumulator += cover.immersion * CONDENSATION_RATE * time.delta_secs();
+    let count = accumulator.floor();
+    *accumulator -= count;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..count as u32 {
+        // Camera-local: a pane just in front of the lens
+        let offset = Vec3::new(rng.gen_range(-1.2..1.2), rng.gen_range(-0.7..0.7), -1.5);
+        particles.send(
+            EmitParticles::new(ParticleType::Condensation, offset)
+                .with_size(rng.gen_range(0.6..1.4))
+                .attached_to(camera),
+        );
+    }
+}
+
+/// Fog range and color while the camera is inside a cloud. Returns the adjusted
+/// `(visibility, color)` for `update_altitude_visuals`.
+pub fn in_cloud_fog(cover: &CloudCover, time_of_day: &TimeOfDay, visibility: f32, color: Color) -> (f32, Color) {
+    if cover.immersion <= 0.0 {
+        return (visibility, color);
+    }
+    let light = 0.1 + 0.9 * time_of_day.daylight();
+    // Keep alpha at 1.0: fog color alpha scales the whole fog effect
+    let cloud_grey = LinearRgba::rgb(0.58 * light, 0.6 * light, 0.65 * light);
+    let mixed = LinearRgba::from(color).mix(&cloud_grey, cover.immersion);
+    (
+        visibility + (IN_CLOUD_VISIBILITY - visibility) * cover.immersion,
+        Color::from(mixed),
+    )
+}
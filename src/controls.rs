@@ -0,0 +1,891 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write as _,
+    io,
+    path::Path,
+};
+
+use bevy::{input::InputSystem, prelude::*};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Bindings file, loaded at startup and rewritten by the rebinding menu
+const BINDINGS_PATH: &str = "config/bindings.cfg";
+
+/// A throttle lever has to move this much before it takes over from the keys
+const THROTTLE_TAKEOVER: f32 = 0.02;
+/// A gamepad axis has to travel this far to be picked up by the rebinding menu
+const AXIS_CAPTURE_TRAVEL: f32 = 0.5;
+
+/// Keys the bindings file understands (named as in `KeyCode`)
+const KEY_CODES: &[KeyCode] = &[
+    KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC, KeyCode::KeyD, KeyCode::KeyE, KeyCode::KeyF,
+    KeyCode::KeyG, KeyCode::KeyH, KeyCode::KeyI, KeyCode::KeyJ, KeyCode::KeyK, KeyCode::KeyL,
+    KeyCode::KeyM, KeyCode::KeyN, KeyCode::KeyO, KeyCode::KeyP, KeyCode::KeyQ, KeyCode::KeyR,
+    KeyCode::KeyS, KeyCode::KeyT, KeyCode::KeyU, KeyCode::KeyV, KeyCode::KeyW, KeyCode::KeyX,
+    KeyCode::KeyY, KeyCode::KeyZ,
+    KeyCode::Digit0, KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4,
+    KeyCode::Digit5, KeyCode::Digit6, KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
+    KeyCode::F1, KeyCode::F2, KeyCode::F3, KeyCode::F4, KeyCode::F5, KeyCode::F6,
+    KeyCode::F7, KeyCode::F8, KeyCode::F9, KeyCode::F10, KeyCode::F11, KeyCode::F12,
+    KeyCode::ArrowUp, KeyCode::ArrowDown, KeyCode::ArrowLeft, KeyCode::ArrowRight,
+    KeyCode::Space, KeyCode::Enter, KeyCode::Tab, KeyCode::Backspace, KeyCode::Delete,
+    KeyCode::Insert, KeyCode::Home, KeyCode::End, KeyCode::PageUp, KeyCode::PageDown,
+    KeyCode::ShiftLeft, KeyCode::ShiftRight, KeyCode::ControlLeft, KeyCode::ControlRight,
+    KeyCode::AltLeft, KeyCode::AltRight, KeyCode::CapsLock,
+    KeyCode::Minus, KeyCode::Equal, KeyCode::BracketLeft, KeyCode::BracketRight,
+    KeyCode::Backslash, KeyCode::Semicolon, KeyCode::Quote, KeyCode::Comma,
+    KeyCode::Period, KeyCode::Slash, KeyCode::Backquote,
+    KeyCode::Numpad0, KeyCode::Numpad1, KeyCode::Numpad2, KeyCode::Numpad3, KeyCode::Numpad4,
+    KeyCode::Numpad5, KeyCode::Numpad6, KeyCode::Numpad7, KeyCode::Numpad8, KeyCode::Numpad9,
+    KeyCode::NumpadAdd, KeyCode::NumpadSubtract, KeyCode::NumpadMultiply,
+    KeyCode::NumpadDivide, KeyCode::NumpadDecimal, KeyCode::NumpadEnter,
+];
+
+const MOUSE_BUTTONS: [MouseButton; 5] = [
+    MouseButton::Left,
+    MouseButton::Right,
+    MouseButton::Middle,
+    MouseButton::Back,
+    MouseButton::Forward,
+];
+
+// ============================================================================
+// ACTIONS & BINDINGS
+// ============================================================================
+
+/// Everything the player can do with a button
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    PitchUp,
+    PitchDown,
+    RollLeft,
+    RollRight,
+    YawLeft,
+    YawRight,
+    ThrottleUp,
+    ThrottleDown,
+    FireMissile,
+    FireGun,
+    ToggleRocket,
+    ToggleSas,
+    ToggleMouseAim,
+    ToggleGear,
+    CycleFlaps,
+    Brake,
+    CycleCamera,
+    CycleWeather,
+    TimeFaster,
+    TimeSlower,
+    ToggleLodStats,
+    Pause,
+    Restart,
+    Replay,
+    Quit,
+}
+
+impl Action {
+    pub const ALL: [Action; 25] = [
+        Action::PitchUp,
+        Action::PitchDown,
+        Action::RollLeft,
+        Action::RollRight,
+        Action::YawLeft,
+        Action::YawRight,
+        Action::ThrottleUp,
+        Action::ThrottleDown,
+        Action::FireMissile,
+        Action::FireGun,
+        Action::ToggleRocket,
+        Action::ToggleSas,
+        Action::ToggleMouseAim,
+        Action::ToggleGear,
+        Action::CycleFlaps,
+        Action::Brake,
+        Action::CycleCamera,
+        Action::CycleWeather,
+        Action::TimeFaster,
+        Action::TimeSlower,
+        Action::ToggleLodStats,
+        Action::Pause,
+        Action::Restart,
+        Action::Replay,
+        Action::Quit,
+    ];
+
+    /// Name in the bindings file
+    fn key(self) -> &'static str {
+        match self {
+            Action::PitchUp => "pitch_up",
+            Action::PitchDown => "pitch_down",
+            Action::RollLeft => "roll_left",
+            Action::RollRight => "roll_right",
+            Action::YawLeft => "yaw_left",
+            Action::YawRight => "yaw_right",
+            Action::ThrottleUp => "throttle_up",
+            Action::ThrottleDown => "throttle_down",
+            Action::FireMissile => "fire_missile",
+            Action::FireGun => "fire_gun",
+            Action::ToggleRocket => "toggle_rocket",
+            Action::ToggleSas => "toggle_sas",
+            Action::ToggleMouseAim => "toggle_mouse_aim",
+            Action::ToggleGear => "toggle_gear",
+            Action::CycleFlaps => "cycle_flaps",
+            Action::Brake => "brake",
+            Action::CycleCamera => "cycle_camera",
+            Action::CycleWeather => "cycle_weather",
+            Action::TimeFaster => "time_faster",
+            Action::TimeSlower => "time_slower",
+            Action::ToggleLodStats => "toggle_lod_stats",
+            Action::Pause => "pause",
+            Action::Restart => "restart",
+            Action::Replay => "replay",
+            Action::Quit => "quit",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Action::PitchUp => "Pitch Up",
+            Action::PitchDown => "Pitch Down",
+            Action::RollLeft => "Roll Left",
+            Action::RollRight => "Roll Right",
+            Action::YawLeft => "Yaw Left",
+            Action::YawRight => "Yaw Right",
+            Action::ThrottleUp => "Increase Throttle",
+            Action::ThrottleDown => "Decrease Throttle",
+            Action::FireMissile => "Fire Missiles",
+            Action::FireGun => "Fire Machine Gun",
+            Action::ToggleRocket => "Toggle Rocket",
+            Action::ToggleSas => "Toggle SAS",
+            Action::ToggleMouseAim => "Toggle Mouse Aim",
+            Action::ToggleGear => "Landing Gear Up/Down",
+            Action::CycleFlaps => "Cycle Flaps",
+            Action::Brake => "Wheel Brakes / Airbrake",
+            Action::CycleCamera => "Cycle Camera View",
+            Action::CycleWeather => "Cycle Weather",
+            Action::TimeFaster => "Speed Up Time of Day",
+            Action::TimeSlower => "Slow Down Time of Day",
+            Action::ToggleLodStats => "LOD Stats Report",
+            Action::Pause => "Pause",
+            Action::Restart => "Restart Game",
+            Action::Replay => "Instant Replay",
+            Action::Quit => "Quit",
+        }
+    }
+}
+
+/// Control axes a stick, pedal or lever can drive directly
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FlightAxis {
+    /// Stick forward = nose down (negative), like the keys
+    Pitch,
+    /// Stick right = roll right (positive)
+    Roll,
+    /// Stick/pedal right = yaw right (negative, matching `PlayerInput::yaw`)
+    Yaw,
+    /// Lever 0..1
+    Throttle,
+}
+
+impl FlightAxis {
+    pub const ALL: [FlightAxis; 4] = [FlightAxis::Pitch, FlightAxis::Roll, FlightAxis::Yaw, FlightAxis::Throttle];
+
+    fn key(self) -> &'static str {
+        match self {
+            FlightAxis::Pitch => "axis.pitch",
+            FlightAxis::Roll => "axis.roll",
+            FlightAxis::Yaw => "axis.yaw",
+            FlightAxis::Throttle => "axis.throttle",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            FlightAxis::Pitch => "Pitch Axis",
+            FlightAxis::Roll => "Roll Axis",
+            FlightAxis::Yaw => "Yaw Axis",
+            FlightAxis::Throttle => "Throttle Axis",
+        }
+    }
+
+    /// Converts the device's sign convention to the flight model's
+    fn sign(self) -> f32 {
+        match self {
+            FlightAxis::Pitch | FlightAxis::Yaw => -1.0,
+            FlightAxis::Roll | FlightAxis::Throttle => 1.0,
+        }
+    }
+}
+
+/// One physical button
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Binding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+}
+
+impl Binding {
+    fn to_config(self) -> String {
+        match self {
+            Binding::Key(key) => format!("key:{:?}", key),
+            Binding::Mouse(button) => format!("mouse:{:?}", button),
+            Binding::Gamepad(button) => format!("pad:{:?}", button),
+        }
+    }
+
+    fn parse(text: &str) -> Option<Binding> {
+        let (device, name) = text.split_once(':')?;
+        match device {
+            "key" => KEY_CODES.iter().find(|key| format!("{:?}", key) == name).map(|&key| Binding::Key(key)),
+            "mouse" => MOUSE_BUTTONS.iter().find(|button| format!("{:?}", button) == name).map(|&button| Binding::Mouse(button)),
+            "pad" => parse_gamepad_button(name).map(Binding::Gamepad),
+            _ => None,
+        }
+    }
+
+    /// Short name for the banner and the rebinding menu
+    fn describe(self) -> String {
+        match self {
+            Binding::Key(key) => {
+                let name = format!("{:?}", key);
+                name.strip_prefix("Key").or_else(|| name.strip_prefix("Digit")).unwrap_or(&name).to_string()
+            }
+            Binding::Mouse(MouseButton::Left) => "LMB".to_string(),
+            Binding::Mouse(MouseButton::Right) => "RMB".to_string(),
+            Binding::Mouse(MouseButton::Middle) => "MMB".to_string(),
+            Binding::Mouse(button) => format!("Mouse {:?}", button),
+            Binding::Gamepad(button) => format!("Pad {:?}", button),
+        }
+    }
+
+    fn is_gamepad(self) -> bool {
+        matches!(self, Binding::Gamepad(_))
+    }
+}
+
+fn parse_gamepad_button(name: &str) -> Option<GamepadButton> {
+    GamepadButton::all().into_iter().find(|button| format!("{:?}", button) == name)
+}
+
+/// Where an analog axis reads from: a stick/lever, or an analog trigger
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnalogSource {
+    Axis(GamepadAxis),
+    Button(GamepadButton),
+}
+
+/// A gamepad or joystick axis bound to a `FlightAxis`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AxisBinding {
+    pub source: AnalogSource,
+    pub invert: bool,
+    /// Travel around the center (or the lever's idle stop) that reads as zero
+    pub deadzone: f32,
+    /// Response exponent: 1.0 is linear, higher gives finer control near center
+    pub curve: f32,
+}
+
+impl AxisBinding {
+    fn new(source: AnalogSource) -> Self {
+        Self { source, invert: false, deadzone: 0.1, curve: 1.5 }
+    }
+
+    /// Raw device value to -1..1 (0..1 for the throttle)
+    fn shape(&self, axis: FlightAxis, raw: f32) -> f32 {
+        let raw = if self.invert { -raw } else { raw };
+        if axis == FlightAxis::Throttle {
+            // Levers travel -1..1, triggers 0..1
+            let lever = match self.source {
+                AnalogSource::Axis(_) => (raw + 1.0) * 0.5,
+                AnalogSource::Button(_) if self.invert => 1.0 + raw,
+                AnalogSource::Button(_) => raw,
+            };
+            let travel = ((lever - self.deadzone) / (1.0 - self.deadzone).max(0.01)).clamp(0.0, 1.0);
+            return travel.powf(self.curve);
+        }
+        let travel = ((raw.abs() - self.deadzone) / (1.0 - self.deadzone).max(0.01)).clamp(0.0, 1.0);
+        travel.powf(self.curve) * raw.signum() * axis.sign()
+    }
+
+    fn to_config(self) -> String {
+        let source = match self.source {
+            AnalogSource::Axis(axis) => format!("pad_axis:{:?}", axis),
+            AnalogSource::Button(button) => format!("pad:{:?}", button),
+        };
+        format!("{}{} deadzone={:.2} curve={:.2}", source, if self.invert { " invert" } else { "" }, self.deadzone, self.curve)
+    }
+
+    fn parse(text: &str) -> Option<AxisBinding> {
+        let mut words = text.split_whitespace();
+        let (device, name) = words.next()?.split_once(':')?;
+        let source = match device {
+            "pad_axis" => AnalogSource::Axis(GamepadAxis::all().into_iter().find(|axis| format!("{:?}", axis) == name)?),
+            "pad" => AnalogSource::Button(parse_gamepad_button(name)?),
+            _ => return None,
+        };
+        let mut binding = AxisBinding::new(source);
+        for word in words {
+            match word.split_once('=') {
+                None if word == "invert" => binding.invert = true,
+                Some(("deadzone", value)) => binding.deadzone = value.parse::<f32>().ok()?.clamp(0.0, 0.9),
+                Some(("curve", value)) => binding.curve = value.parse::<f32>().ok()?.clamp(0.25, 4.0),
+                _ => return None,
+            }
+        }
+        Some(binding)
+    }
+
+    fn describe(&self) -> String {
+        let source = match self.source {
+            AnalogSource::Axis(axis) => format!("{:?}", axis),
+            AnalogSource::Button(button) => format!("{:?}", button),
+        };
+        format!("{}{}  dz {:.2}  curve {:.2}", source, if self.invert { " (inv)" } else { "" }, self.deadzone, self.curve)
+    }
+}
+
+/// The player's control layout
+#[derive(Resource, Clone)]
+pub struct Bindings {
+    pub actions: HashMap<Action, Vec<Binding>>,
+    pub axes: HashMap<FlightAxis, AxisBinding>,
+}
+
+impl Default for Bindings {
+    fn default() -> Self {
+        use Binding::{Gamepad as Pad, Key, Mouse};
+        let actions = HashMap::from([
+            (Action::PitchUp, vec![Key(KeyCode::KeyS)]),
+            (Action::PitchDown, vec![Key(KeyCode::KeyW)]),
+            (Action::RollLeft, vec![Key(KeyCode::KeyA)]),
+            (Action::RollRight, vec![Key(KeyCode::KeyD)]),
+            (Action::YawLeft, vec![Key(KeyCode::KeyQ), Pad(GamepadButton::LeftTrigger)]),
+            (Action::YawRight, vec![Key(KeyCode::KeyE), Pad(GamepadButton::RightTrigger)]),
+            (Action::ThrottleUp, vec![Key(KeyCode::ShiftLeft), Pad(GamepadButton::DPadUp)]),
+            (Action::ThrottleDown, vec![Key(KeyCode::ControlLeft), Pad(GamepadButton::DPadDown)]),
+            (Action::FireMissile, vec![Key(KeyCode::Space), Mouse(MouseButton::Right), Pad(GamepadButton::LeftTrigger2)]),
+            (Action::FireGun, vec![Mouse(MouseButton::Left), Pad(GamepadButton::RightTrigger2)]),
+            (Action::ToggleRocket, vec![Key(KeyCode::KeyR), Pad(GamepadButton::North)]),
+            (Action::ToggleSas, vec![Key(KeyCode::KeyK), Pad(GamepadButton::West)]),
+            (Action::ToggleMouseAim, vec![Key(KeyCode::KeyM)]),
+            (Action::ToggleGear, vec![Key(KeyCode::KeyG), Pad(GamepadButton::DPadLeft)]),
+            (Action::CycleFlaps, vec![Key(KeyCode::KeyF), Pad(GamepadButton::DPadRight)]),
+            (Action::Brake, vec![Key(KeyCode::KeyB), Pad(GamepadButton::East)]),
+            (Action::CycleCamera, vec![Key(KeyCode::KeyC), Pad(GamepadButton::Select)]),
+            (Action::CycleWeather, vec![Key(KeyCode::F6)]),
+            (Action::TimeFaster, vec![Key(KeyCode::BracketRight)]),
+            (Action::TimeSlower, vec![Key(KeyCode::BracketLeft)]),
+            (Action::ToggleLodStats, vec![Key(KeyCode::F3)]),
+            (Action::Pause, vec![Key(KeyCode::KeyP), Pad(GamepadButton::Start)]),
+            (Action::Restart, vec![Key(KeyCode::F5)]),
+            (Action::Replay, vec![Key(KeyCode::F8)]),
+            (Action::Quit, vec![Key(KeyCode::F10)]),
+        ]);
+        // Throttle is left unbound: levers show up on different axes on every HOTAS
+        let axes = HashMap::from([
+            (FlightAxis::Pitch, AxisBinding::new(AnalogSource::Axis(GamepadAxis::LeftStickY))),
+            (FlightAxis::Roll, AxisBinding::new(AnalogSource::Axis(GamepadAxis::LeftStickX))),
+            (FlightAxis::Yaw, AxisBinding::new(AnalogSource::Axis(GamepadAxis::RightStickX))),
+        ]);
+        Self { actions, axes }
+    }
+}
+
+impl Bindings {
+    /// Defaults overlaid with whatever the file sets. A missing file is written
+    /// out with the defaults so there is something to edit.
+    fn load(path: &Path) -> Bindings {
+        let mut bindings = Bindings::default();
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                match bindings.save(path) {
+                    Ok(()) => println!("🎮 CONTROLS: Wrote default bindings to {}", path.display()),
+                    Err(err) => eprintln!("⚠️  CONTROLS: Could not write {}: {}", path.display(), err),
+                }
+                return bindings;
+            }
+            Err(err) => {
+                eprintln!("⚠️  CONTROLS: Could not read {}: {} - using defaults", path.display(), err);
+                return bindings;
+            }
+        };
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((name, value)) = line.split_once('=') else {
+                eprintln!("⚠️  CONTROLS: {}:{}: expected `name = binding`", path.display(), number + 1);
+                continue;
+            };
+            let (name, value) = (name.trim(), value.trim());
+
+            if let Some(axis) = FlightAxis::ALL.into_iter().find(|axis| axis.key() == name) {
+                if value.is_empty() {
+                    bindings.axes.remove(&axis);
+                } else if let Some(binding) = AxisBinding::parse(value) {
+                    bindings.axes.insert(axis, binding);
+                } else {
+                    eprintln!("⚠️  CONTROLS: {}:{}: bad axis binding `{}`", path.display(), number + 1, value);
+                }
+            } else if let Some(action) = Action::ALL.into_iter().find(|action| action.key() == name) {
+                let mut parsed = Vec::new();
+                for item in value.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+                    match Binding::parse(item) {
+                        Some(binding) => parsed.push(binding),
+                        None => eprintln!("⚠️  CONTROLS: {}:{}: unknown binding `{}`", path.display(), number + 1, item),
+                    }
+                }
+                // An empty value unbinds; a line with nothing usable keeps the default
+                if parsed.is_empty() && !value.is_empty() {
+                    continue;
+                }
+                bindings.actions.insert(action, parsed);
+            } else {
+                eprintln!("⚠️  CONTROLS: {}:{}: unknown action `{}`", path.display(), number + 1, name);
+            }
+        }
+        println!("🎮 CONTROLS: Loaded bindings from {}", path.display());
+        bindings
+    }
+
+    fn save(&self, path: &Path) -> io::Result<()> {
+        let mut text = String::new();
+        text.push_str("# ViperEye control bindings (F1 in game to rebind)\n");
+        text.push_str("# Buttons: action = key:<KeyCode>, mouse:<Left|Right|Middle|Back|Forward>, pad:<GamepadButton>\n");
+        text.push_str("# Axes:    axis.<name> = pad_axis:<GamepadAxis> | pad:<analog trigger> [invert] [deadzone=0.10] [curve=1.50]\n\n");
+        for action in Action::ALL {
+            let bound: Vec<String> = self.bound(action).iter().map(|binding| binding.to_config()).collect();
+            let _ = writeln!(text, "{} = {}", action.key(), bound.join(", "));
+        }
+        text.push('\n');
+        for axis in FlightAxis::ALL {
+            let bound = self.axes.get(&axis).map(|binding| binding.to_config()).unwrap_or_default();
+            let _ = writeln!(text, "{} = {}", axis.key(), bound);
+        }
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, text)
+    }
+
+    pub fn bound(&self, action: Action) -> &[Binding] {
+        self.actions.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Keyboard/mouse bindings of an action for display, e.g. "Space/RMB"
+    pub fn describe(&self, action: Action) -> String {
+        let names: Vec<String> = self.bound(action).iter()
+            .filter(|binding| !binding.is_gamepad())
+            .map(|binding| binding.describe())
+            .collect();
+        if names.is_empty() { "-".to_string() } else { names.join("/") }
+    }
+
+    fn describe_all(&self, action: Action) -> String {
+        let names: Vec<String> = self.bound(action).iter().map(|binding| binding.describe()).collect();
+        if names.is_empty() { "(unbound)".to_string() } else { names.join(", ") }
+    }
+}
+
+// ============================================================================
+// ACTION STATE
+// ============================================================================
+
+/// This frame's controls, resolved from every bound device
+#[derive(Resource, Default)]
+pub struct ActionState {
+    pressed: HashSet<Action>,
+    just_pressed: HashSet<Action>,
+    axes: HashMap<FlightAxis, f32>,
+    /// Lever position the throttle last followed
+    throttle_lever: Option<f32>,
+}
+
+impl ActionState {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.pressed.contains(&action)
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.just_pressed.contains(&action)
+    }
+
+    /// +1 / -1 / 0 from a pair of buttons
+    pub fn digital_axis(&self, positive: Action, negative: Action) -> f32 {
+        self.pressed(positive) as i32 as f32 - self.pressed(negative) as i32 as f32
+    }
+
+    /// Analog value of an axis, when a bound stick is out of its deadzone (or,
+    /// for the throttle, when the lever has just been moved)
+    pub fn axis(&self, axis: FlightAxis) -> Option<f32> {
+        self.axes.get(&axis).copied()
+    }
+}
+
+// ============================================================================
+// REBINDING MENU
+// ============================================================================
+
+/// One line of the rebinding menu
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MenuRow {
+    Action(Action),
+    Axis(FlightAxis),
+}
+
+impl MenuRow {
+    fn all() -> impl Iterator<Item = MenuRow> {
+        Action::ALL.into_iter().map(MenuRow::Action).chain(FlightAxis::ALL.into_iter().map(MenuRow::Axis))
+    }
+
+    fn count() -> usize {
+        Action::ALL.len() + FlightAxis::ALL.len()
+    }
+}
+
+/// `F1` overlay for rebinding controls
+#[derive(Resource, Default)]
+pub struct RebindMenu {
+    open: bool,
+    selected: usize,
+    /// Waiting for the player to press the new button / move the new axis
+    listening: bool,
+    /// Axis positions when listening started, to tell a deliberate move from a resting offset
+    axis_rest: Vec<(Entity, GamepadAxis, f32)>,
+    message: String,
+}
+
+impl RebindMenu {
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Bring the overlay up from another menu
+    pub fn open(&mut self) {
+        self.open = true;
+        self.listening = false;
+        self.message.clear();
+    }
+}
+
+#[derive(Component)]
+struct RebindMenuText;
+
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Bindings::load(Path::new(BINDINGS_PATH)))
+            .init_resource::<ActionState>()
+            .init_resource::<RebindMenu>()
+            .add_systems(Startup, setup_rebind_menu)
+            .add_systems(PreUpdate, update_action_state.after(InputSystem))
+            .add_systems(Update, (rebind_menu_input, update_rebind_menu).chain());
+    }
+}
+
+/// Resolve bindings against keyboard, mouse and every connected gamepad/joystick
+fn update_action_state(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+    bindings: Res<Bindings>,
+    menu: Res<RebindMenu>,
+    mut state: ResMut<ActionState>,
+) {
+    state.pressed.clear();
+    state.just_pressed.clear();
+    state.axes.clear();
+    // The menu swallows all input while it is open
+    if menu.open {
+        return;
+    }
+
+    for action in Action::ALL {
+        for binding in bindings.bound(action) {
+            let (pressed, just_pressed) = match *binding {
+                Binding::Key(key) => (keyboard.pressed(key), keyboard.just_pressed(key)),
+                Binding::Mouse(button) => (mouse.pressed(button), mouse.just_pressed(button)),
+                Binding::Gamepad(button) => gamepads.iter().fold((false, false), |(pressed, just), gamepad| {
+                    (pressed || gamepad.pressed(button), just || gamepad.just_pressed(button))
+                }),
+            };
+            if pressed {
+                state.pressed.insert(action);
+            }
+            if just_pressed {
+                state.just_pressed.insert(action);
+            }
+        }
+    }
+
+    for (&axis, binding) in &bindings.axes {
+        // Stick and throttle often live on separate devices: take whichever reports
+        let raw = gamepads.iter().filter_map(|gamepad| match binding.source {
+            AnalogSource::Axis(source) => gamepad.get(source),
+            AnalogSource::Button(source) => gamepad.get(source),
+        });
+        if axis == FlightAxis::Throttle {
+            let Some(lever) = raw.map(|value| binding.shape(axis, value)).next() else { continue };
+            let moved = state.throttle_lever.is_none_or(|last| (lever - last).abs() > THROTTLE_TAKEOVER);
+            if moved {
+                state.throttle_lever = Some(lever);
+                state.axes.insert(axis, lever);
+            }
+        } else if let Some(value) = raw.map(|value| binding.shape(axis, value)).max_by(|a, b| a.abs().total_cmp(&b.abs())) {
+            if value != 0.0 {
+                state.axes.insert(axis, value);
+            }
+        }
+    }
+}
+
+fn setup_rebind_menu(mut commands: Commands) {
+    commands.spawn((
+        RebindMenuText,
+        Text::new(""),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.0, 1.0, 0.0)), // HUD Green
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(60.0),
+            left: Val::Percent(30.0),
+            padding: UiRect::all(Val::Px(12.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        Visibility::Hidden,
+        GlobalZIndex(10),
+    ));
+}
+
+/// `F1` opens the menu. Up/Down select, Enter rebinds (press the new button or
+/// move the new axis), Backspace clears; on axes `I` inverts, Left/Right set the
+/// deadzone and `-`/`=` the curve. Esc cancels or closes. Changes save at once.
+pub fn rebind_menu_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    mut menu: ResMut<RebindMenu>,
+    mut bindings: ResMut<Bindings>,
+) {
+    if keyboard.just_pressed(KeyCode::F1) && !menu.listening {
+        menu.open = !menu.open;
+        menu.message.clear();
+        return;
+    }
+    if !menu.open {
+        return;
+    }
+
+    let row = MenuRow::all().nth(menu.selected).unwrap_or(MenuRow::Action(Action::PitchUp));
+    let mut changed = false;
+
+    if menu.listening {
+        if keyboard.just_pressed(KeyCode::Escape) {
+            menu.listening = false;
+            menu.message = "Cancelled".to_string();
+            return;
+        }
+        match row {
+            MenuRow::Action(action) => {
+                let captured = keyboard.get_just_pressed().find_map(|&key| Binding::parse(&Binding::Key(key).to_config()))
+                    .or_else(|| mouse.get_just_pressed().find_map(|&button| Binding::parse(&Binding::Mouse(button).to_config())))
+                    .or_else(|| gamepads.iter().find_map(|(_, gamepad)| gamepad.digital().get_just_pressed().next().map(|&button| Binding::Gamepad(button))));
+                let Some(binding) = captured else { return };
+
+                // Replaces the binding from the same kind of device, keeps the others
+                let list = bindings.actions.entry(action).or_default();
+                list.retain(|existing| existing.is_gamepad() != binding.is_gamepad());
+                list.push(binding);
+                let clashes: Vec<&str> = Action::ALL.into_iter()
+                    .filter(|&other| other != action && bindings.bound(other).contains(&binding))
+                    .map(Action::label)
+                    .collect();
+                menu.message = if clashes.is_empty() {
+                    format!("{} -> {}", action.label(), binding.describe())
+                } else {
+                    format!("{} -> {} (also {})", action.label(), binding.describe(), clashes.join(", "))
+                };
+                menu.listening = false;
+                changed = true;
+            }
+            MenuRow::Axis(axis) => {
+                let rest = &menu.axis_rest;
+                let moved = gamepads.iter().flat_map(|(entity, gamepad)| {
+                    GamepadAxis::all().into_iter().filter_map(move |source| {
+                        let value = gamepad.get(source)?;
+                        let start = rest.iter().find(|(e, a, _)| *e == entity && *a == source).map_or(0.0, |(_, _, v)| *v);
+                        ((value - start).abs() > AXIS_CAPTURE_TRAVEL).then_some(source)
+                    })
+                }).next();
+                let Some(source) = moved else { return };
+
+                let binding = bindings.axes.entry(axis).or_insert(AxisBinding::new(AnalogSource::Axis(source)));
+                binding.source = AnalogSource::Axis(source);
+                menu.message = format!("{} -> {:?}", axis.label(), source);
+                menu.listening = false;
+                changed = true;
+            }
+        }
+    } else {
+        if keyboard.just_pressed(KeyCode::Escape) {
+            menu.open = false;
+            return;
+        }
+        if keyboard.just_pressed(KeyCode::ArrowUp) {
+            menu.selected = (menu.selected + MenuRow::count() - 1) % MenuRow::count();
+        }
+        if keyboard.just_pressed(KeyCode::ArrowDown) {
+            menu.selected = (menu.selected + 1) % MenuRow::count();
+        }
+        if keyboard.just_pressed(KeyCode::Enter) {
+            menu.listening = true;
+            menu.axis_rest = gamepads.iter().flat_map(|(entity, gamepad)| {
+                GamepadAxis::all().into_iter().filter_map(move |source| Some((entity, source, gamepad.get(source)?)))
+            }).collect();
+            menu.message = match row {
+                MenuRow::Action(_) => "Press a key, mouse button or gamepad button (Esc cancels)".to_string(),
+                MenuRow::Axis(_) => "Move the stick or lever all the way (Esc cancels)".to_string(),
+            };
+        }
+        if keyboard.just_pressed(KeyCode::Backspace) || keyboard.just_pressed(KeyCode::Delete) {
+            match row {
+                MenuRow::Action(action) => {
+                    bindings.actions.insert(action, Vec::new());
+                }
+                MenuRow::Axis(axis) => {
+                    bindings.axes.remove(&axis);
+                }
+            }
+            changed = true;
+        }
+        if let MenuRow::Axis(axis) = row {
+            if let Some(binding) = bindings.axes.get_mut(&axis) {
+                if keyboard.just_pressed(KeyCode::KeyI) {
+                    binding.invert = !binding.invert;
+                    changed = true;
+                }
+                for (key, step) in [(KeyCode::ArrowLeft, -0.05), (KeyCode::ArrowRight, 0.05)] {
+                    if keyboard.just_pressed(key) {
+                        binding.deadzone = (binding.deadzone + step).clamp(0.0, 0.9);
+                        changed = true;
+                    }
+                }
+                for (key, step) in [(KeyCode::Minus, -0.25), (KeyCode::Equal, 0.25)] {
+                    if keyboard.just_pressed(key) {
+                        binding.curve = (binding.curve + step).clamp(0.25, 4.0);
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+
+    if changed {
+        if let Err(err) = bindings.save(Path::new(BINDINGS_PATH)) {
+            menu.message = format!("Could not save {}: {}", BINDINGS_PATH, err);
+            eprintln!("⚠️  CONTROLS: Could not save {}: {}", BINDINGS_PATH, err);
+        }
+    }
+}
+
+fn update_rebind_menu(
+    menu: Res<RebindMenu>,
+    bindings: Res<Bindings>,
+    mut text_query: Query<(&mut Text, &mut Visibility), With<RebindMenuText>>,
+) {
+    if !menu.is_changed() && !bindings.is_changed() {
+        return;
+    }
+    let Ok((mut text, mut visibility)) = text_query.get_single_mut() else { return };
+    *visibility = if menu.open { Visibility::Visible } else { Visibility::Hidden };
+    if !menu.open {
+        return;
+    }
+
+    let mut listing = String::from("CONTROLS  (Enter rebind, Backspace clear, Esc close)\n\n");
+    for (index, row) in MenuRow::all().enumerate() {
+        let marker = if index == menu.selected { if menu.listening { "…" } else { ">" } } else { " " };
+        let (label, bound) = match row {
+            MenuRow::Action(action) => (action.label(), bindings.describe_all(action)),
+            MenuRow::Axis(axis) => (
+                axis.label(),
+                bindings.axes.get(&axis).map_or("(unbound)".to_string(), AxisBinding::describe),
+            ),
+        };
+        let _ = writeln!(listing, "{} {:<18} {}", marker, label, bound);
+    }
+    listing.push_str("\nAxes: I invert, Left/Right deadzone, -/= curve\n");
+    listing.push_str(&menu.message);
+    text.0 = listing;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scratch bindings file, unique per test so they can run in parallel
+    fn scratch_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("vipereye-{}-{}.cfg", name, std::process::id()))
+    }
+
+    #[test]
+    fn bindings_round_trip_through_the_config_file() {
+        let mut bindings = Bindings::default();
+        bindings.actions.insert(Action::FireGun, vec![Binding::Key(KeyCode::KeyG), Binding::Mouse(MouseButton::Back)]);
+        bindings.actions.insert(Action::ToggleLodStats, Vec::new());
+        bindings.axes.insert(FlightAxis::Throttle, AxisBinding {
+            source: AnalogSource::Button(GamepadButton::RightTrigger2),
+            invert: true,
+            deadzone: 0.25,
+            curve: 2.0,
+        });
+        bindings.axes.remove(&FlightAxis::Yaw);
+
+        let path = scratch_path("round-trip");
+        bindings.save(&path).expect("bindings should save");
+        let loaded = Bindings::load(&path);
+        let _ = std::fs::remove_file(&path);
+
+        for action in Action::ALL {
+            assert_eq!(loaded.bound(action), bindings.bound(action), "{:?}", action);
+        }
+        assert_eq!(loaded.axes, bindings.axes);
+    }
+
+    #[test]
+    fn malformed_lines_fall_back_to_the_default_binding() {
+        let path = scratch_path("malformed");
+        std::fs::write(&path, [
+            "fire_gun = key:NotAKey",
+            "brake",
+            "toggle_sas = key:KeyJ",
+            "warp_drive = key:KeyZ",
+            "axis.pitch = pad_axis:LeftStickY deadzone=lots",
+            "axis.roll = trackball:X",
+            "cycle_camera =",
+        ].join("\n")).expect("scratch file should write");
+        let loaded = Bindings::load(&path);
+        let _ = std::fs::remove_file(&path);
+
+        let defaults = Bindings::default();
+        for action in [Action::FireGun, Action::Brake] {
+            assert_eq!(loaded.bound(action), defaults.bound(action), "{:?}", action);
+        }
+        assert_eq!(loaded.bound(Action::ToggleSas), [Binding::Key(KeyCode::KeyJ)]);
+        assert!(loaded.bound(Action::CycleCamera).is_empty(), "an empty value unbinds");
+        assert_eq!(loaded.axes, defaults.axes);
+    }
+}
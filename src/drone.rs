@@ -1,6 +1,10 @@
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
 use avian3d::prelude::*;
 use crate::{PlayerPlane, GameState, Meteor};
+use crate::lod::{LodCategory, LodGroup, LodVariant};
+use crate::weather::Weather;
+use crate::clouds::CloudCover;
+use crate::origin::WorldOrigin;
 
 // ============================================================================
 // RESOURCES
@@ -118,7 +122,8 @@ impl Plugin for DronePlugin {
                     drone_projectile_system,  // Projectile lifetime
                     missile_cleanup,          // Sync active missile count
                 )
-                    .chain(),
+                    .chain()
+                    .run_if(in_state(GameState::Playing)), // Frozen while paused or replaying
             );
     }
 }
@@ -139,6 +144,7 @@ pub fn spawn_drone(
         DroneWeapons::default(),
         DroneState::Patrol,
         DronePhysics::default(),
+        LodGroup::new(LodCategory::Drone),
         Transform {
             translation: position,
             rotation: Quat::from_rotation_y(std::f32::consts::PI),
@@ -156,12 +162,15 @@ pub fn spawn_drone(
     .with_children(|parent| {
         // SceneRoot for 3D model
         parent.spawn((
+            LodVariant::levels(0, 1),
             SceneRoot(drone_scene_handle),
             Transform::from_scale(Vec3::splat(15.0)),
         ));
         
         // VISUAL FALLBACK: Smaller red cube (semi-transparent to see model through)
+        // Doubles as the far LOD: a glowing marker is easier to spot than a tiny model
         parent.spawn((
+            LodVariant::levels(0, 3),
             Mesh3d(meshes.add(Cuboid::new(3.0, 1.5, 4.5))),
             MeshMaterial3d(materials.add(StandardMaterial {
                 base_color: Color::srgba(0.8, 0.1, 0.1, 0.5), // Semi-transparent red
@@ -192,6 +201,8 @@ pub fn spawn_beaver_drone(
 fn move_drones(
     mut commands: Commands,
     time: Res<Time>,
+    weather: Res<Weather>,
+    origin: Res<WorldOrigin>,
     mut drone_query: Query<(Entity, &mut Transform, &Drone), (With<KamikazeBehavior>, Without<PlayerPlane>)>,
     player_query: Query<(&Transform, &LinearVelocity), With<PlayerPlane>>,
     meteor_query: Query<&Transform, (With<Meteor>, Without<Drone>)>,
@@ -329,7 +340,10 @@ fn move_drones(
         };
 
         let move_vec = forward * drone.speed * speed_mult * delta_secs;
-        transform.translation += move_vec;
+        // Drones are light: they drift with the wind and get tossed by gusts.
+        // Sampled in absolute space like the player's, so a recenter doesn't reshuffle the gusts.
+        let wind_drift = weather.wind_at(origin.to_absolute(transform.translation).as_vec3(), elapsed) * delta_secs;
+        transform.translation += move_vec + wind_drift;
 
         if distance_to_player > 15000.0 {
             commands.entity(entity).despawn_recursive();
@@ -341,6 +355,21 @@ fn move_drones(
 // WEAPON SYSTEM
 // ============================================================================
 
+/// What the drones' sensors can tell about the player: where it is, and whether
+/// a cloud sits between it and a drone
+#[derive(SystemParam)]
+struct DroneSensors<'w, 's> {
+    player_query: Query<'w, 's, &'static GlobalTransform, With<PlayerPlane>>,
+    cloud_cover: Res<'w, CloudCover>,
+}
+
+impl DroneSensors<'_, '_> {
+    /// No radar/visual lock through a cloud
+    fn can_lock(&self, from: Vec3, to: Vec3) -> bool {
+        !self.cloud_cover.blocks_line_of_sight(from, to)
+    }
+}
+
 /// Handle drone weapon firing logic
 fn drone_weapon_system(
     mut drone_query: Query<
@@ -353,7 +382,7 @@ fn drone_weapon_system(
         ),
         With<Drone>,
     >,
-    player_query: Query<&GlobalTransform, With<PlayerPlane>>,
+    sensors: DroneSensors,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
@@ -369,9 +398,9 @@ fn drone_weapon_system(
         }
     }
     
-    let Ok(player_transform) = player_query.get_single() else { 
+    let Ok(player_transform) = sensors.player_query.get_single() else { 
         // More visible error message
-        eprintln!("❌ WEAPON: No player found (query returned {} results)", player_query.iter().count());
+        eprintln!("❌ WEAPON: No player found (query returned {} results)", sensors.player_query.iter().count());
         return 
     };
     let player_pos = player_transform.translation();
@@ -408,6 +437,13 @@ fn drone_weapon_system(
         weapons.missile_cooldown.tick(time.delta());
         weapons.gun_cooldown.tick(time.delta());
 
+        if !sensors.can_lock(drone_pos, player_pos) {
+            if should_log {
+                eprintln!("   ☁️ Lock blocked by cloud");
+            }
+            continue;
+        }
+
         // MISSILE FIRING (medium-long range, relaxed angle)
         let missile_range_ok = distance > 800.0 && distance < 2000.0;
         let missile_angle_ok = angle_deg < 45.0;
@@ -566,6 +602,7 @@ fn drone_projectile_system(
     mut missile_query: Query<(Entity, &GlobalTransform, &mut Missile, &mut LinearVelocity), Without<Bullet>>,
     mut bullet_query: Query<(Entity, &mut Bullet), Without<Missile>>,
     player_query: Query<&GlobalTransform, With<PlayerPlane>>,
+    cloud_cover: Res<CloudCover>,
     mut commands: Commands,
     time: Res<Time>,
 ) {
@@ -579,9 +616,13 @@ fn drone_projectile_system(
     for (missile_entity, missile_transform, mut missile, mut velocity) in missile_query.iter_mut() {
         let missile_pos = missile_transform.translation();
 
-        // Pure pursuit: steer directly at player
+        // Pure pursuit: steer directly at player (seeker loses the lock behind clouds)
         let to_player = (player_pos - missile_pos).normalize_or_zero();
-        let steering_force = to_player * 5.0; // Aggressive steering
+        let steering_force = if cloud_cover.blocks_line_of_sight(missile_pos, player_pos) {
+            Vec3::ZERO
+        } else {
+            to_player * 5.0 // Aggressive steering
+        };
 
         // Update velocity
         velocity.0 = (velocity.0 + steering_force).clamp_length_max(150.0);
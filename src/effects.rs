@@ -0,0 +1,412 @@
+use bevy::{pbr::NotShadowCaster, prelude::*};
+use avian3d::prelude::*;
+use rand::Rng;
+use std::collections::HashMap;
+use crate::{
+    graphics::GraphicsSettings,
+    particles::{EmitParticles, ParticleEmitter, ParticleType},
+    GameState, world_active,
+};
+
+// ============================================================================
+// EFFECT LIBRARY
+// ============================================================================
+
+/// Named destruction effects. Every hit path fires one of these through `SpawnEffect`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum EffectType {
+    /// Something blown apart in the air (drones, missiles): no wreck left behind
+    AirExplosion,
+    /// Ground target destroyed: leaves a burning wreck and a smoke column
+    GroundExplosion,
+    /// The player's jet hitting the ground at speed
+    AircraftCrash,
+}
+
+/// Trigger an effect. `size` scales every layer (1.0 = drone kill, ~30 m fireball).
+#[derive(Event, Clone, Copy)]
+pub struct SpawnEffect {
+    pub effect: EffectType,
+    pub position: Vec3,
+    pub size: f32,
+}
+
+impl SpawnEffect {
+    pub fn new(effect: EffectType, position: Vec3, size: f32) -> Self {
+        Self { effect, position, size }
+    }
+}
+
+/// Burst of fire puffs
+#[derive(Clone, Copy, Debug)]
+pub struct FireballDef {
+    pub puffs: u32,
+    pub radius: f32,
+}
+
+/// Rising smoke from the impact point
+#[derive(Clone, Copy, Debug)]
+pub struct SmokeColumnDef {
+    pub duration: f32,
+    pub rate: f32,
+    pub puff_size: f32,
+}
+
+/// Expanding ring along the ground / through the air
+#[derive(Clone, Copy, Debug)]
+pub struct ShockwaveDef {
+    pub radius: f32,
+    pub duration: f32,
+}
+
+/// Short point-light flash
+#[derive(Clone, Copy, Debug)]
+pub struct FlashDef {
+    pub intensity: f32,
+    pub range: f32,
+    pub duration: f32,
+}
+
+/// Charred hulk that keeps burning
+#[derive(Clone, Copy, Debug)]
+pub struct BurningWreckDef {
+    pub duration: f32,
+    pub hulk_size: f32,
+}
+
+/// Physics chunks thrown out of the blast
+#[derive(Clone, Copy, Debug)]
+pub struct DebrisDef {
+    pub count: u32,
+    pub speed: f32,
+    pub lifetime: f32,
+}
+
+/// Layers that make up one effect. Distances/sizes are for `size = 1.0`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EffectDef {
+    pub fireball: Option<FireballDef>,
+    pub smoke_column: Option<SmokeColumnDef>,
+    pub shockwave: Option<ShockwaveDef>,
+    pub flash: Option<FlashDef>,
+    pub burning_wreck: Option<BurningWreckDef>,
+    pub debris: Option<DebrisDef>,
+}
+
+/// Tunable definitions for every `EffectType`
+#[derive(Resource)]
+pub struct EffectLibrary {
+    pub effects: HashMap<EffectType, EffectDef>,
+}
+
+impl Default for EffectLibrary {
+    fn default() -> Self {
+        let mut effects = HashMap::new();
+
+        effects.insert(EffectType::AirExplosion, EffectDef {
+            fireball: Some(FireballDef { puffs: 30, radius: 30.0 }),
+            smoke_column: None,
+            shockwave: Some(ShockwaveDef { radius: 80.0, duration: 0.5 }),
+            flash: Some(FlashDef { intensity: 30000.0, range: 150.0, duration: 0.4 }),
+            burning_wreck: None,
+            debris: Some(DebrisDef { count: 10, speed: 40.0, lifetime: 4.0 }),
+        });
+
+        effects.insert(EffectType::GroundExplosion, EffectDef {
+            fireball: Some(FireballDef { puffs: 40, radius: 30.0 }),
+            smoke_column: Some(SmokeColumnDef { duration: 8.0, rate: 6.0, puff_size: 15.0 }),
+            shockwave: Some(ShockwaveDef { radius: 120.0, duration: 0.7 }),
+            flash: Some(FlashDef { intensity: 50000.0, range: 200.0, duration: 0.6 }),
+            burning_wreck: Some(BurningWreckDef { duration: 30.0, hulk_size: 10.0 }),
+            debris: Some(DebrisDef { count: 20, speed: 60.0, lifetime: 5.0 }),
+        });
+
+        effects.insert(EffectType::AircraftCrash, EffectDef {
+            fireball: Some(FireballDef { puffs: 50, radius: 35.0 }),
+            smoke_column: Some(SmokeColumnDef { duration: 12.0, rate: 8.0, puff_size: 18.0 }),
+            shockwave: Some(ShockwaveDef { radius: 150.0, duration: 0.8 }),
+            flash: Some(FlashDef { intensity: 60000.0, range: 250.0, duration: 0.8 }),
+            burning_wreck: Some(BurningWreckDef { duration: 30.0, hulk_size: 8.0 }),
+            debris: Some(DebrisDef { count: 25, speed: 70.0, lifetime: 6.0 }),
+        });
+
+        Self { effects }
+    }
+}
+
+// ============================================================================
+// COMPONENTS & RESOURCES
+// ============================================================================
+
+/// Component for explosion effects that despawn after a time
+#[derive(Component)]
+pub struct ExplosionEffect {
+    pub lifetime: f32,
+    pub max_lifetime: f32,
+}
+
+impl ExplosionEffect {
+    fn new(max_lifetime: f32) -> Self {
+        Self { lifetime: 0.0, max_lifetime }
+    }
+
+    fn progress(&self) -> f32 {
+        (self.lifetime / self.max_lifetime).clamp(0.0, 1.0)
+    }
+}
+
+/// Flash light that decays over its `ExplosionEffect` lifetime
+#[derive(Component)]
+struct ExplosionFlash {
+    intensity: f32,
+}
+
+/// Ring that expands to `radius` over its `ExplosionEffect` lifetime
+#[derive(Component)]
+struct Shockwave {
+    radius: f32,
+}
+
+/// Shared meshes/materials so effects never allocate assets per spawn
+#[derive(Resource)]
+struct EffectAssets {
+    shockwave_mesh: Handle<Mesh>,
+    shockwave_material: Handle<StandardMaterial>,
+    debris_meshes: Vec<Handle<Mesh>>,
+    debris_material: Handle<StandardMaterial>,
+    wreck_mesh: Handle<Mesh>,
+    wreck_material: Handle<StandardMaterial>,
+}
+
+pub struct EffectsPlugin;
+
+impl Plugin for EffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SpawnEffect>()
+            .init_resource::<EffectLibrary>()
+            .add_systems(OnEnter(GameState::Spawning), setup_effect_assets)
+            .add_systems(Update, (
+                spawn_effects,
+                update_explosion_flashes,
+                update_shockwaves,
+                update_explosion_effects, // Clean up explosion effects
+            ).chain().run_if(world_active));
+    }
+}
+
+fn setup_effect_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(EffectAssets {
+        shockwave_mesh: meshes.add(Annulus::new(0.9, 1.0)),
+        shockwave_material: materials.add(StandardMaterial {
+            base_color: Color::srgba(1.0, 0.9, 0.7, 0.35),
+            emissive: LinearRgba::rgb(3.0, 2.0, 1.0),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            double_sided: true,
+            cull_mode: None,
+            ..default()
+        }),
+        debris_meshes: vec![
+            meshes.add(Cuboid::new(1.0, 0.4, 0.7)),
+            meshes.add(Cuboid::new(0.5, 0.5, 1.4)),
+            meshes.add(Sphere::new(0.5).mesh().ico(0).unwrap()),
+        ],
+        debris_material: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.3, 0.3, 0.3),
+            metallic: 0.8,
+            ..default()
+        }),
+        wreck_mesh: meshes.add(Cuboid::new(1.0, 0.35, 1.0)),
+        wreck_material: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.06, 0.05, 0.05), // Charred
+            perceptual_roughness: 1.0,
+            ..default()
+        }),
+    });
+    eprintln!("💥 STARTUP: Effect library ready");
+}
+
+// ============================================================================
+// SPAWNING
+// ============================================================================
+
+fn spawn_effects(
+    mut commands: Commands,
+    mut events: EventReader<SpawnEffect>,
+    library: Res<EffectLibrary>,
+    assets: Option<Res<EffectAssets>>,
+    mut particles: EventWriter<EmitParticles>,
+    graphics: Res<GraphicsSettings>,
+) {
+    let Some(assets) = assets else {
+        events.clear();
+        return;
+    };
+    let mut rng = rand::thread_rng();
+
+    for event in events.read() {
+        let Some(def) = library.effects.get(&event.effect) else { continue };
+        if !event.position.is_finite() {
+            continue;
+        }
+        let size = event.size.max(0.1);
+        let position = event.position;
+
+        if let Some(fireball) = def.fireball {
+            particles.send(
+                EmitParticles::new(ParticleType::Explosion, position)
+                    .with_velocity(Vec3::ZERO, fireball.radius * size)
+                    .with_count(fireball.puffs)
+                    .with_size(fireball.radius * 0.6 * size),
+            );
+            // A few dark puffs immediately so the fireball has a smoky edge
+            particles.send(
+                EmitParticles::new(ParticleType::Smoke, position)
+                    .with_velocity(Vec3::Y * 5.0, fireball.radius * 0.3 * size)
+                    .with_count(fireball.puffs / 3)
+                    .with_size(fireball.radius * 0.5 * size),
+            );
+        }
+
+        if let Some(flash) = def.flash {
+            commands.spawn((
+                ExplosionEffect::new(flash.duration),
+                ExplosionFlash { intensity: flash.intensity * size },
+                PointLight {
+                    intensity: flash.intensity * size,
+                    color: Color::srgb(1.0, 0.6, 0.2),
+                    range: flash.range * size,
+                    shadows_enabled: graphics.effect_light_shadows,
+                    ..default()
+                },
+                Transform::from_translation(position + Vec3::Y * 10.0),
+            ));
+        }
+
+        if let Some(shockwave) = def.shockwave {
+            // Annulus is built in the XY plane: lay it flat
+            commands.spawn((
+                ExplosionEffect::new(shockwave.duration),
+                Shockwave { radius: shockwave.radius * size },
+                Mesh3d(assets.shockwave_mesh.clone()),
+                MeshMaterial3d(assets.shockwave_material.clone()),
+                Transform::from_translation(position + Vec3::Y * 1.0)
+                    .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+                    .with_scale(Vec3::splat(0.1)),
+                NotShadowCaster,
+            ));
+        }
+
+        if let Some(column) = def.smoke_column {
+            commands.spawn((
+                ExplosionEffect::new(column.duration),
+                ParticleEmitter::new(ParticleType::Smoke, column.rate)
+                    .with_velocity(Vec3::Y * 12.0, 3.0 * size)
+                    .with_size(column.puff_size * size),
+                Transform::from_translation(position),
+            ));
+        }
+
+        if let Some(wreck) = def.burning_wreck {
+            let hulk = wreck.hulk_size * size;
+            commands.spawn((
+                ExplosionEffect::new(wreck.duration),
+                Mesh3d(assets.wreck_mesh.clone()),
+                MeshMaterial3d(assets.wreck_material.clone()),
+                Transform::from_translation(position)
+                    .with_rotation(Quat::from_rotation_y(rng.gen_range(0.0..std::f32::consts::TAU)))
+                    .with_scale(Vec3::splat(hulk)),
+            ))
+            .with_children(|parent| {
+                // Flames licking off the hulk, and the smoke they feed
+                parent.spawn((
+                    ParticleEmitter::new(ParticleType::Explosion, 10.0)
+                        .with_velocity(Vec3::Y * 3.0, 1.5)
+                        .with_size(hulk * 0.3),
+                    Transform::from_xyz(0.0, 0.5, 0.0),
+                ));
+                parent.spawn((
+                    ParticleEmitter::new(ParticleType::Smoke, 3.0)
+                        .with_velocity(Vec3::Y * 8.0, 2.0)
+                        .with_size(hulk * 0.8),
+                    Transform::from_xyz(0.0, 1.0, 0.0),
+                ));
+            });
+        }
+
+        if let Some(debris) = def.debris {
+            for _ in 0..debris.count {
+                let direction = Vec3::new(
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(0.3..1.0),
+                    rng.gen_range(-1.0..1.0),
+                ).normalize_or_zero();
+                let velocity = direction * debris.speed * rng.gen_range(0.5..1.2) * size.sqrt();
+                let mesh = assets.debris_meshes[rng.gen_range(0..assets.debris_meshes.len())].clone();
+                let scale = rng.gen_range(0.6..2.0) * size.sqrt();
+
+                commands.spawn((
+                    ExplosionEffect::new(debris.lifetime * rng.gen_range(0.7..1.0)),
+                    Mesh3d(mesh),
+                    MeshMaterial3d(assets.debris_material.clone()),
+                    Transform::from_translation(position + direction * 2.0).with_scale(Vec3::splat(scale)),
+                    RigidBody::Dynamic,
+                    Collider::sphere(0.5), // Collider scales with Transform
+                    LinearVelocity(velocity),
+                    AngularVelocity(Vec3::new(
+                        rng.gen_range(-8.0..8.0),
+                        rng.gen_range(-8.0..8.0),
+                        rng.gen_range(-8.0..8.0),
+                    )),
+                    GravityScale(1.0),
+                ))
+                .with_children(|parent| {
+                    // Thin smoke trail behind each burning chunk
+                    parent.spawn((
+                        ParticleEmitter::new(ParticleType::Smoke, 8.0).with_size(scale * 2.0),
+                        Transform::IDENTITY,
+                    ));
+                });
+            }
+        }
+    }
+}
+
+// ============================================================================
+// UPDATE
+// ============================================================================
+
+fn update_explosion_flashes(mut query: Query<(&ExplosionEffect, &ExplosionFlash, &mut PointLight)>) {
+    for (effect, flash, mut light) in &mut query {
+        // Fast quadratic falloff reads as a flash rather than a lamp
+        let fade = 1.0 - effect.progress();
+        light.intensity = flash.intensity * fade * fade;
+    }
+}
+
+fn update_shockwaves(mut query: Query<(&ExplosionEffect, &Shockwave, &mut Transform)>) {
+    for (effect, shockwave, mut transform) in &mut query {
+        // Ease-out expansion
+        let t = effect.progress();
+        let radius = shockwave.radius * (1.0 - (1.0 - t).powi(3));
+        transform.scale = Vec3::splat(radius.max(0.1));
+    }
+}
+
+/// Update and despawn explosion effects
+fn update_explosion_effects(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut ExplosionEffect)>,
+) {
+    for (entity, mut effect) in &mut query {
+        effect.lifetime += time.delta_secs();
+        if effect.lifetime >= effect.max_lifetime {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
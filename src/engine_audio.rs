@@ -0,0 +1,271 @@
+use avian3d::prelude::*;
+use bevy::{audio::PlaybackMode, prelude::*};
+use crate::{
+    assets::GameAssets,
+    audio::{AudioBus, MixerSound, PlaySound, SPEED_OF_SOUND},
+    biome::smoothstep,
+    camera::CameraRig,
+    resupply::FuelTank,
+    space, GameState, PlayerInput, PlayerPlane,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Core (N2) speed at idle and at full military power, as a fraction of rated RPM
+const IDLE_N2: f32 = 0.65;
+const MIL_N2: f32 = 1.0;
+/// The fan sits a little below the core at idle and catches up at mil power
+const IDLE_N1: f32 = 0.3;
+/// Spool time constants (seconds): turbines are slower to wind up than down
+const SPOOL_UP_TIME: f32 = 1.8;
+const SPOOL_DOWN_TIME: f32 = 1.0;
+/// How far the fan lags the core (seconds)
+const FAN_LAG: f32 = 0.6;
+/// Throttle above which the afterburner lights (matches the flight model's boost)
+const AFTERBURNER_THROTTLE: f32 = 0.8;
+/// Afterburner light-off and blow-out time (seconds)
+const AFTERBURNER_TIME: f32 = 0.3;
+/// Core speed the burner needs before it will light
+const AFTERBURNER_MIN_N2: f32 = 0.95;
+
+/// Below this much air (`space::atmosphere_factor`) the intake can't hold a flame
+const FLAMEOUT_AIR: f32 = 0.2;
+/// Thick enough air to try a relight, with the core windmilling at least this fast
+const RELIGHT_AIR: f32 = 0.6;
+const RELIGHT_MIN_N2: f32 = 0.12;
+/// Seconds of good conditions before the relight catches
+const RELIGHT_TIME: f32 = 2.0;
+/// Windmilling core speed per m/s of airspeed (a dead engine still turns)
+const WINDMILL_N2_PER_MPS: f32 = 0.001;
+const WINDMILL_MAX_N2: f32 = 0.35;
+
+/// Seconds the extinguisher needs to put out an engine fire
+pub const ENGINE_FIRE_TIME: f32 = 10.0;
+
+/// Seconds to crossfade between the exterior and interior mix on a view change
+const VIEW_BLEND_TIME: f32 = 0.25;
+/// Mach numbers where the jet counts as supersonic, and subsonic again
+const MACH_BOOM: f32 = 1.0;
+const MACH_SUBSONIC: f32 = 0.98;
+
+// ============================================================================
+// COMPONENTS & EVENTS
+// ============================================================================
+
+/// Turbine state the engine sound is built from. Lives on the player.
+#[derive(Component)]
+pub struct EngineSpool {
+    /// Fan speed (fraction of rated RPM): the low rumble
+    pub n1: f32,
+    /// Core speed: the whine
+    pub n2: f32,
+    /// Afterburner flame (0 = out, 1 = full)
+    pub afterburner: f32,
+    pub flamed_out: bool,
+    /// Seconds until the engine fire is out (0 = no fire)
+    pub fire: f32,
+    relight_timer: f32,
+}
+
+impl Default for EngineSpool {
+    fn default() -> Self {
+        Self {
+            n1: IDLE_N1,
+            n2: IDLE_N2,
+            afterburner: 0.0,
+            flamed_out: false,
+            fire: 0.0,
+            relight_timer: 0.0,
+        }
+    }
+}
+
+/// Layers of the engine sound, crossfaded by `EngineSpool`
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum EngineLayer {
+    /// High compressor whine, dominant at idle and inside the cockpit
+    IdleWhine,
+    /// Mid-band turbine rumble that builds to military power
+    MilPower,
+    /// Low afterburner roar
+    Afterburner,
+}
+
+impl EngineLayer {
+    const ALL: [EngineLayer; 3] = [EngineLayer::IdleWhine, EngineLayer::MilPower, EngineLayer::Afterburner];
+
+    /// Gain in the (exterior, interior) mix: outside you hear the exhaust, inside
+    /// the intake whine and the airframe
+    fn mix(self) -> (f32, f32) {
+        match self {
+            EngineLayer::IdleWhine => (0.5, 1.1),
+            EngineLayer::MilPower => (1.0, 0.55),
+            EngineLayer::Afterburner => (1.0, 0.35),
+        }
+    }
+
+    /// Volume and playback speed for the current spool state. All three layers
+    /// are `engine.ogg` pitched apart until dedicated recordings exist.
+    fn level(self, spool: &EngineSpool) -> (f32, f32) {
+        let mil = smoothstep(IDLE_N2, MIL_N2, spool.n2);
+        match self {
+            EngineLayer::IdleWhine => (spool.n2 * (0.5 - 0.3 * mil), 1.3 + spool.n2 * 0.6),
+            EngineLayer::MilPower => (spool.n1 * (0.15 + 0.55 * mil), 0.6 + spool.n1 * 0.4),
+            EngineLayer::Afterburner => (spool.afterburner * 0.9, 0.45 + spool.afterburner * 0.1),
+        }
+    }
+}
+
+/// The player broke (or dropped back through) the sound barrier
+#[derive(Event, Clone, Copy)]
+pub struct SonicBoom {
+    pub position: Vec3,
+    /// True going supersonic, false slowing down through Mach 1
+    pub accelerating: bool,
+}
+
+pub struct EngineAudioPlugin;
+
+impl Plugin for EngineAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SonicBoom>()
+            .add_systems(Update, (
+                spool_engine,
+                mix_engine_layers,
+                detect_sonic_boom,
+                play_sonic_boom,
+            ).chain().after(crate::read_player_input).run_if(in_state(GameState::Playing)));
+    }
+}
+
+/// Looping layers for the player's engine (children of the jet)
+pub fn spawn_engine_layers(parent: &mut ChildBuilder, sounds: &GameAssets) {
+    for layer in EngineLayer::ALL {
+        parent.spawn((
+            layer,
+            AudioPlayer(sounds.engine_loop.clone()),
+            PlaybackSettings {
+                mode: PlaybackMode::Loop,
+                ..default()
+            },
+            MixerSound::new(AudioBus::Engine, 0.0),
+        ));
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// Spool the turbine toward the throttle, and starve or relight it with the air and the fuel
+fn spool_engine(
+    time: Res<Time>,
+    mut player_query: Query<(&PlayerInput, &Transform, &LinearVelocity, &FuelTank, &mut EngineSpool), With<PlayerPlane>>,
+    mut play_sound: EventWriter<PlaySound>,
+    sounds: Res<GameAssets>,
+) {
+    let dt = time.delta_secs();
+    let Ok((input, transform, velocity, tank, mut spool)) = player_query.get_single_mut() else { return };
+    let air = space::atmosphere_factor(transform.translation.y);
+    let throttle = input.throttle.clamp(0.0, 1.0);
+
+    spool.fire = (spool.fire - dt).max(0.0);
+
+    // --- Flame-out & relight ---
+    if !spool.flamed_out && (air < FLAMEOUT_AIR || tank.is_empty()) {
+        spool.flamed_out = true;
+        spool.relight_timer = 0.0;
+        // Compressor stall bang
+        play_sound.send(PlaySound::new(sounds.afterburner_thump.clone(), AudioBus::Engine).with_speed(0.6));
+    } else if spool.flamed_out {
+        let can_relight = air > RELIGHT_AIR && spool.n2 > RELIGHT_MIN_N2 && !tank.is_empty();
+        spool.relight_timer = if can_relight { spool.relight_timer + dt } else { 0.0 };
+        if spool.relight_timer > RELIGHT_TIME {
+            spool.flamed_out = false;
+            play_sound.send(PlaySound::new(sounds.afterburner_thump.clone(), AudioBus::Engine)
+                .with_volume(0.6)
+                .with_speed(1.2));
+        }
+    }
+
+    // --- Core & fan ---
+    let target_n2 = if spool.flamed_out {
+        (velocity.length() * WINDMILL_N2_PER_MPS).min(WINDMILL_MAX_N2)
+    } else {
+        IDLE_N2 + (MIL_N2 - IDLE_N2) * throttle
+    };
+    let tau = if target_n2 > spool.n2 { SPOOL_UP_TIME } else { SPOOL_DOWN_TIME };
+    spool.n2 += (target_n2 - spool.n2) * (1.0 - (-dt / tau).exp());
+
+    // Fan speed the core is driving, reached a beat later
+    let target_n1 = (IDLE_N1 + (1.0 - IDLE_N1) * smoothstep(IDLE_N2 * 0.5, MIL_N2, spool.n2)) * spool.n2 / MIL_N2;
+    spool.n1 += (target_n1 - spool.n1) * (1.0 - (-dt / FAN_LAG).exp());
+
+    // --- Afterburner ---
+    let was_lit = spool.afterburner > 0.5;
+    let burner = !spool.flamed_out && throttle > AFTERBURNER_THROTTLE && spool.n2 > AFTERBURNER_MIN_N2;
+    let target_ab = if burner { 1.0 } else { 0.0 };
+    spool.afterburner += (target_ab - spool.afterburner) * (1.0 - (-dt / AFTERBURNER_TIME).exp());
+    if !was_lit && spool.afterburner > 0.5 {
+        // Cinematic light-off thump
+        play_sound.send(PlaySound::new(sounds.afterburner_thump.clone(), AudioBus::Engine).with_volume(1.5));
+    }
+}
+
+/// Crossfade the layers from the spool state and blend the interior/exterior mix
+fn mix_engine_layers(
+    time: Res<Time>,
+    rig: Res<CameraRig>,
+    player_query: Query<&EngineSpool, With<PlayerPlane>>,
+    mut layer_query: Query<(&EngineLayer, &mut MixerSound, Option<&AudioSink>)>,
+    mut interior: Local<f32>,
+) {
+    let Ok(spool) = player_query.get_single() else { return };
+
+    let target_interior = if rig.view.is_first_person() { 1.0 } else { 0.0 };
+    let step = time.delta_secs() / VIEW_BLEND_TIME;
+    *interior += (target_interior - *interior).clamp(-step, step);
+
+    for (layer, mut sound, sink) in &mut layer_query {
+        let (volume, speed) = layer.level(spool);
+        let (exterior_gain, interior_gain) = layer.mix();
+        sound.volume = volume * (exterior_gain + (interior_gain - exterior_gain) * *interior);
+        if let Some(sink) = sink {
+            sink.set_speed(speed);
+        }
+    }
+}
+
+/// Send `SonicBoom` when the jet crosses Mach 1 either way
+fn detect_sonic_boom(
+    player_query: Query<(&Transform, &LinearVelocity), With<PlayerPlane>>,
+    mut booms: EventWriter<SonicBoom>,
+    mut supersonic: Local<bool>,
+) {
+    let Ok((transform, velocity)) = player_query.get_single() else { return };
+    let mach = velocity.length() / SPEED_OF_SOUND;
+
+    let crossed = if *supersonic { mach < MACH_SUBSONIC } else { mach > MACH_BOOM };
+    if crossed {
+        *supersonic = !*supersonic;
+        booms.send(SonicBoom { position: transform.translation, accelerating: *supersonic });
+    }
+}
+
+/// Double-crack of the shock cone passing over where the jet was
+fn play_sonic_boom(
+    mut booms: EventReader<SonicBoom>,
+    mut play_sound: EventWriter<PlaySound>,
+    sounds: Res<GameAssets>,
+) {
+    for boom in booms.read() {
+        let volume = if boom.accelerating { 1.2 } else { 0.7 };
+        play_sound.send(PlaySound::new(sounds.explosion_heavy.clone(), AudioBus::Ambient)
+            .at(boom.position)
+            .with_volume(volume)
+            .with_speed(0.7)
+            .with_range(1000.0, 20000.0));
+    }
+}
@@ -0,0 +1,280 @@
+use bevy::{
+    prelude::*,
+    render::{mesh::{Indices, PrimitiveTopology}, render_asset::RenderAssetUsages},
+};
+use std::collections::HashMap;
+use crate::{
+    lod::{LodCategory, LodGroup, LodVariant},
+    procedural_textures, world_active, ChunkCoordinate, ChunkEntity, GameState, Tree,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Every tree model the biomes can ask for (see `BiomeSample::tree_models`)
+pub const TREE_MODELS: [&str; 5] = [
+    "fantasy_town/tree.glb",
+    "fantasy_town/tree-crooked.glb",
+    "fantasy_town/tree-high.glb",
+    "fantasy_town/tree-high-crooked.glb",
+    "fantasy_town/tree-high-round.glb",
+];
+
+/// Impostor card size relative to the tree's uniform scale (matches the Kenney tree bounds)
+const IMPOSTOR_WIDTH: f32 = 0.9;
+const IMPOSTOR_HEIGHT: f32 = 1.6;
+
+// ============================================================================
+// COMPONENTS & RESOURCES
+// ============================================================================
+
+/// All trees of one model in one chunk, drawn as one merged mesh (and one merged
+/// impostor mesh past LOD 2) instead of an entity per tree
+#[derive(Component)]
+pub struct FoliageBatch;
+
+/// Placements for a batch whose merged meshes haven't been built yet
+/// (the tree model may still be loading)
+#[derive(Component)]
+struct PendingFoliage {
+    model: &'static str,
+    transforms: Vec<Transform>,
+}
+
+/// Full-detail meshes are drawn up to `LODLevel(2)`, impostors at `LODLevel(3)`
+const MESH_LEVELS: LodVariant = LodVariant::levels(0, 2);
+const IMPOSTOR_LEVELS: LodVariant = LodVariant::levels(3, 3);
+
+/// One tree to place: which model and where (chunk-local)
+pub struct TreeInstance {
+    pub model: &'static str,
+    pub transform: Transform,
+}
+
+/// Shared handles for every tree in the world (created once at startup)
+#[derive(Resource)]
+pub struct FoliageAssets {
+    tree_meshes: HashMap<&'static str, Handle<Mesh>>,
+    tree_material: Handle<StandardMaterial>,
+    impostor_mesh: Handle<Mesh>,
+    impostor_material: Handle<StandardMaterial>,
+}
+
+impl FoliageAssets {
+    fn tree_mesh(&self, model: &str) -> Handle<Mesh> {
+        self.tree_meshes
+            .get(model)
+            .cloned()
+            .unwrap_or_else(|| self.tree_meshes[TREE_MODELS[0]].clone())
+    }
+}
+
+pub struct FoliagePlugin;
+
+impl Plugin for FoliagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Spawning), setup_foliage_assets)
+            .add_systems(Update, build_foliage_batches.run_if(world_active));
+    }
+}
+
+// ============================================================================
+// SETUP
+// ============================================================================
+
+fn setup_foliage_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    // Add #Mesh0/Primitive0 to target the mesh data directly inside the GLB
+    let tree_meshes = TREE_MODELS
+        .iter()
+        .map(|path| (*path, asset_server.load(format!("{}#Mesh0/Primitive0", path))))
+        .collect();
+
+    // ONE material for every tree in the world (per-chunk materials break batching)
+    let tree_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.1, 0.5, 0.1), // Grass green
+        perceptual_roughness: 0.9,
+        reflectance: 0.1,
+        ..default()
+    });
+
+    let impostor_material = materials.add(StandardMaterial {
+        base_color_texture: Some(images.add(procedural_textures::create_tree_impostor_texture())),
+        alpha_mode: AlphaMode::Mask(0.5),
+        perceptual_roughness: 1.0,
+        reflectance: 0.0,
+        double_sided: true,
+        cull_mode: None,
+        ..default()
+    });
+
+    commands.insert_resource(FoliageAssets {
+        tree_meshes,
+        tree_material,
+        impostor_mesh: meshes.add(create_cross_quad_mesh()),
+        impostor_material,
+    });
+    eprintln!("🌲 STARTUP: Foliage assets created ({} tree models, shared material)", TREE_MODELS.len());
+}
+
+/// Two crossed unit quads (base at y=0). Reads as a tree from any horizontal
+/// angle without per-frame billboard rotation.
+fn create_cross_quad_mesh() -> Mesh {
+    let positions: Vec<[f32; 3]> = vec![
+        // Quad facing Z
+        [-0.5, 0.0, 0.0], [0.5, 0.0, 0.0], [0.5, 1.0, 0.0], [-0.5, 1.0, 0.0],
+        // Quad facing X
+        [0.0, 0.0, -0.5], [0.0, 0.0, 0.5], [0.0, 1.0, 0.5], [0.0, 1.0, -0.5],
+    ];
+    let normals: Vec<[f32; 3]> = vec![
+        [0.0, 0.0, 1.0], [0.0, 0.0, 1.0], [0.0, 0.0, 1.0], [0.0, 0.0, 1.0],
+        [1.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 0.0, 0.0],
+    ];
+    let uvs: Vec<[f32; 2]> = vec![
+        [0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0],
+        [0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0],
+    ];
+    let indices = vec![0, 1, 2, 0, 2, 3, 4, 5, 6, 4, 6, 7];
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
+
+// ============================================================================
+// SPAWNING
+// ============================================================================
+
+/// Group a chunk's trees by model and spawn one `FoliageBatch` per model.
+/// The batch's merged meshes are built by `build_foliage_batches`.
+pub fn spawn_foliage_batches(
+    commands: &mut Commands,
+    chunk_entity: Entity,
+    chunk_coord: ChunkCoordinate,
+    instances: Vec<TreeInstance>,
+) {
+    let mut by_model: HashMap<&'static str, Vec<Transform>> = HashMap::new();
+    for instance in instances {
+        by_model.entry(instance.model).or_default().push(instance.transform);
+    }
+
+    commands.entity(chunk_entity).with_children(|chunk| {
+        for (model, transforms) in by_model {
+            chunk.spawn((
+                FoliageBatch,
+                LodGroup { category: LodCategory::Tree, instances: transforms.len() as u32 },
+                PendingFoliage { model, transforms },
+                ChunkEntity,
+                chunk_coord,
+                Transform::IDENTITY,
+                Visibility::default(),
+            ));
+        }
+    });
+}
+
+/// Merge each pending batch's trees into one mesh per LOD variant once its model is loaded
+fn build_foliage_batches(
+    mut commands: Commands,
+    foliage: Option<Res<FoliageAssets>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    pending_query: Query<(Entity, &PendingFoliage)>,
+) {
+    let Some(foliage) = foliage else { return };
+
+    for (entity, pending) in &pending_query {
+        let (Some(tree_source), Some(impostor_source)) =
+            (meshes.get(&foliage.tree_mesh(pending.model)), meshes.get(&foliage.impostor_mesh))
+        else {
+            continue; // Model still loading; try again next frame
+        };
+
+        let impostor_transforms: Vec<Transform> = pending.transforms.iter()
+            .map(|transform| {
+                let scale = transform.scale.x;
+                Transform {
+                    translation: transform.translation,
+                    rotation: transform.rotation,
+                    scale: Vec3::new(scale * IMPOSTOR_WIDTH, scale * IMPOSTOR_HEIGHT, scale * IMPOSTOR_WIDTH),
+                }
+            })
+            .collect();
+        let tree_mesh = merge_instances(tree_source, &pending.transforms);
+        let impostor_mesh = merge_instances(impostor_source, &impostor_transforms);
+
+        let (tree_mesh, impostor_mesh) = (meshes.add(tree_mesh), meshes.add(impostor_mesh));
+        commands.entity(entity)
+            .remove::<PendingFoliage>()
+            .with_children(|batch| {
+                batch.spawn((
+                    Tree,
+                    MESH_LEVELS,
+                    Mesh3d(tree_mesh),
+                    MeshMaterial3d(foliage.tree_material.clone()),
+                    Transform::IDENTITY,
+                    Visibility::Inherited,
+                ));
+                batch.spawn((
+                    IMPOSTOR_LEVELS,
+                    Mesh3d(impostor_mesh),
+                    MeshMaterial3d(foliage.impostor_material.clone()),
+                    Transform::IDENTITY,
+                    Visibility::Hidden,
+                ));
+            });
+    }
+}
+
+/// One mesh holding a copy of `source` at every transform (chunk-local)
+fn merge_instances(source: &Mesh, transforms: &[Transform]) -> Mesh {
+    // Widen to 32-bit indices first: a forest easily passes 65k vertices
+    let mut base = source.clone();
+    if let Some(Indices::U16(indices)) = base.indices() {
+        let widened = indices.iter().map(|&i| i as u32).collect();
+        base.insert_indices(Indices::U32(widened));
+    }
+
+    let mut merged: Option<Mesh> = None;
+    for transform in transforms {
+        let placed = base.clone().transformed_by(*transform);
+        match merged.as_mut() {
+            Some(mesh) => mesh.merge(&placed),
+            None => merged = Some(placed),
+        }
+    }
+    merged.unwrap_or(base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merged_batch_places_every_tree_with_offset_indices() {
+        let source = create_cross_quad_mesh().with_inserted_indices(Indices::U16(vec![0, 1, 2, 0, 2, 3, 4, 5, 6, 4, 6, 7]));
+        let transforms: Vec<Transform> = (0..3)
+            .map(|i| Transform::from_xyz(i as f32 * 10.0, 5.0, 0.0).with_scale(Vec3::splat(2.0)))
+            .collect();
+        let merged = merge_instances(&source, &transforms);
+
+        assert_eq!(merged.count_vertices(), 8 * 3);
+        let Some(Indices::U32(indices)) = merged.indices() else { panic!("indices not widened to u32") };
+        assert_eq!(indices.len(), 12 * 3);
+        // Third tree's triangles point at its own vertices
+        assert_eq!(&indices[24..27], &[16, 17, 18]);
+
+        let Some(bevy::render::mesh::VertexAttributeValues::Float32x3(positions)) = merged.attribute(Mesh::ATTRIBUTE_POSITION)
+        else { panic!("no positions") };
+        // Top of the third tree's first quad: scaled by 2, moved to (20, 5, 0)
+        assert_eq!(positions[16 + 2], [21.0, 7.0, 0.0]);
+    }
+}
@@ -0,0 +1,179 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use crate::{
+    airfield,
+    controls::{Action, ActionState, FlightAxis},
+    landing_gear::LandingGear,
+    origin::WorldOrigin,
+    settings::Settings,
+    space, water, GameState, PlayerInput, PlayerPlane,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// How far ahead the flight path is projected (seconds), and the step
+const LOOKAHEAD: f32 = 12.0;
+const LOOKAHEAD_STEP: f32 = 0.25;
+/// Clearance the projected path has to keep over the ground (meters)
+const CLEARANCE_MARGIN: f32 = 30.0;
+/// Predicted impact inside these times raises a caution, then a warning
+const CAUTION_TIME: f32 = 10.0;
+const WARNING_TIME: f32 = 5.0;
+/// Sinking below this height above ground is a caution even with a clear path
+const LOW_AGL: f32 = 150.0;
+const LOW_AGL_SINK_RATE: f32 = 2.0;
+/// Radar altimeter range; above this the HUD shows dashes
+pub const RADAR_ALTIMETER_RANGE: f32 = 1500.0;
+
+/// Auto-GCAS recovery model: roll and pitch rates the flight model gives at
+/// full stick (rad/s) plus reaction slack (s). It fires when the predicted
+/// impact is closer than the time this recovery takes.
+const GCAS_ROLL_RATE: f32 = 2.5;
+const GCAS_PITCH_RATE: f32 = 1.8;
+const GCAS_REACTION_TIME: f32 = 1.0;
+/// Bank (radians) under which the recovery stops rolling and starts pulling
+const GCAS_PULL_BANK: f32 = 0.5;
+const GCAS_ROLL_GAIN: f32 = 2.5;
+/// Climb angle (radians) with a clear path at which the jet is handed back
+const GCAS_RELEASE_CLIMB: f32 = 0.1;
+/// Stick deflection by hand that takes control back from Auto-GCAS
+const GCAS_OVERRIDE: f32 = 0.5;
+
+// ============================================================================
+// RESOURCES
+// ============================================================================
+
+/// Ground proximity alert level
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TerrainAlert {
+    #[default]
+    Clear,
+    /// Terrain ahead or sinking low: "ALTITUDE"
+    Caution,
+    /// Impact imminent: "PULL UP"
+    Warning,
+}
+
+/// What the ground proximity computer sees, updated every frame in flight
+#[derive(Resource, Default)]
+pub struct GroundProximity {
+    /// Height above the terrain or water right below the jet (meters)
+    pub agl: f32,
+    /// Seconds until the projected flight path busts the clearance margin
+    pub time_to_impact: Option<f32>,
+    pub alert: TerrainAlert,
+    /// Auto-GCAS is flying the recovery
+    pub gcas_active: bool,
+}
+
+pub struct GpwsPlugin;
+
+impl Plugin for GpwsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GroundProximity>()
+            .add_systems(Update, (
+                update_ground_proximity,
+                // Last word on the stick before the flight model flies it
+                auto_gcas
+                    .after(crate::read_player_input)
+                    .after(crate::mouse_aim::mouse_aim_instructor)
+                    .before(crate::arcade_flight_physics),
+            ).chain().run_if(in_state(GameState::Playing)));
+    }
+}
+
+/// Terrain, or the water surface over lakes and sea
+fn ground_height(origin: &WorldOrigin, point: Vec3) -> f32 {
+    origin.terrain_height_at(point).max(water::SEA_LEVEL)
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// Radar altitude and a straight-line projection of the flight path
+pub fn update_ground_proximity(
+    origin: Res<WorldOrigin>,
+    mut proximity: ResMut<GroundProximity>,
+    player_query: Query<(&Transform, &LinearVelocity, &LandingGear), With<PlayerPlane>>,
+) {
+    let Ok((transform, velocity, gear)) = player_query.get_single() else { return };
+    let position = transform.translation;
+    proximity.agl = position.y - ground_height(&origin, position);
+
+    // Nothing to warn about while rolling on the ground
+    if gear.on_ground {
+        proximity.time_to_impact = None;
+        proximity.alert = TerrainAlert::Clear;
+        return;
+    }
+
+    // Landing mode: with the gear down, a path that meets an airfield is an approach
+    let landing = gear.down;
+    let steps = (LOOKAHEAD / LOOKAHEAD_STEP) as usize;
+    proximity.time_to_impact = (1..=steps)
+        .map(|i| i as f32 * LOOKAHEAD_STEP)
+        .find(|t| {
+            let ahead = position + velocity.0 * *t;
+            let absolute = origin.to_absolute(ahead);
+            ahead.y < ground_height(&origin, ahead) + CLEARANCE_MARGIN
+                && !(landing && airfield::airfield_at(absolute.x, absolute.z).is_some())
+        });
+
+    let sinking_low = !landing && proximity.agl < LOW_AGL && -velocity.y > LOW_AGL_SINK_RATE;
+    proximity.alert = match proximity.time_to_impact {
+        Some(t) if t < WARNING_TIME => TerrainAlert::Warning,
+        Some(t) if t < CAUTION_TIME => TerrainAlert::Caution,
+        _ if sinking_low => TerrainAlert::Caution,
+        _ => TerrainAlert::Clear,
+    };
+}
+
+/// Automatic ground collision avoidance: when the impact is closer than a
+/// roll-wings-level-and-pull recovery takes, fly that recovery until the jet
+/// climbs clear. Any firm stick input by hand cancels it.
+fn auto_gcas(
+    settings: Res<Settings>,
+    actions: Res<ActionState>,
+    mut proximity: ResMut<GroundProximity>,
+    mut player_query: Query<(&mut PlayerInput, &Transform, &LinearVelocity, &LandingGear), With<PlayerPlane>>,
+) {
+    let Ok((mut input, transform, velocity, gear)) = player_query.get_single_mut() else { return };
+
+    let hand_on_stick = [
+        actions.axis(FlightAxis::Pitch).unwrap_or(0.0),
+        actions.axis(FlightAxis::Roll).unwrap_or(0.0),
+        actions.digital_axis(Action::PitchUp, Action::PitchDown),
+        actions.digital_axis(Action::RollRight, Action::RollLeft),
+    ].iter().any(|v| v.abs() > GCAS_OVERRIDE);
+    // Wings only bite in the air (no terrain to hit up there anyway), and the
+    // gear handle going down tells it the pilot means to meet the ground
+    let armed = settings.auto_gcas && !gear.down && space::atmosphere_factor(transform.translation.y) > 0.5;
+
+    // Bank (right wing down positive) and flight path angle
+    let right = transform.right().as_vec3();
+    let up = transform.up().as_vec3();
+    let bank = (-right.y).atan2(up.y);
+    let climb = velocity.0.normalize_or_zero().y.clamp(-1.0, 1.0).asin();
+
+    if !proximity.gcas_active {
+        let recovery_time = bank.abs() / GCAS_ROLL_RATE + (-climb).max(0.0) / GCAS_PITCH_RATE + GCAS_REACTION_TIME;
+        let imminent = proximity.time_to_impact.is_some_and(|t| t < recovery_time);
+        proximity.gcas_active = armed && imminent && !hand_on_stick;
+    } else if !armed || hand_on_stick {
+        // Pilot override
+        proximity.gcas_active = false;
+    } else if proximity.time_to_impact.is_none() && climb > GCAS_RELEASE_CLIMB {
+        // Recovered, control returned
+        proximity.gcas_active = false;
+    }
+
+    if proximity.gcas_active {
+        // Roll wings level, then a max-performance pull
+        input.roll = (-GCAS_ROLL_GAIN * bank).clamp(-1.0, 1.0);
+        input.yaw = 0.0;
+        input.pitch = if bank.abs() < GCAS_PULL_BANK { 1.0 } else { 0.0 };
+    }
+}
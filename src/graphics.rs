@@ -0,0 +1,450 @@
+use std::{fmt::Write as _, io, path::Path};
+
+use bevy::{
+    core_pipeline::bloom::Bloom,
+    pbr::{CascadeShadowConfig, CascadeShadowConfigBuilder, DirectionalLightShadowMap},
+    prelude::*,
+};
+use crate::{time_of_day::SunLight, ChunkCoordinate, ChunkEntity, ChunkManager};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Graphics file: a preset plus any options overridden on top of it
+pub const GRAPHICS_PATH: &str = "config/graphics.cfg";
+
+/// Shadow map resolutions the settings screen steps through
+pub const SHADOW_MAP_SIZES: [usize; 4] = [1024, 2048, 4096, 8192];
+
+// ============================================================================
+// GRAPHICS SETTINGS
+// ============================================================================
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphicsQuality {
+    Low,
+    Medium,
+    High,
+    Ultra,
+}
+
+impl GraphicsQuality {
+    pub const ALL: [GraphicsQuality; 4] = [
+        GraphicsQuality::Low,
+        GraphicsQuality::Medium,
+        GraphicsQuality::High,
+        GraphicsQuality::Ultra,
+    ];
+
+    /// Name in the graphics file
+    fn key(self) -> &'static str {
+        match self {
+            GraphicsQuality::Low => "low",
+            GraphicsQuality::Medium => "medium",
+            GraphicsQuality::High => "high",
+            GraphicsQuality::Ultra => "ultra",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GraphicsQuality::Low => "Low",
+            GraphicsQuality::Medium => "Medium",
+            GraphicsQuality::High => "High",
+            GraphicsQuality::Ultra => "Ultra",
+        }
+    }
+}
+
+/// Everything that trades looks for frame rate. Start from a preset, then
+/// override single options; systems read it live, so changes apply in flight.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct GraphicsSettings {
+    /// Preset the options started from (they may have been overridden since)
+    pub quality: GraphicsQuality,
+    // --- Shadows ---
+    pub shadows: bool,
+    pub shadow_map_size: usize,
+    pub shadow_cascades: usize,
+    /// Distance covered by the sun's shadow cascades (meters)
+    pub shadow_distance: f32,
+    // --- World streaming ---
+    /// Chunks within this radius are loaded (fixes ground holes)
+    pub load_radius_chunks: i32,
+    /// Chunks beyond this radius are unloaded
+    pub unload_radius_chunks: i32,
+    // --- Vegetation & props ---
+    pub trees_per_chunk_min: usize,
+    pub trees_per_chunk_max: usize,
+    pub meteors_per_chunk: usize,
+    // --- Effects ---
+    pub bloom: bool,
+    /// Multiplier on every particle burst
+    pub particle_density: f32,
+    /// Explosion flashes cast shadows (a cube map per flash)
+    pub effect_light_shadows: bool,
+}
+
+impl Default for GraphicsSettings {
+    fn default() -> Self {
+        Self::preset(GraphicsQuality::Ultra)
+    }
+}
+
+impl GraphicsSettings {
+    pub fn preset(quality: GraphicsQuality) -> Self {
+        match quality {
+            GraphicsQuality::Low => Self {
+                quality,
+                shadows: false,
+                shadow_map_size: 1024,
+                shadow_cascades: 2,
+                shadow_distance: 1500.0,
+                load_radius_chunks: 3,
+                unload_radius_chunks: 5,
+                trees_per_chunk_min: 3,
+                trees_per_chunk_max: 15,
+                meteors_per_chunk: 8,
+                bloom: false,
+                particle_density: 0.4,
+                effect_light_shadows: false,
+            },
+            GraphicsQuality::Medium => Self {
+                quality,
+                shadows: true,
+                shadow_map_size: 2048,
+                shadow_cascades: 3,
+                shadow_distance: 2500.0,
+                load_radius_chunks: 5,
+                unload_radius_chunks: 8,
+                trees_per_chunk_min: 6,
+                trees_per_chunk_max: 40,
+                meteors_per_chunk: 20,
+                bloom: true,
+                particle_density: 0.7,
+                effect_light_shadows: false,
+            },
+            GraphicsQuality::High => Self {
+                quality,
+                shadows: true,
+                shadow_map_size: 4096,
+                shadow_cascades: 4,
+                shadow_distance: 4000.0,
+                load_radius_chunks: 7,
+                unload_radius_chunks: 10,
+                trees_per_chunk_min: 8,
+                trees_per_chunk_max: 60,
+                meteors_per_chunk: 30,
+                bloom: true,
+                particle_density: 1.0,
+                effect_light_shadows: true,
+            },
+            // The pre-preset shadows, view distance and sky debris, with denser
+            // forests than the old 5-10 trees per chunk
+            GraphicsQuality::Ultra => Self {
+                quality,
+                shadows: true,
+                shadow_map_size: 4096, // High-res shadows from Bevy example
+                shadow_cascades: 4,
+                shadow_distance: 5000.0, // 5km keeps the ground bright
+                load_radius_chunks: 8,
+                unload_radius_chunks: 12,
+                trees_per_chunk_min: 10,
+                trees_per_chunk_max: 80, // One merged mesh per tree model per chunk makes real forests affordable
+                meteors_per_chunk: 40,
+                bloom: true,
+                particle_density: 1.0,
+                effect_light_shadows: true,
+            },
+        }
+    }
+
+    /// True when some option no longer matches the preset
+    pub fn is_custom(&self) -> bool {
+        *self != Self::preset(self.quality)
+    }
+
+    /// Distance out to which terrain is guaranteed to be loaded (meters)
+    pub fn view_distance(&self) -> f32 {
+        self.load_radius_chunks as f32 * crate::CHUNK_SIZE
+    }
+
+    /// Sun cascades for the current shadow options (tuned for flight-sim distances)
+    pub fn cascade_config(&self) -> CascadeShadowConfig {
+        CascadeShadowConfigBuilder {
+            num_cascades: self.shadow_cascades,
+            minimum_distance: 0.1,
+            maximum_distance: self.shadow_distance,
+            first_cascade_far_bound: 50.0,
+            overlap_proportion: 0.2,
+        }
+        .build()
+    }
+
+    /// Keep the options consistent after loading or editing
+    pub fn sanitize(&mut self) {
+        // Only sizes the settings screen offers; anything else snaps to the closest one
+        self.shadow_map_size = SHADOW_MAP_SIZES.into_iter()
+            .min_by_key(|size| size.abs_diff(self.shadow_map_size))
+            .unwrap_or(SHADOW_MAP_SIZES[0]);
+        self.shadow_cascades = self.shadow_cascades.clamp(1, 4);
+        self.shadow_distance = self.shadow_distance.clamp(500.0, 20000.0);
+        self.load_radius_chunks = self.load_radius_chunks.clamp(2, 16);
+        self.unload_radius_chunks = self.unload_radius_chunks.max(self.load_radius_chunks + 1);
+        self.trees_per_chunk_max = self.trees_per_chunk_max.min(400);
+        self.trees_per_chunk_min = self.trees_per_chunk_min.min(self.trees_per_chunk_max);
+        self.meteors_per_chunk = self.meteors_per_chunk.min(200);
+        self.particle_density = self.particle_density.clamp(0.1, 2.0);
+    }
+
+    /// Preset from the file, then each override on top
+    fn load(path: &Path) -> GraphicsSettings {
+        let mut graphics = GraphicsSettings::default();
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                match graphics.save(path) {
+                    Ok(()) => println!("🖥️  GRAPHICS: Wrote defaults to {}", path.display()),
+                    Err(err) => eprintln!("⚠️  GRAPHICS: Could not write {}: {}", path.display(), err),
+                }
+                return graphics;
+            }
+            Err(err) => {
+                eprintln!("⚠️  GRAPHICS: Could not read {}: {} - using defaults", path.display(), err);
+                return graphics;
+            }
+        };
+
+        let lines: Vec<(usize, &str, &str)> = text.lines().enumerate()
+            .map(|(number, line)| (number + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|(number, line)| match line.split_once('=') {
+                Some((name, value)) => Some((number, name.trim(), value.trim())),
+                None => {
+                    eprintln!("⚠️  GRAPHICS: {}:{}: expected `name = value`", path.display(), number);
+                    None
+                }
+            })
+            .collect();
+
+        // The preset goes first wherever it appears in the file
+        if let Some(&(number, _, value)) = lines.iter().find(|(_, name, _)| *name == "preset") {
+            match GraphicsQuality::ALL.into_iter().find(|quality| quality.key() == value) {
+                Some(quality) => graphics = GraphicsSettings::preset(quality),
+                None => eprintln!("⚠️  GRAPHICS: {}:{}: unknown preset `{}`", path.display(), number, value),
+            }
+        }
+        for (number, name, value) in lines {
+            let parsed = match name {
+                "preset" => continue,
+                "shadows" => value.parse().ok().map(|v| graphics.shadows = v),
+                "shadow_map_size" => value.parse().ok().map(|v| graphics.shadow_map_size = v),
+                "shadow_cascades" => value.parse().ok().map(|v| graphics.shadow_cascades = v),
+                "shadow_distance" => value.parse().ok().map(|v| graphics.shadow_distance = v),
+                "load_radius_chunks" => value.parse().ok().map(|v| graphics.load_radius_chunks = v),
+                "unload_radius_chunks" => value.parse().ok().map(|v| graphics.unload_radius_chunks = v),
+                "trees_per_chunk_min" => value.parse().ok().map(|v| graphics.trees_per_chunk_min = v),
+                "trees_per_chunk_max" => value.parse().ok().map(|v| graphics.trees_per_chunk_max = v),
+                "meteors_per_chunk" => value.parse().ok().map(|v| graphics.meteors_per_chunk = v),
+                "bloom" => value.parse().ok().map(|v| graphics.bloom = v),
+                "particle_density" => value.parse().ok().map(|v| graphics.particle_density = v),
+                "effect_light_shadows" => value.parse().ok().map(|v| graphics.effect_light_shadows = v),
+                _ => {
+                    eprintln!("⚠️  GRAPHICS: {}:{}: unknown option `{}`", path.display(), number, name);
+                    continue;
+                }
+            };
+            if parsed.is_none() {
+                eprintln!("⚠️  GRAPHICS: {}:{}: bad value `{}` for {}", path.display(), number, value, name);
+            }
+        }
+        graphics.sanitize();
+        println!("🖥️  GRAPHICS: Loaded {} ({}{})", path.display(), graphics.quality.label(),
+            if graphics.is_custom() { ", custom" } else { "" });
+        graphics
+    }
+
+    /// Writes the preset and only the options that differ from it
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let base = GraphicsSettings::preset(self.quality);
+        let mut text = String::new();
+        text.push_str("# ViperEye graphics (Settings in the main or pause menu)\n");
+        text.push_str("# preset = low | medium | high | ultra; any option below overrides the preset\n\n");
+        let _ = writeln!(text, "preset = {}", self.quality.key());
+
+        let mut overrides = String::new();
+        let mut add = |name: &str, differs: bool, value: String| {
+            if differs {
+                let _ = writeln!(overrides, "{} = {}", name, value);
+            }
+        };
+        add("shadows", self.shadows != base.shadows, self.shadows.to_string());
+        add("shadow_map_size", self.shadow_map_size != base.shadow_map_size, self.shadow_map_size.to_string());
+        add("shadow_cascades", self.shadow_cascades != base.shadow_cascades, self.shadow_cascades.to_string());
+        add("shadow_distance", self.shadow_distance != base.shadow_distance, format!("{:.0}", self.shadow_distance));
+        add("load_radius_chunks", self.load_radius_chunks != base.load_radius_chunks, self.load_radius_chunks.to_string());
+        add("unload_radius_chunks", self.unload_radius_chunks != base.unload_radius_chunks, self.unload_radius_chunks.to_string());
+        add("trees_per_chunk_min", self.trees_per_chunk_min != base.trees_per_chunk_min, self.trees_per_chunk_min.to_string());
+        add("trees_per_chunk_max", self.trees_per_chunk_max != base.trees_per_chunk_max, self.trees_per_chunk_max.to_string());
+        add("meteors_per_chunk", self.meteors_per_chunk != base.meteors_per_chunk, self.meteors_per_chunk.to_string());
+        add("bloom", self.bloom != base.bloom, self.bloom.to_string());
+        add("particle_density", self.particle_density != base.particle_density, format!("{:.2}", self.particle_density));
+        add("effect_light_shadows", self.effect_light_shadows != base.effect_light_shadows, self.effect_light_shadows.to_string());
+        if !overrides.is_empty() {
+            text.push_str("\n# Overrides\n");
+            text.push_str(&overrides);
+        }
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, text)
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+pub struct GraphicsPlugin;
+
+impl Plugin for GraphicsPlugin {
+    fn build(&self, app: &mut App) {
+        let graphics = GraphicsSettings::load(Path::new(GRAPHICS_PATH));
+        app.insert_resource(DirectionalLightShadowMap { size: graphics.shadow_map_size })
+            .insert_resource(graphics)
+            .add_systems(Update, (
+                apply_graphics_settings,
+                rebuild_chunk_props,
+            ).run_if(resource_changed::<GraphicsSettings>));
+    }
+}
+
+/// Push edits into the renderer. Spawning reads the same settings, so the
+/// first run (before the world exists) only has the shadow map to set.
+fn apply_graphics_settings(
+    mut commands: Commands,
+    graphics: Res<GraphicsSettings>,
+    mut shadow_map: ResMut<DirectionalLightShadowMap>,
+    mut sun_query: Query<&mut CascadeShadowConfig, With<SunLight>>,
+    camera_query: Query<(Entity, Has<Bloom>), With<Camera3d>>,
+) {
+    if shadow_map.size != graphics.shadow_map_size {
+        shadow_map.size = graphics.shadow_map_size;
+    }
+    for mut cascades in &mut sun_query {
+        *cascades = graphics.cascade_config();
+    }
+    for (camera, has_bloom) in &camera_query {
+        match (graphics.bloom, has_bloom) {
+            (true, false) => {
+                commands.entity(camera).insert(Bloom::NATURAL);
+            }
+            (false, true) => {
+                commands.entity(camera).remove::<Bloom>();
+            }
+            _ => {}
+        }
+    }
+
+    println!("🖥️  GRAPHICS: {}{} - shadows {} ({}px), view {} km, bloom {}",
+        graphics.quality.label(),
+        if graphics.is_custom() { " (custom)" } else { "" },
+        if graphics.shadows { "on" } else { "off" },
+        graphics.shadow_map_size,
+        graphics.load_radius_chunks,
+        if graphics.bloom { "on" } else { "off" });
+}
+
+/// Chunk roots and the top-level entities spawned beside them (cloud puffs).
+/// Children (trees, runway paint) go with their root.
+type TopLevelChunkQuery<'w, 's> = Query<'w, 's, (Entity, &'static ChunkCoordinate), (With<ChunkEntity>, Without<Parent>)>;
+
+/// Trees and meteors are baked into chunks: unload everything chunk-owned so
+/// `manage_chunks` rebuilds the loaded area with the new counts. That includes
+/// the top-level cloud puffs, which would otherwise be spawned a second time.
+fn rebuild_chunk_props(
+    mut commands: Commands,
+    graphics: Res<GraphicsSettings>,
+    mut chunk_manager: ResMut<ChunkManager>,
+    chunk_entities: TopLevelChunkQuery,
+    mut built_props: Local<Option<(usize, usize, usize)>>,
+) {
+    let props = (graphics.trees_per_chunk_min, graphics.trees_per_chunk_max, graphics.meteors_per_chunk);
+    if built_props.is_some_and(|built| built != props) && !chunk_manager.loaded_chunks.is_empty() {
+        for (entity, _) in &chunk_entities {
+            commands.entity(entity).despawn_recursive();
+        }
+        chunk_manager.loaded_chunks.clear();
+    }
+    *built_props = Some(props);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stands in for `clouds::CloudPuff`: top-level, owned by a chunk
+    #[derive(Component)]
+    struct Puff;
+
+    fn spawn_loaded_chunk(app: &mut App, coord: ChunkCoordinate) {
+        let world = app.world_mut();
+        let root = world.spawn((ChunkEntity, coord)).with_children(|chunk| {
+            chunk.spawn((ChunkEntity, coord));
+        }).id();
+        world.spawn((Puff, ChunkEntity, coord));
+        world.resource_mut::<ChunkManager>().loaded_chunks.insert(coord, root);
+    }
+
+    fn puffs_per_chunk(app: &mut App) -> Vec<(ChunkCoordinate, usize)> {
+        let world = app.world_mut();
+        let mut counts: Vec<(ChunkCoordinate, usize)> = Vec::new();
+        for coord in world.query_filtered::<&ChunkCoordinate, With<Puff>>().iter(world) {
+            match counts.iter_mut().find(|(seen, _)| seen == coord) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((*coord, 1)),
+            }
+        }
+        counts
+    }
+
+    #[test]
+    fn vegetation_change_unloads_every_chunk_owned_entity() {
+        let mut app = App::new();
+        app.init_resource::<ChunkManager>()
+            .insert_resource(GraphicsSettings::default())
+            .add_systems(Update, rebuild_chunk_props);
+
+        let coords = [ChunkCoordinate { x: 0, z: 0 }, ChunkCoordinate { x: 1, z: -1 }];
+        for coord in coords {
+            spawn_loaded_chunk(&mut app, coord);
+        }
+        app.update();
+        // First run must not rebuild: still exactly the one puff each chunk was given
+        let counts = puffs_per_chunk(&mut app);
+        assert_eq!(counts.len(), coords.len());
+        for coord in coords {
+            assert!(counts.contains(&(coord, 1)), "chunk {:?}: {:?}", coord, counts);
+        }
+
+        app.world_mut().resource_mut::<GraphicsSettings>().trees_per_chunk_max += 10;
+        app.update();
+
+        // Puffs included, so manage_chunks' reload can't stack a second set on the old one
+        let world = app.world_mut();
+        assert!(world.resource::<ChunkManager>().loaded_chunks.is_empty());
+        assert_eq!(world.query_filtered::<(), With<ChunkEntity>>().iter(world).count(), 0);
+    }
+
+    #[test]
+    fn shadow_map_size_snaps_to_an_offered_size() {
+        let mut graphics = GraphicsSettings::default();
+        for (configured, expected) in [(0, 1024), (3000, 2048), (3500, 4096), (8192, 8192), (65536, 8192)] {
+            graphics.shadow_map_size = configured;
+            graphics.sanitize();
+            assert_eq!(graphics.shadow_map_size, expected, "from {}", configured);
+        }
+    }
+}
@@ -0,0 +1,550 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use crate::{
+    airfield::{self, Airfield, MissionStart, PlayerSpawn},
+    assets::GameAssets,
+    audio::{AudioBus, PlaySound},
+    controls::{Action, ActionState},
+    effects::{EffectType, SpawnEffect},
+    origin::WorldOrigin,
+    space, water, GameState, PlayerInput, PlayerPlane, MASS_KG,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Height of the jet's origin above the ground when sitting on its wheels, and
+/// when sliding on its belly (the physics collider is 1 m half-height)
+pub const GEAR_HEIGHT: f32 = 2.5;
+const BELLY_HEIGHT: f32 = 1.2;
+/// Wheel positions relative to the jet (x, z): nose gear and the two mains
+const WHEELS: [(f32, f32); 3] = [(0.0, -4.5), (-1.6, 0.8), (1.6, 0.8)];
+/// Belly skid points (z): nose and tail
+const BELLY_POINTS: [f32; 2] = [-4.0, 3.5];
+/// The surface under a wheel is sampled this far either side for its slope
+const SLOPE_SAMPLE: f64 = 2.0;
+
+/// Seconds for the gear to travel fully up or down
+const GEAR_TRANSIT_TIME: f32 = 4.0;
+/// The gear won't come down faster than this (m/s)
+const GEAR_MAX_SPEED: f32 = 150.0;
+/// Flap detents (fraction of full travel), seconds for full travel, and the
+/// speed above which the airloads blow them back up
+const FLAP_SETTINGS: [f32; 3] = [0.0, 0.5, 1.0];
+const FLAP_TRAVEL_TIME: f32 = 3.0;
+const FLAP_BLOWBACK_SPEED: f32 = 130.0;
+/// Extra lift from full flaps per (m/s)², capped at a fraction of the jet's weight
+const FLAP_LIFT: f32 = 5.0;
+const FLAP_MAX_LIFT: f32 = 0.5;
+/// Drag added on top of the flight model's (same units as its DRAG_COEFFICIENT)
+const GEAR_DRAG: f32 = 0.02;
+const FLAP_DRAG: f32 = 0.03;
+const AIRBRAKE_DRAG: f32 = 0.12;
+
+/// Friction coefficients: free rolling, full wheel braking, and scraping along on the belly
+const ROLLING_FRICTION: f32 = 0.02;
+const BRAKE_FRICTION: f32 = 0.5;
+const BELLY_FRICTION: f32 = 0.45;
+/// How fast tires (and, weakly, the belly) kill sideways slip (1/s)
+const TIRE_GRIP: f32 = 8.0;
+const BELLY_GRIP: f32 = 1.0;
+/// Speed at which the nose can be pulled off the runway (m/s)
+const ROTATE_SPEED: f32 = 70.0;
+/// Nose-wheel steering: wheelbase, max wheel angle, and max turn rate at taxi speed
+const WHEELBASE: f32 = 5.3;
+const NOSEWHEEL_MAX_ANGLE: f32 = 0.5;
+const NOSEWHEEL_MAX_RATE: f32 = 0.6;
+/// Clear of the ground by this much before the jet counts as airborne again
+const LIFTOFF_CLEARANCE: f32 = 0.5;
+/// Rolling slower than this after a touchdown is a full stop
+const FULL_STOP_SPEED: f32 = 1.0;
+
+/// Touchdown sink rates (m/s, into the ground) for each grade; past HARD the gear
+/// gives way, past CRASH the airframe does
+const GREASER_SINK: f32 = 1.0;
+const SMOOTH_SINK: f32 = 2.0;
+const FIRM_SINK: f32 = 3.5;
+const HARD_SINK: f32 = 5.0;
+const CRASH_SINK: f32 = 8.0;
+/// Sideways drift (radians between nose and track) the gear can take
+const MAX_CRAB: f32 = 0.26;
+/// Bank that puts a wingtip into the ground, and how far nose-low is a nose-first arrival
+const MAX_TOUCHDOWN_BANK: f32 = 0.35;
+const MAX_NOSE_LOW: f32 = 0.1;
+/// Tire limit with the gear down, and the fastest a belly landing survives (m/s)
+const MAX_TOUCHDOWN_SPEED: f32 = 130.0;
+const BELLY_MAX_SPEED: f32 = 90.0;
+/// Ground steeper than this (normal.y) can't be landed on
+const MAX_LANDING_SLOPE: f32 = 0.96;
+
+// ============================================================================
+// COMPONENTS & EVENTS
+// ============================================================================
+
+/// How a touchdown went, best first
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TouchdownGrade {
+    Greaser,
+    Smooth,
+    Firm,
+    Hard,
+    /// Gear sheared off; the jet slides on its belly
+    GearCollapse,
+    /// Gear up (or in transit) but slow and level enough to walk away from
+    BellyLanding,
+    Crash,
+}
+
+impl TouchdownGrade {
+    pub fn label(self) -> &'static str {
+        match self {
+            TouchdownGrade::Greaser => "GREASER",
+            TouchdownGrade::Smooth => "SMOOTH",
+            TouchdownGrade::Firm => "FIRM",
+            TouchdownGrade::Hard => "HARD",
+            TouchdownGrade::GearCollapse => "GEAR COLLAPSE",
+            TouchdownGrade::BellyLanding => "BELLY LANDING",
+            TouchdownGrade::Crash => "CRASH",
+        }
+    }
+}
+
+/// What the jet was doing the moment it met the ground
+#[derive(Clone, Copy, Debug)]
+pub struct Touchdown {
+    pub grade: TouchdownGrade,
+    /// Speed into the ground (m/s)
+    pub sink_rate: f32,
+    pub speed: f32,
+    /// Angle between the nose and the track over the ground (radians)
+    pub crab: f32,
+    /// Runway touched down on, with the track's angle off the runway axis and the
+    /// distance off the centerline
+    pub runway: Option<(Airfield, f32, f32)>,
+    /// `Time::elapsed_secs` at touchdown (for the HUD readout)
+    pub time: f32,
+}
+
+/// Landing gear, flaps and ground contact state. Lives on the player.
+#[derive(Component)]
+pub struct LandingGear {
+    /// Gear handle position
+    pub down: bool,
+    /// 0 = stowed, 1 = down and locked
+    pub extension: f32,
+    /// Current flap deflection (0..1)
+    pub flaps: f32,
+    flap_detent: usize,
+    /// Weight on wheels (or belly)
+    pub on_ground: bool,
+    pub last_touchdown: Option<Touchdown>,
+    /// Touched down and still rolling out
+    rolling_out: bool,
+}
+
+impl LandingGear {
+    /// Gear down on the ground, up in the air
+    pub fn new(on_ground: bool) -> Self {
+        Self {
+            down: on_ground,
+            extension: if on_ground { 1.0 } else { 0.0 },
+            flaps: 0.0,
+            flap_detent: 0,
+            on_ground,
+            last_touchdown: None,
+            rolling_out: false,
+        }
+    }
+
+    pub fn locked_down(&self) -> bool {
+        self.down && self.extension >= 1.0
+    }
+
+    /// Flap handle setting (0..1)
+    pub fn flap_setting(&self) -> f32 {
+        FLAP_SETTINGS[self.flap_detent]
+    }
+
+    /// Points that meet the ground first, relative to the jet: the wheels as far
+    /// out as the gear has travelled, or the belly with the gear stowed
+    fn contact_points(&self) -> Vec<Vec3> {
+        let belly = BELLY_POINTS.iter().map(|z| Vec3::new(0.0, -BELLY_HEIGHT, *z));
+        if self.extension <= 0.0 {
+            return belly.collect();
+        }
+        let height = BELLY_HEIGHT + (GEAR_HEIGHT - BELLY_HEIGHT) * self.extension;
+        WHEELS.iter().map(|(x, z)| Vec3::new(*x, -height, *z)).chain(belly).collect()
+    }
+}
+
+/// The jet came to a full stop on a runway after a touchdown
+#[derive(Event, Clone, Copy)]
+pub struct Landed {
+    pub airfield: Airfield,
+    pub touchdown: Touchdown,
+}
+
+pub struct LandingGearPlugin;
+
+impl Plugin for LandingGearPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Landed>()
+            .add_systems(PreUpdate, wheel_contact
+                .after(crate::check_ground_collision)
+                .run_if(in_state(GameState::Playing)))
+            .add_systems(Update, (
+                operate_gear_and_flaps.after(crate::read_player_input),
+                (apply_configuration_forces, ground_handling)
+                    .chain()
+                    .after(crate::arcade_flight_physics),
+                detect_full_stop,
+            ).run_if(in_state(GameState::Playing)));
+    }
+}
+
+/// Ground surface under a render-space point and its normal
+fn surface_at(origin: &WorldOrigin, point: Vec3) -> (f32, Vec3) {
+    let absolute = origin.to_absolute(point);
+    let height = |dx: f64, dz: f64| crate::terrain_surface_height(absolute.x + dx, absolute.z + dz);
+    let h_r = height(SLOPE_SAMPLE, 0.0);
+    let h_l = height(-SLOPE_SAMPLE, 0.0);
+    let h_d = height(0.0, SLOPE_SAMPLE);
+    let h_u = height(0.0, -SLOPE_SAMPLE);
+    let normal = Vec3::new(h_l - h_r, 2.0 * SLOPE_SAMPLE as f32, h_u - h_d).normalize();
+    (height(0.0, 0.0), normal)
+}
+
+/// Grade the arrival from sink rate, attitude, drift and what the gear was doing
+fn evaluate_touchdown(
+    gear: &LandingGear,
+    transform: &Transform,
+    velocity: Vec3,
+    normal: Vec3,
+    origin: &WorldOrigin,
+    time: f32,
+) -> Touchdown {
+    let sink_rate = -velocity.dot(normal);
+    let speed = velocity.length();
+    let (_, pitch, bank) = transform.rotation.to_euler(EulerRot::YXZ);
+
+    // Drift: nose vs track, both flattened onto the ground
+    let nose = transform.forward().as_vec3().reject_from_normalized(Vec3::Y).normalize_or_zero();
+    let track = velocity.reject_from_normalized(Vec3::Y).normalize_or_zero();
+    let crab = if track == Vec3::ZERO { 0.0 } else { nose.angle_between(track) };
+
+    // Alignment with the runway, either way along it
+    let absolute = origin.to_absolute(transform.translation);
+    let runway = airfield::airfield_at(absolute.x, absolute.z)
+        .filter(|field| field.on_runway(absolute.x, absolute.z))
+        .map(|field| {
+            let angle = track.angle_between(field.direction());
+            let misalignment = angle.min(std::f32::consts::PI - angle);
+            (field, misalignment, field.runway_coords(absolute.x, absolute.z).1)
+        });
+
+    let wingtip = bank.abs() > MAX_TOUCHDOWN_BANK;
+    let too_steep = normal.y < MAX_LANDING_SLOPE;
+    let grade = if !gear.locked_down() {
+        if speed > BELLY_MAX_SPEED || sink_rate > HARD_SINK || wingtip || too_steep {
+            TouchdownGrade::Crash
+        } else {
+            TouchdownGrade::BellyLanding
+        }
+    } else if sink_rate > CRASH_SINK || wingtip || too_steep || pitch < -MAX_NOSE_LOW || speed > MAX_TOUCHDOWN_SPEED {
+        TouchdownGrade::Crash
+    } else if sink_rate > HARD_SINK || crab > MAX_CRAB {
+        TouchdownGrade::GearCollapse
+    } else if sink_rate < GREASER_SINK {
+        TouchdownGrade::Greaser
+    } else if sink_rate < SMOOTH_SINK {
+        TouchdownGrade::Smooth
+    } else if sink_rate < FIRM_SINK {
+        TouchdownGrade::Firm
+    } else {
+        TouchdownGrade::Hard
+    };
+
+    Touchdown { grade, sink_rate, speed, crab, runway, time }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// Gear handle, flap detents, and the brake (wheel brakes on the ground, airbrake in the air)
+fn operate_gear_and_flaps(
+    time: Res<Time>,
+    actions: Res<ActionState>,
+    mut player_query: Query<(&mut LandingGear, &mut PlayerInput, &LinearVelocity), With<PlayerPlane>>,
+    mut play_sound: EventWriter<PlaySound>,
+    sounds: Res<GameAssets>,
+) {
+    let dt = time.delta_secs();
+    let Ok((mut gear, mut input, velocity)) = player_query.get_single_mut() else { return };
+    let speed = velocity.length();
+
+    input.brake = if actions.pressed(Action::Brake) { 1.0 } else { 0.0 };
+
+    if actions.just_pressed(Action::ToggleGear) {
+        // Weight on wheels locks the handle down, and above the limit it won't come down
+        let handle_locked = gear.on_ground && gear.down;
+        let too_fast = !gear.down && speed > GEAR_MAX_SPEED;
+        if !handle_locked && !too_fast {
+            gear.down = !gear.down;
+        }
+    }
+
+    let was_locked = gear.locked_down();
+    let target = if gear.down { 1.0 } else { 0.0 };
+    let step = dt / GEAR_TRANSIT_TIME;
+    gear.extension += (target - gear.extension).clamp(-step, step);
+    if !was_locked && gear.locked_down() {
+        // Three green: downlock clunk
+        play_sound.send(PlaySound::new(sounds.afterburner_thump.clone(), AudioBus::Ambient)
+            .with_volume(0.3)
+            .with_speed(1.6));
+    }
+
+    if actions.just_pressed(Action::CycleFlaps) {
+        gear.flap_detent = (gear.flap_detent + 1) % FLAP_SETTINGS.len();
+    }
+    // Too fast and the airload pushes them back up until the jet slows down
+    let flap_target = if speed > FLAP_BLOWBACK_SPEED { 0.0 } else { gear.flap_setting() };
+    let step = dt / FLAP_TRAVEL_TIME;
+    gear.flaps += (flap_target - gear.flaps).clamp(-step, step);
+}
+
+/// Drag from the gear, flaps and airbrake, and the extra lift of the flaps
+/// (runs after the flight model has set this frame's forces)
+fn apply_configuration_forces(
+    mut player_query: Query<(&PlayerInput, &LandingGear, &Transform, &LinearVelocity, &mut ExternalForce), With<PlayerPlane>>,
+) {
+    let Ok((input, gear, transform, velocity, mut ext_force)) = player_query.get_single_mut() else { return };
+    let air = space::atmosphere_factor(transform.translation.y);
+    let speed = velocity.length();
+    if speed < 1.0 || !speed.is_finite() {
+        return;
+    }
+
+    let airbrake = if gear.on_ground { 0.0 } else { input.brake };
+    let drag = GEAR_DRAG * gear.extension + FLAP_DRAG * gear.flaps + AIRBRAKE_DRAG * airbrake;
+    let drag_force = -velocity.0 * speed * drag * air;
+
+    let weight = MASS_KG * 9.81;
+    let lift = (FLAP_LIFT * gear.flaps * speed * speed).min(FLAP_MAX_LIFT * weight) * air;
+    let lift_force = transform.up().as_vec3() * lift;
+
+    let force = drag_force + lift_force;
+    if force.is_finite() {
+        ext_force.apply_force(force);
+    }
+}
+
+/// Hold the wheels (or belly) on the surface, grade each touchdown, and keep the
+/// jet sitting on the ground while it rolls. The terrain heightfield is too coarse
+/// to roll on, so contact is solved here against the same grid it is built from.
+pub fn wheel_contact(
+    time: Res<Time>,
+    origin: Res<WorldOrigin>,
+    mission_start: Res<MissionStart>,
+    mut player_query: Query<(&mut Transform, &mut LinearVelocity, &mut AngularVelocity, &mut LandingGear), With<PlayerPlane>>,
+    mut play_sound: EventWriter<PlaySound>,
+    mut effects: EventWriter<SpawnEffect>,
+    sounds: Res<GameAssets>,
+) {
+    let Ok((mut transform, mut velocity, mut ang_vel, mut gear)) = player_query.get_single_mut() else { return };
+    if !transform.translation.is_finite() {
+        return;
+    }
+
+    // Deepest point below the surface; water is left to the ditching code
+    let mut deepest: Option<(f32, Vec3)> = None;
+    for point in gear.contact_points() {
+        let world_point = transform.transform_point(point);
+        let (surface, normal) = surface_at(&origin, world_point);
+        if surface < water::SEA_LEVEL {
+            continue;
+        }
+        let depth = surface - world_point.y;
+        if deepest.is_none_or(|(deepest_depth, _)| depth > deepest_depth) {
+            deepest = Some((depth, normal));
+        }
+    }
+    let Some((depth, normal)) = deepest else { return };
+
+    if depth <= 0.0 {
+        if gear.on_ground && depth < -LIFTOFF_CLEARANCE {
+            gear.on_ground = false;
+            gear.rolling_out = false;
+        }
+        return;
+    }
+
+    if !gear.on_ground {
+        let touchdown = evaluate_touchdown(&gear, &transform, velocity.0, normal, &origin, time.elapsed_secs());
+
+        match touchdown.grade {
+            TouchdownGrade::Crash => {
+                effects.send(SpawnEffect::new(EffectType::AircraftCrash, transform.translation, 1.0));
+                play_sound.send(PlaySound::new(sounds.crash.clone(), AudioBus::Weapons));
+                // Full reset so physics never sees invalid rotation (avoids AABB panic)
+                PlayerSpawn::new(*mission_start).place(&origin, &mut transform, &mut velocity, &mut ang_vel, &mut gear);
+                return;
+            }
+            TouchdownGrade::GearCollapse | TouchdownGrade::BellyLanding => {
+                gear.down = false;
+                gear.extension = 0.0;
+                play_sound.send(PlaySound::new(sounds.crash.clone(), AudioBus::Weapons).with_volume(0.4));
+            }
+            _ => {
+                // Tire chirp
+                play_sound.send(PlaySound::new(sounds.afterburner_thump.clone(), AudioBus::Ambient)
+                    .with_volume((touchdown.sink_rate / HARD_SINK).clamp(0.2, 1.0))
+                    .with_speed(1.8));
+            }
+        }
+        gear.on_ground = true;
+        gear.rolling_out = true;
+        gear.last_touchdown = Some(touchdown);
+    }
+
+    // Sit on the surface, moving along it but never into it
+    transform.translation.y += depth;
+    let into_ground = velocity.0.dot(normal);
+    if into_ground < 0.0 {
+        velocity.0 -= normal * into_ground;
+    }
+
+    // Wings level and the nose no lower than the nose wheel allows
+    let (yaw, pitch, roll) = transform.rotation.to_euler(EulerRot::YXZ);
+    if pitch < 0.0 || roll != 0.0 {
+        transform.rotation = Quat::from_euler(EulerRot::YXZ, yaw, pitch.max(0.0), 0.0);
+    }
+}
+
+/// Rolling on the ground: tire grip, rolling friction and brakes, nose-wheel
+/// steering, and no pulling the nose up before rotate speed
+fn ground_handling(
+    time: Res<Time>,
+    mut player_query: Query<(&PlayerInput, &LandingGear, &Transform, &mut LinearVelocity, &mut AngularVelocity), With<PlayerPlane>>,
+) {
+    let dt = time.delta_secs();
+    let Ok((input, gear, transform, mut velocity, mut ang_vel)) = player_query.get_single_mut() else { return };
+    if !gear.on_ground {
+        return;
+    }
+    let on_wheels = gear.locked_down();
+
+    let forward = transform.forward().as_vec3().reject_from_normalized(Vec3::Y).normalize_or_zero();
+    let right = transform.right().as_vec3().reject_from_normalized(Vec3::Y).normalize_or_zero();
+    let horizontal = velocity.0.reject_from_normalized(Vec3::Y);
+
+    // Tires roll along the nose and resist sliding sideways
+    let grip = if on_wheels { TIRE_GRIP } else { BELLY_GRIP };
+    let slip = horizontal.dot(right);
+    velocity.0 -= right * slip * (grip * dt).min(1.0);
+
+    // Friction slows the jet down but never pushes it backwards
+    let friction = if on_wheels { ROLLING_FRICTION + BRAKE_FRICTION * input.brake } else { BELLY_FRICTION };
+    let horizontal = velocity.0.reject_from_normalized(Vec3::Y);
+    let ground_speed = horizontal.length();
+    if ground_speed > 0.0 {
+        let slowdown = (friction * 9.81 * dt).min(ground_speed);
+        velocity.0 -= horizontal / ground_speed * slowdown;
+    }
+
+    // Pitch: the nose only lifts past rotate speed, but can always come back down
+    let pitch_axis = transform.right().as_vec3();
+    let pitch_rate = ang_vel.0.dot(pitch_axis);
+    let (_, pitch, _) = transform.rotation.to_euler(EulerRot::YXZ);
+    let can_rotate = on_wheels && ground_speed > ROTATE_SPEED;
+    let blocked = (pitch_rate > 0.0 && !can_rotate) || (pitch_rate < 0.0 && pitch <= 0.0);
+    let pitch_rate = if blocked { 0.0 } else { pitch_rate };
+
+    // Yaw: nose-wheel steering turns about the main gear, tighter at taxi speed
+    let rolling_speed = velocity.0.dot(forward);
+    let yaw_rate = if on_wheels && pitch <= 0.0 {
+        let steer_limit = NOSEWHEEL_MAX_RATE * (1.0 - ground_speed / ROTATE_SPEED).clamp(0.15, 1.0);
+        (input.yaw * rolling_speed * NOSEWHEEL_MAX_ANGLE.tan() / WHEELBASE).clamp(-steer_limit, steer_limit)
+    } else {
+        0.0
+    };
+
+    ang_vel.0 = pitch_axis * pitch_rate + Vec3::Y * yaw_rate;
+}
+
+/// Stopping on a runway after a touchdown completes the mission (see the menu's debrief)
+fn detect_full_stop(
+    origin: Res<WorldOrigin>,
+    mut player_query: Query<(&Transform, &LinearVelocity, &mut LandingGear), With<PlayerPlane>>,
+    mut landed: EventWriter<Landed>,
+) {
+    let Ok((transform, velocity, mut gear)) = player_query.get_single_mut() else { return };
+    if !gear.on_ground || !gear.rolling_out || velocity.length() > FULL_STOP_SPEED {
+        return;
+    }
+    gear.rolling_out = false;
+    let Some(touchdown) = gear.last_touchdown else { return };
+
+    let absolute = origin.to_absolute(transform.translation);
+    if let Some(field) = airfield::airfield_at(absolute.x, absolute.z) {
+        landed.send(Landed { airfield: field, touchdown });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Arrive at the middle of the home runway, wings level and lined up, with
+    /// the given sink rate and ground speed; `drift` swings the track off the nose
+    fn touchdown(gear_down: bool, sink: f32, speed: f32, drift: f32, bank: f32) -> Touchdown {
+        let field = airfield::home_airfield();
+        let transform = Transform::from_translation(field.center.as_vec3() + Vec3::Y * GEAR_HEIGHT)
+            .with_rotation(field.rotation() * Quat::from_rotation_z(bank));
+        let track = Quat::from_rotation_y(drift) * field.direction();
+        let velocity = track * speed - Vec3::Y * sink;
+        evaluate_touchdown(&LandingGear::new(gear_down), &transform, velocity, Vec3::Y, &WorldOrigin::default(), 0.0)
+    }
+
+    fn grade(sink: f32) -> TouchdownGrade {
+        touchdown(true, sink, 70.0, 0.0, 0.0).grade
+    }
+
+    #[test]
+    fn sink_rate_sets_the_grade() {
+        assert_eq!(grade(0.5), TouchdownGrade::Greaser);
+        assert_eq!(grade(1.5), TouchdownGrade::Smooth);
+        assert_eq!(grade(3.0), TouchdownGrade::Firm);
+        assert_eq!(grade(4.5), TouchdownGrade::Hard);
+        assert_eq!(grade(6.0), TouchdownGrade::GearCollapse);
+        assert_eq!(grade(9.0), TouchdownGrade::Crash);
+    }
+
+    #[test]
+    fn drift_and_bank_break_a_soft_landing() {
+        assert_eq!(touchdown(true, 0.5, 70.0, MAX_CRAB + 0.1, 0.0).grade, TouchdownGrade::GearCollapse);
+        assert_eq!(touchdown(true, 0.5, 70.0, 0.0, MAX_TOUCHDOWN_BANK + 0.1).grade, TouchdownGrade::Crash);
+        assert_eq!(touchdown(true, 0.5, MAX_TOUCHDOWN_SPEED + 10.0, 0.0, 0.0).grade, TouchdownGrade::Crash);
+    }
+
+    #[test]
+    fn gear_up_arrivals() {
+        assert_eq!(touchdown(false, 1.0, 70.0, 0.0, 0.0).grade, TouchdownGrade::BellyLanding);
+        assert_eq!(touchdown(false, 1.0, BELLY_MAX_SPEED + 10.0, 0.0, 0.0).grade, TouchdownGrade::Crash);
+        assert_eq!(touchdown(false, HARD_SINK + 1.0, 70.0, 0.0, 0.0).grade, TouchdownGrade::Crash);
+    }
+
+    #[test]
+    fn lined_up_on_the_home_runway() {
+        let arrival = touchdown(true, 1.5, 70.0, 0.0, 0.0);
+        assert!(arrival.sink_rate > 1.49 && arrival.sink_rate < 1.51);
+        assert!(arrival.crab < 1e-3);
+
+        let (field, misalignment, offset) = arrival.runway.expect("touched down on the runway");
+        assert!(field.home);
+        assert!(misalignment < 1e-3);
+        assert!(offset.abs() < 0.1);
+    }
+}
@@ -0,0 +1,287 @@
+use bevy::{prelude::*, render::view::VisibilitySystems, transform::TransformSystem};
+use crate::{controls::{Action, ActionState}, GameState, world_active, PlayerPlane};
+
+// ============================================================================
+// COMPONENTS
+// ============================================================================
+
+/// Current detail level of a `LodGroup`: 0=full detail, 1=medium, 2=low, 3=billboard.
+/// `LOD_CULLED` means the group is past its cull distance and draws nothing.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct LODLevel(pub u8);
+
+pub const LOD_LEVELS: usize = 4;
+pub const LOD_CULLED: u8 = LOD_LEVELS as u8;
+
+/// What kind of prop a group is; selects its thresholds in `LodSettings`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LodCategory {
+    Tree,
+    Rock,
+    Building,
+    Meteor,
+    Drone,
+}
+
+impl LodCategory {
+    pub const ALL: [LodCategory; 5] = [
+        LodCategory::Tree,
+        LodCategory::Rock,
+        LodCategory::Building,
+        LodCategory::Meteor,
+        LodCategory::Drone,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Root of something with several representations. The level is picked from the
+/// root's distance to the player; its `LodVariant` children are shown or hidden to match.
+#[derive(Component)]
+#[require(LODLevel)]
+pub struct LodGroup {
+    pub category: LodCategory,
+    /// How many things this group draws (e.g. trees in a foliage batch), for stats
+    pub instances: u32,
+}
+
+impl LodGroup {
+    pub fn new(category: LodCategory) -> Self {
+        Self { category, instances: 1 }
+    }
+}
+
+/// One representation of a `LodGroup`, visible for levels `min..=max`
+#[derive(Component, Clone, Copy)]
+pub struct LodVariant {
+    pub min: u8,
+    pub max: u8,
+}
+
+impl LodVariant {
+    pub const fn levels(min: u8, max: u8) -> Self {
+        Self { min, max }
+    }
+
+    fn visible_at(&self, level: u8) -> bool {
+        level >= self.min && level <= self.max
+    }
+}
+
+// ============================================================================
+// RESOURCES
+// ============================================================================
+
+/// Distance bands for one category
+#[derive(Clone, Copy, Debug)]
+pub struct LodThresholds {
+    /// Distances where level 0→1, 1→2 and 2→3 begin (meters, ascending)
+    pub distances: [f32; LOD_LEVELS - 1],
+    /// Beyond this nothing is drawn
+    pub cull_distance: f32,
+}
+
+impl LodThresholds {
+    /// Level for a distance, without hysteresis
+    fn level_for(&self, distance: f32) -> u8 {
+        if distance > self.cull_distance {
+            return LOD_CULLED;
+        }
+        self.distances.iter().filter(|d| distance > **d).count() as u8
+    }
+
+    /// Distance at which `level` hands over to `level + 1`
+    fn upper_bound(&self, level: u8) -> f32 {
+        self.distances.get(level as usize).copied().unwrap_or(self.cull_distance)
+    }
+}
+
+/// Tunable LOD distances. Chunks load out to 8 km, so everything here sits inside that.
+#[derive(Resource, Clone, Debug)]
+pub struct LodSettings {
+    pub thresholds: [LodThresholds; 5],
+    /// A group only changes level once it is this far past a boundary (prevents popping)
+    pub hysteresis: f32,
+    /// Print `LodStats` every couple of seconds (F3)
+    pub report_stats: bool,
+}
+
+impl Default for LodSettings {
+    fn default() -> Self {
+        Self {
+            thresholds: [
+                // Tree
+                LodThresholds { distances: [800.0, 1600.0, 2500.0], cull_distance: 7000.0 },
+                // Rock
+                LodThresholds { distances: [600.0, 1500.0, 3000.0], cull_distance: 4000.0 },
+                // Building
+                LodThresholds { distances: [1000.0, 2000.0, 4000.0], cull_distance: 8000.0 },
+                // Meteor
+                LodThresholds { distances: [700.0, 1500.0, 3000.0], cull_distance: 6000.0 },
+                // Drone: never culled, they are threats
+                LodThresholds { distances: [1500.0, 3000.0, 6000.0], cull_distance: f32::INFINITY },
+            ],
+            hysteresis: 100.0,
+            report_stats: false,
+        }
+    }
+}
+
+impl LodSettings {
+    pub fn thresholds(&self, category: LodCategory) -> &LodThresholds {
+        &self.thresholds[category.index()]
+    }
+
+    /// Next level for a group currently at `current`. Only moves once the distance is
+    /// `hysteresis` meters past the boundary in either direction.
+    fn next_level(&self, category: LodCategory, current: u8, distance: f32) -> u8 {
+        let thresholds = self.thresholds(category);
+        let target = thresholds.level_for(distance);
+        if target > current && distance < thresholds.upper_bound(current) + self.hysteresis {
+            return current;
+        }
+        if target < current && current > 0 && distance > thresholds.upper_bound(current - 1) - self.hysteresis {
+            return current;
+        }
+        target
+    }
+}
+
+/// Per-frame counts of groups and instances at each level (index `LOD_CULLED` = culled)
+#[derive(Resource, Default, Debug)]
+pub struct LodStats {
+    pub groups: [[u32; LOD_LEVELS + 1]; 5],
+    pub instances: [[u32; LOD_LEVELS + 1]; 5],
+}
+
+/// Shared low-detail stand-ins used by the far LOD levels
+#[derive(Resource)]
+pub struct LodAssets {
+    pub building_proxy_mesh: Handle<Mesh>,
+    pub building_proxy_material: Handle<StandardMaterial>,
+    pub meteor_proxy_mesh: Handle<Mesh>,
+    pub meteor_proxy_material: Handle<StandardMaterial>,
+}
+
+pub struct LodPlugin;
+
+impl Plugin for LodPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LodSettings>()
+            .init_resource::<LodStats>()
+            .add_systems(OnEnter(GameState::Spawning), setup_lod_assets)
+            // PostUpdate so props spawned this frame get a level before they are drawn: after
+            // propagation (fresh GlobalTransforms, also right after an origin shift), before
+            // visibility is propagated from the variants we toggle
+            .add_systems(PostUpdate, update_lod_levels
+                .after(TransformSystem::TransformPropagate)
+                .before(VisibilitySystems::VisibilityPropagate)
+                .run_if(world_active))
+            .add_systems(Update, (
+                toggle_lod_stats,
+                report_lod_stats,
+            ).run_if(world_active));
+    }
+}
+
+fn setup_lod_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(LodAssets {
+        // Unit box sitting on its base, scaled like the wall model it replaces
+        building_proxy_mesh: meshes.add(Cuboid::new(1.0, 1.0, 1.0).mesh().build().translated_by(Vec3::Y * 0.5)),
+        building_proxy_material: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.55, 0.42, 0.32),
+            perceptual_roughness: 0.9,
+            ..default()
+        }),
+        // Same radius as the meteor collider
+        meteor_proxy_mesh: meshes.add(Sphere::new(0.8).mesh().ico(1).unwrap()),
+        meteor_proxy_material: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.35, 0.3, 0.28),
+            perceptual_roughness: 1.0,
+            ..default()
+        }),
+    });
+    eprintln!("🔭 STARTUP: LOD proxies created");
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+fn update_lod_levels(
+    settings: Res<LodSettings>,
+    mut stats: ResMut<LodStats>,
+    player_query: Query<&Transform, With<PlayerPlane>>,
+    mut group_query: Query<(&GlobalTransform, &LodGroup, &mut LODLevel, Option<Ref<Children>>)>,
+    mut variant_query: Query<(&LodVariant, &mut Visibility)>,
+) {
+    let Ok(player_transform) = player_query.get_single() else { return };
+    let player_pos = player_transform.translation;
+
+    *stats = LodStats::default();
+
+    for (group_transform, group, mut lod, children) in &mut group_query {
+        let distance = player_pos.distance(group_transform.translation());
+        let level = settings.next_level(group.category, lod.0, distance);
+
+        let category = group.category.index();
+        stats.groups[category][level as usize] += 1;
+        stats.instances[category][level as usize] += group.instances;
+
+        // Compare before writing so unchanged groups don't trip change detection.
+        // Variants added after the group (e.g. foliage meshes built once loaded) still need a pass.
+        let children_changed = children.as_ref().is_some_and(|children| children.is_changed());
+        if lod.0 == level && !lod.is_added() && !children_changed {
+            continue;
+        }
+        lod.0 = level;
+
+        for &child in children.iter().flat_map(|children| children.iter()) {
+            if let Ok((variant, mut visibility)) = variant_query.get_mut(child) {
+                *visibility = if variant.visible_at(level) { Visibility::Inherited } else { Visibility::Hidden };
+            }
+        }
+    }
+}
+
+/// F3 (by default) toggles the periodic LOD report
+fn toggle_lod_stats(
+    actions: Res<ActionState>,
+    mut settings: ResMut<LodSettings>,
+) {
+    if actions.just_pressed(Action::ToggleLodStats) {
+        settings.report_stats = !settings.report_stats;
+        println!("🔭 LOD stats {}", if settings.report_stats { "ON" } else { "OFF" });
+    }
+}
+
+fn report_lod_stats(
+    time: Res<Time>,
+    settings: Res<LodSettings>,
+    stats: Res<LodStats>,
+    mut since_report: Local<f32>,
+) {
+    if !settings.report_stats {
+        return;
+    }
+    *since_report += time.delta_secs();
+    if *since_report < 2.0 {
+        return;
+    }
+    *since_report = 0.0;
+
+    println!("🔭 LOD draws per level [L0, L1, L2, L3, culled]:");
+    for category in LodCategory::ALL {
+        let i = category.index();
+        println!(
+            "   {:<8?} instances {:?}  groups {:?}",
+            category, stats.instances[i], stats.groups[i]
+        );
+    }
+}
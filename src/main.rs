@@ -1,9 +1,10 @@
 use bevy::{
-    pbr::{CascadeShadowConfigBuilder, DirectionalLightShadowMap},
     prelude::*,
+    ecs::system::SystemParam,
     render::mesh::VertexAttributeValues,
     render::camera::Exposure,
     image::{ImageSampler, ImageAddressMode, ImageSamplerDescriptor},
+    math::DVec3,
     window::PrimaryWindow,
     winit::WinitWindows,
     core_pipeline::bloom::{Bloom, BloomCompositeMode},
@@ -19,49 +20,98 @@ mod drone;
 mod ui; // NEW: HUD System
 mod procedural_textures; // NEW: Procedural Grass Texture
 mod assets; // NEW: Asset Loader
+mod biome; // NEW: Biome queries + terrain splat material
+mod water; // NEW: Sea level, rivers, water surfaces & splashes
+mod foliage; // NEW: Merged per-chunk tree batches + billboard impostors
+mod lod; // NEW: Distance-based LOD for props
+mod particles; // NEW: Pooled particle system
+mod effects; // NEW: Explosion & destruction VFX library
+mod time_of_day; // NEW: Day/night cycle
+mod weather; // NEW: Rain, fog & wind
+mod clouds; // NEW: Soft sprite clouds you can fly through
+mod sky; // NEW: Atmospheric scattering sky
+mod space; // NEW: Rocket, RCS & re-entry above the atmosphere
+mod origin; // NEW: Floating origin for large worlds
+mod camera; // NEW: Chase, cockpit, orbit & cinematic views
+mod replay; // NEW: Flight recorder & replay viewer
+mod controls; // NEW: Action mapping, gamepads & rebinding
+mod mouse_aim; // NEW: Mouse-aim flight with instructor controller
+mod settings; // NEW: Persisted player settings
+mod menu; // NEW: Main menu, mission select, settings & pause menu
+mod graphics; // NEW: Graphics quality presets & overrides
+mod audio; // NEW: Audio mixer buses, spatialization & sound travel time
+mod engine_audio; // NEW: Spooling turbine sound layers, flame-out & sonic boom
+mod warnings; // NEW: Prioritized cockpit voice warnings
+mod gpws; // NEW: Ground proximity warning & Auto-GCAS
+mod airfield; // NEW: Procedural airfields, runways & mission start point
+mod landing_gear; // NEW: Gear, flaps, brakes, wheel contact & touchdown grading
+mod resupply; // NEW: Jet fuel, weapon stores, tanker boom & airfield ground service
 use bevy_asset_loader::prelude::*;
 use assets::GameAssets;
 use drone::{Drone, DronePlugin};
+use biome::{BiomePlugin, GroundMaterial};
+use lod::{LodCategory, LodGroup, LodVariant};
+use particles::{EmitParticles, ParticleType};
+use effects::{EffectType, SpawnEffect};
+use controls::{Action, ActionState, FlightAxis};
 
 #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
 pub enum GameState {
     #[default]
     Loading,
+    MainMenu, // Title screen, mission select & settings
     Spawning, // One-time setup state
     Playing,
     Paused,
+    Replay, // Watching a recorded flight (F8)
 }
 
-/// Generate terrain height using a multi-biome selector (Plains, Canyons, Mountains)
-fn get_terrain_height(world_x: f32, world_z: f32) -> f32 {
-    let perlin = Perlin::new(42);
+/// Seed of every noise field the terrain is built from. Chunks are a pure
+/// function of it and their coordinate, so replays regenerate the same world.
+pub const WORLD_SEED: u32 = 42;
+
+/// Run condition for systems that keep the world alive around the player:
+/// chunk streaming, sky, weather and effects run for live flight and replays alike
+pub fn world_active(state: Res<State<GameState>>) -> bool {
+    matches!(state.get(), GameState::Playing | GameState::Replay)
+}
+
+/// Generate terrain height using a multi-biome selector (Plains, Canyons, Mountains).
+/// Takes absolute world coordinates; render-space callers go through `origin::WorldOrigin`.
+fn get_terrain_height(world_x: f64, world_z: f64) -> f32 {
+    // Runways are graded flat into whatever the land does around them
+    airfield::flatten_terrain(world_x, world_z, natural_terrain_height(world_x, world_z))
+}
+
+/// The land before any airfield is graded into it
+fn natural_terrain_height(world_x: f64, world_z: f64) -> f32 {
+    let perlin = Perlin::new(WORLD_SEED);
 
     // 1. BIOME SELECTOR (Very large scale: 1/4000 meters)
-    // Determines where mountains, flats, and canyons are placed
-    let selector_raw = perlin.get([world_x as f64 * 0.0002, world_z as f64 * 0.0002]) as f32;
-    let s = (selector_raw + 1.0) / 2.0; // Map -1..1 to 0..1
+    // Determines where mountains, flats, and canyons are placed (shared with biome::biome_at)
+    let s = biome::biome_selector(world_x, world_z);
 
     // 2. BIOME A: FLATLANDS (Lowlands)
     // Gently rolling plains for high-speed flight
-    let flat_noise = perlin.get([world_x as f64 * 0.001, world_z as f64 * 0.001]) as f32;
+    let flat_noise = perlin.get([world_x * 0.001, world_z * 0.001]) as f32;
     let biome_flats = flat_noise * 30.0;
 
     // 3. BIOME B: THE CANYONS (Ridged Noise)
     // Sharp valleys and narrow passes for tactical maneuvering
-    let ridge_raw = perlin.get([world_x as f64 * 0.002, world_z as f64 * 0.002]) as f32;
+    let ridge_raw = perlin.get([world_x * 0.002, world_z * 0.002]) as f32;
     let biome_canyons = (1.0 - ridge_raw.abs()).powi(2) * 500.0 - 250.0;
 
     // 4. BIOME C: MASSIVE PEAKS (Highlands)
     // Huge 1.2km+ mountains that force the player to climb or dodge
-    let mountain_raw = perlin.get([world_x as f64 * 0.0007, world_z as f64 * 0.0007]) as f32;
+    let mountain_raw = perlin.get([world_x * 0.0007, world_z * 0.0007]) as f32;
     let mountain_base = (mountain_raw.abs() * 1400.0) - 100.0;
     // Add craggy detail to the mountain peaks
-    let mountain_detail = perlin.get([world_x as f64 * 0.005, world_z as f64 * 0.005]) as f32 * 50.0;
+    let mountain_detail = perlin.get([world_x * 0.005, world_z * 0.005]) as f32 * 50.0;
     let biome_mountains = mountain_base + mountain_detail;
 
     // 5. WEIGHTED BIOME BLENDING
     // Blends smoothly between types to avoid hard "cliffs" at biome borders
-    if s < 0.35 {
+    let height = if s < 0.35 {
         // Mostly flats, blending into canyons
         let t = (s / 0.35).clamp(0.0, 1.0);
         // Smoothstep interpolation for natural transitions
@@ -75,7 +125,11 @@ fn get_terrain_height(world_x: f32, world_z: f32) -> f32 {
     } else {
         // Pure high-altitude mountain range
         biome_mountains
-    }
+    };
+
+    // 6. RIVERS: carve channels through the lowlands, fading out toward the mountains
+    let river_mask = 1.0 - biome::smoothstep(0.25, 0.45, s);
+    water::carve_river(world_x, world_z, height, river_mask)
 }
 
 // #region agent log
@@ -101,7 +155,6 @@ fn debug_log(location: &str, message: &str, data: &str, hypothesis_id: &str) {
 struct AfterburnerParticles {
     spawn_rate: f32,
     spawn_threshold: f32,
-    particle_lifetime: f32,
     last_spawn_pos: Option<Vec3>, // For "Ribbon" interpolation
 }
 
@@ -110,33 +163,11 @@ impl Default for AfterburnerParticles {
         Self {
             spawn_rate: 60.0, // Increased spawn rate for dense ribbons
             spawn_threshold: 0.1,
-            particle_lifetime: 1.5, // Longer life for lingering trails
             last_spawn_pos: None,
         }
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
-enum ParticleType {
-    Exhaust, // Fire transitioning to smoke
-    BulletImpact,
-    Explosion,
-}
-
-/// Individual particle component for cinematic trails
-#[derive(Component)]
-struct Particle {
-    lifetime_remaining: f32,
-    lifetime_max: f32,
-    velocity: Vec3,
-    start_scale: f32,
-    end_scale: f32,
-    start_color: LinearRgba,
-    end_color: LinearRgba,
-    base_emissive: LinearRgba,
-    p_type: ParticleType,
-}
-
 /// Chunk coordinate system
 #[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
 struct ChunkCoordinate {
@@ -145,18 +176,20 @@ struct ChunkCoordinate {
 }
 
 impl ChunkCoordinate {
-    fn from_world_pos(pos: Vec3) -> Self {
+    /// Chunk containing an absolute world position
+    fn from_world_pos(pos: DVec3) -> Self {
         Self {
-            x: (pos.x / CHUNK_SIZE).floor() as i32,
-            z: (pos.z / CHUNK_SIZE).floor() as i32,
+            x: (pos.x / CHUNK_SIZE as f64).floor() as i32,
+            z: (pos.z / CHUNK_SIZE as f64).floor() as i32,
         }
     }
 
-    fn world_position(&self) -> Vec3 {
-        Vec3::new(
-            self.x as f32 * CHUNK_SIZE,
+    /// Absolute world position of the chunk center
+    fn world_position(&self) -> DVec3 {
+        DVec3::new(
+            self.x as f64 * CHUNK_SIZE as f64,
             0.0,
-            self.z as f32 * CHUNK_SIZE,
+            self.z as f64 * CHUNK_SIZE as f64,
         )
     }
 }
@@ -164,26 +197,41 @@ impl ChunkCoordinate {
 #[derive(Component)]
 struct ChunkEntity; // Tag for entities that belong to chunks
 
+/// Marker for the terrain surface of a chunk (carries the heightfield collider)
+#[derive(Component)]
+struct Terrain;
+
 #[derive(Resource, Default)]
 struct ChunkManager {
     loaded_chunks: std::collections::HashMap<ChunkCoordinate, Entity>,
     last_player_chunk: ChunkCoordinate,
 }
 
+/// Everything a new chunk is built from: shared meshes/materials, plus the
+/// weather, origin and quality settings that shape what goes in it
+#[derive(SystemParam)]
+struct ChunkAssets<'w> {
+    asset_server: Res<'w, AssetServer>,
+    meshes: ResMut<'w, Assets<Mesh>>,
+    materials: ResMut<'w, Assets<StandardMaterial>>,
+    ground_material: Res<'w, GroundMaterial>, // Shared ground material
+    water: Res<'w, water::WaterAssets>, // Shared water surface mesh/material
+    lod: Res<'w, lod::LodAssets>, // Far-LOD proxy meshes
+    clouds: Res<'w, clouds::CloudAssets>, // Shared cloud sprite quad/materials
+    airfields: Res<'w, airfield::AirfieldAssets>, // Shared runway meshes/materials
+    weather: Res<'w, weather::Weather>,
+    origin: Res<'w, origin::WorldOrigin>, // Chunks live in absolute coordinates
+    graphics: Res<'w, graphics::GraphicsSettings>, // View distance & prop counts
+}
+
 // Constants
 const CHUNK_SIZE: f32 = 1000.0; // 1km x 1km chunks
-const LOAD_RADIUS_CHUNKS: i32 = 8; // Load chunks within 8km (fixes ground holes)
-const UNLOAD_RADIUS_CHUNKS: i32 = 12; // Unload chunks beyond 12km
+// Load/unload radii, tree and meteor counts come from graphics::GraphicsSettings
+const TERRAIN_SUBDIVISIONS: usize = 20; // 20x20 grid = 800 triangles per chunk (mesh + heightfield)
 
 #[derive(Component)]
 struct Tree;
 
-#[derive(Component)]
-struct LODLevel(u8); // 0=full detail, 1=medium, 2=low, 3=billboard
-
-const TREES_PER_CHUNK_MIN: usize = 5;  // REDUCED (was 10)
-const TREES_PER_CHUNK_MAX: usize = 10; // REDUCED (was 50)
-
 /// Marker component for meteors
 #[derive(Component)]
 pub struct Meteor;
@@ -214,32 +262,9 @@ struct VillageRoad;
 #[derive(Component)]
 struct VillageDecoration;
 
-/// Marker component for cloud entities
-#[derive(Component)]
-struct Cloud;
-
-/// Marker component for the sky sphere
-#[derive(Component)]
-struct SkySphere;
-
-/// Marker component for the infinite horizon disk
-#[derive(Component)]
-struct HorizonDisk;
-
-/// Marker component for the visual sun sphere
-#[derive(Component)]
-struct SunMarker;
-
-/// Resource holding the shared ground material (loaded once at startup)
-#[derive(Resource)]
-struct GroundMaterial(Handle<StandardMaterial>);
-
 
 
 
-/// Marker for the active engine sound entity
-#[derive(Component)]
-struct EngineSound;
 
 /// Marker for the wind sound entity
 #[derive(Component)]
@@ -253,94 +278,10 @@ struct WindStressSound;
 #[derive(Component)]
 struct WarningSound;
 
-/// Component for manual audio attenuation (fading volume with distance)
-/// Used to bypass Bevy's spatial audio limitations with stereo files
-#[derive(Component)]
-struct ManualAttenuation {
-    max_distance: f32, // Not used for inverse square, but good for culling
-    reference_distance: f32, // Distance where volume is 50%
-    base_volume: f32,
-    age: f32,
-    doppler_ramp_time: f32, // Seconds to transition to full Doppler (preserves initial punch)
-}
-
-/// System to manually update volume based on distance to player
-/// Solves "silent spatial audio" issues
-fn update_manual_audio_attenuation(
-    time: Res<Time>,
-    player_query: Query<(&GlobalTransform, &LinearVelocity), With<PlayerPlane>>,
-    mut audio_query: Query<(&GlobalTransform, &AudioSink, &mut ManualAttenuation, Option<&Parent>)>,
-    velocity_query: Query<&LinearVelocity>,
-) {
-    let Ok((player_transform, player_velocity)) = player_query.get_single() else { return };
-    let player_pos = player_transform.translation();
-
-    let mut count = 0;
-    for (transform, sink, mut settings, parent) in &mut audio_query {
-        count += 1;
-        settings.age += time.delta_secs();
-
-        let sound_pos = transform.translation();
-        let distance = player_pos.distance(sound_pos);
-        
-        // Skip processing if too far to save performance
-        if distance > settings.max_distance {
-            sink.set_volume(0.0);
-            continue;
-        }
-
-        // 1. INVERSE SQUARE LAW (Realistic attenuation)
-        // volume = ref / (ref + dist)
-        // This ensures it never cuts off, but fades naturally forever
-        let volume_fraction = settings.reference_distance / (settings.reference_distance + distance);
-        sink.set_volume(settings.base_volume * volume_fraction);
-
-        // 2. DOPPLER SHIFT
-        // Need relative velocity along the line of sight
-        let mut source_velocity = Vec3::ZERO;
-        
-        // Try to get velocity from parent (e.g. Missile)
-        if let Some(parent_entity) = parent {
-            if let Ok(vel) = velocity_query.get(parent_entity.get()) {
-                source_velocity = vel.0;
-            }
-        }
-
-        let direction_to_player = (player_pos - sound_pos).normalize_or_zero();
-        let relative_velocity = source_velocity - player_velocity.0;
-        let speed_towards_player = relative_velocity.dot(direction_to_player);
-
-        // Speed of sound ~343 m/s
-        // Pitch = 1.0 + (v / c)
-        // Tune Doppler effect with a scaling factor (0.5 is standard "cinematic" value)
-        const DOPPLER_SCALE: f32 = 0.5;
-        let doppler_factor = (speed_towards_player / 343.0) * DOPPLER_SCALE;
-        
-        // Limit pitch shift to avoid craziness (0.5x to 2.0x) - Standard range
-        // This prevents the sound from disappearing into sub-bass rumble
-        let target_pitch = (1.0 + doppler_factor).clamp(0.5, 2.0);
-        
-        // Doppler Ramp: Blend from 1.0 (Normal) to Target (Doppler) based on age
-        // This preserves the initial "Punch" of the sound before physics takes over
-        let ramp_factor = (settings.age / settings.doppler_ramp_time).clamp(0.0, 1.0);
-        
-        // LERP: Start at 1.0, fade to target_pitch
-        let current_pitch = 1.0 + (target_pitch - 1.0) * ramp_factor;
-        
-        sink.set_speed(current_pitch);
-
-        // DEBUG: Print info for the first active sound only
-        if count == 1 {
-            println!("[AUDIO] Active: {} | Age: {:.2}s | Dist: {:.1}m | Pitch: {:.2}x (Tgt: {:.2}x)", 
-                count, settings.age, distance, current_pitch, target_pitch);
-        }
-    }
-}
-
 /// System to handle high-G "Air Rip" sound during turns
 fn update_maneuver_audio(
     player_query: Query<&AngularVelocity, With<PlayerPlane>>,
-    mut audio_query: Query<&AudioSink, With<WindStressSound>>,
+    mut audio_query: Query<(&mut audio::MixerSound, Option<&AudioSink>), With<WindStressSound>>,
 ) {
     if let Ok(ang_vel) = player_query.get_single() {
         // High-G turn detection (rotation speed)
@@ -349,34 +290,13 @@ fn update_maneuver_audio(
         // Threshold: Starts becoming audible at 1.5 rad/s
         let target_volume = ((rotation_intensity - 1.5) * 0.6).clamp(0.0, 1.0);
         
-        for sink in &mut audio_query {
-            sink.set_volume(target_volume);
+        for (mut sound, sink) in &mut audio_query {
+            sound.volume = target_volume;
             // Increase pitch slightly as G-force increases
-            sink.set_speed(0.8 + target_volume * 0.4);
-        }
-    }
-}
-
-/// Trigger cinematic "Boom" when afterburner kicks in
-fn update_afterburner_audio(
-    player_query: Query<&PlayerInput, With<PlayerPlane>>,
-    mut commands: Commands,
-    assets: Res<GameAssets>,
-    mut prev_throttle: Local<f32>,
-) {
-    if let Ok(input) = player_query.get_single() {
-        // If throttle just jumped into the "boost" zone (0.9+)
-        if input.throttle > 0.9 && *prev_throttle <= 0.9 {
-            commands.spawn((
-                AudioPlayer(assets.afterburner_thump.clone()),
-                PlaybackSettings {
-                    mode: bevy::audio::PlaybackMode::Despawn,
-                    volume: bevy::audio::Volume::new(1.5), // Loud thump
-                    ..default()
-                },
-            ));
+            if let Some(sink) = sink {
+                sink.set_speed(0.8 + target_volume * 0.4);
+            }
         }
-        *prev_throttle = input.throttle;
     }
 }
 
@@ -385,20 +305,6 @@ fn update_afterburner_audio(
 #[derive(Component)]
 struct ModelContainer;
 
-/// Rocket mode state (Space travel)
-#[derive(Component)]
-struct RocketMode {
-    enabled: bool,
-}
-
-impl Default for RocketMode {
-    fn default() -> Self {
-        Self { enabled: false }
-    }
-}
-
-const ROCKET_THRUST_MULTIPLIER: f32 = 8.0;
-
 /// Stores current player input state
 #[derive(Component)]
 struct PlayerInput {
@@ -406,7 +312,7 @@ struct PlayerInput {
     roll: f32,
     yaw: f32,      // Added Yaw (Rudder)
     throttle: f32,
-    _brake: f32,    // Airbrake
+    brake: f32,     // Wheel brakes on the ground, airbrake in the air
 }
 
 /// Timer for debug diagnostics
@@ -420,7 +326,7 @@ impl Default for PlayerInput {
             roll: 0.0,
             yaw: 0.0,
             throttle: 0.0, // Start at 0 throttle
-            _brake: 0.0,
+            brake: 0.0,
         }
     }
 }
@@ -484,21 +390,6 @@ impl Default for FlightControlComputer {
     }
 }
 
-#[derive(Component)]
-struct FlightCamera {
-    _local_offset: Vec3,
-    _rotation_lag_speed: f32,
-}
-
-impl Default for FlightCamera {
-    fn default() -> Self {
-        Self {
-            _local_offset: Vec3::new(0.0, 5.0, 15.0),
-            _rotation_lag_speed: 5.0, // Stiffer camera for high speed
-        }
-    }
-}
-
 // ============================================================================
 // AERODYNAMICS ENGINE (JSBSim Port)
 // ============================================================================
@@ -640,12 +531,6 @@ struct MuzzleFlash {
     lifetime: f32,
 }
 
-#[derive(Component)]
-struct VisualDebris {
-    velocity: Vec3,
-    lifetime: f32,
-}
-
 #[derive(Component)]
 struct MachineGunState {
     last_fired: f32,
@@ -670,6 +555,23 @@ struct Bullet {
     is_tracer: bool,
 }
 
+/// Bullet and missile meshes/materials, built once instead of on every shot
+#[derive(Resource)]
+struct WeaponAssets {
+    bullet_mesh: Handle<Mesh>,
+    bullet_material: Handle<StandardMaterial>,
+    tracer_mesh: Handle<Mesh>,
+    tracer_material: Handle<StandardMaterial>,
+    missile_body_mesh: Handle<Mesh>,
+    missile_body_material: Handle<StandardMaterial>,
+    missile_nose_mesh: Handle<Mesh>,
+    missile_nose_material: Handle<StandardMaterial>,
+    missile_exhaust_mesh: Handle<Mesh>,
+    missile_exhaust_material: Handle<StandardMaterial>,
+    missile_fin_mesh: Handle<Mesh>,
+    missile_fin_material: Handle<StandardMaterial>,
+}
+
 // ============================================================================
 // CONSTANTS
 // ============================================================================
@@ -704,6 +606,10 @@ const MISSILE_LENGTH: f32 = 2.0;
 const MISSILE_BODY_RADIUS: f32 = 0.15;
 const MISSILE_FIN_SIZE: f32 = 0.3;
 
+// Explosion audio
+const EXPLOSION_SOUND_REFERENCE: f32 = 400.0; // Half volume at 400m
+const EXPLOSION_SOUND_RANGE: f32 = 15000.0; // Distant booms still roll in (late)
+
 fn finish_spawning(mut next_state: ResMut<NextState<GameState>>) {
     next_state.set(GameState::Playing);
 }
@@ -722,78 +628,100 @@ fn main() {
             ..default()
         }))
         .add_plugins(PhysicsPlugins::default())
+        .add_plugins(controls::ControlsPlugin) // NEW: Rebindable keyboard/gamepad/HOTAS controls (F1)
+        .add_plugins(settings::SettingsPlugin) // NEW: config/settings.cfg, applied at startup
+        .add_plugins(graphics::GraphicsPlugin) // NEW: Quality presets (config/graphics.cfg)
         .init_state::<GameState>()
         .add_loading_state(
             LoadingState::new(GameState::Loading)
-                .continue_to_state(GameState::Spawning)
+                .continue_to_state(GameState::MainMenu)
                 .load_collection::<GameAssets>()
         )
-        .insert_resource(ClearColor(Color::srgb(0.5, 0.6, 0.8))) // Skybox match
-        .insert_resource(DirectionalLightShadowMap { size: 4096 }) // High-res shadows from Bevy example
+        .insert_resource(ClearColor(Color::BLACK)) // Fully covered by the sky shader
         .init_resource::<F16AeroData>() // Load Aero Data
         // .init_resource::<SoundAssets>() // REMOVED: Now handled by GameAssets
         .init_resource::<ChunkManager>() // NEW: Chunk Manager
         .add_plugins(DronePlugin)
         .add_plugins(ui::UiPlugin) // NEW: HUD
+        .add_plugins(BiomePlugin) // NEW: Terrain splat material
+        .add_plugins(water::WaterPlugin) // NEW: Lakes, rivers & splashes
+        .add_plugins(foliage::FoliagePlugin) // NEW: Batched forests
+        .add_plugins(lod::LodPlugin) // NEW: Multi-level prop LOD
+        .add_plugins(particles::ParticlePlugin) // NEW: Pooled particles
+        .add_plugins(effects::EffectsPlugin) // NEW: SpawnEffect explosions
+        .insert_resource(time_of_day::TimeOfDay::new(14.0, 1.0)) // Mission start time & time scale
+        .add_plugins(time_of_day::TimeOfDayPlugin) // NEW: Moving sun, moon, stars
+        .insert_resource(weather::Weather::preset(weather::WeatherPreset::Scattered)) // Mission weather
+        .add_plugins(weather::WeatherPlugin) // NEW: Rain, fog & wind
+        .add_plugins(clouds::CloudPlugin) // NEW: Streamed cloud puffs
+        .add_plugins(sky::SkyPlugin) // NEW: Rayleigh/Mie sky dome
+        .add_plugins(space::SpacePlugin) // NEW: Space flight regime
+        .add_plugins(origin::FloatingOriginPlugin) // NEW: Re-center world around the player
+        .add_plugins(camera::CameraPlugin) // NEW: Multi-view camera (C to cycle)
+        .add_plugins(replay::ReplayPlugin) // NEW: Session recorder & replay viewer (F8)
+        .add_plugins(mouse_aim::MouseAimPlugin) // NEW: Mouse-aim flight mode (M)
+        .add_plugins(menu::MenuPlugin) // NEW: Main & pause menus
+        .add_plugins(audio::AudioMixerPlugin) // NEW: Bus mixer, 3D sound & central pause
+        .add_plugins(engine_audio::EngineAudioPlugin) // NEW: N1/N2 engine layers & sonic booms
+        .add_plugins(warnings::WarningsPlugin) // NEW: PULL UP, MISSILE LAUNCH, ... callouts
+        .add_plugins(gpws::GpwsPlugin) // NEW: Predictive terrain clearance, radar altitude, Auto-GCAS
+        .add_plugins(airfield::AirfieldPlugin) // NEW: Runways graded into the terrain, runway/air starts
+        .add_plugins(landing_gear::LandingGearPlugin) // NEW: Takeoff, landing & ground handling
+        .add_plugins(resupply::ResupplyPlugin) // NEW: Fuel burn, air-to-air refueling & rearming
+        .add_event::<RestartRequested>()
         .add_systems(OnEnter(GameState::Spawning), (
             set_window_icon,
             configure_grass_texture_sampler,
+            setup_weapon_assets,
             setup_scene,
-            spawn_realistic_clouds,
             spawn_objectives,
             spawn_turrets,
             spawn_player,
+            spawn_mission_swarm,
             finish_spawning, // Transition to Playing immediately
         ).chain())
         .add_systems(PreUpdate, (
             safety_check_nan, // NEW: Global NaN protection
             check_ground_collision.run_if(in_state(GameState::Playing)), // Before physics so AABB never sees invalid state
         ))
-        .add_systems(Update, (
-            handle_pause_input, // NEW: Pause input runs always
-            update_manual_audio_attenuation, // NEW: Manual audio attenuation runs always
-        ))
+        .add_systems(Update, handle_pause_input) // NEW: Pause input runs always
         // Bevy has a 20-system tuple limit per add_systems; split to avoid overflow when adding more
         .add_systems(Update, (
             read_player_input,
             arcade_flight_physics, // ARCADE PHYSICS: Direct control, no FBW interference
             update_turrets, // NEW: Turret AI
-            update_engine_audio, // NEW: Dynamic engine sound
-            manage_chunks, // NEW: Infinite world chunk system
-            update_altitude_visuals, // NEW: Sky->Space transition
-            propagate_no_frustum_culling, // FIX: Ensure children don't get culled if parent is NoFrustumCulling
+            update_wind_audio, // NEW: Airflow sound
             // debug_flight_diagnostics, // REMOVED: Too noisy
             spawn_afterburner_particles, // Particle spawning based on throttle
-            update_particles, // Update particle positions and fade
-            update_cloud_billboards, // NEW: Make clouds face camera
-            update_sky_sphere, // NEW: Keep sky sphere centered on camera
-            update_sun_position, // NEW: Keep sun disc at fixed sky angle relative to camera
-            update_horizon_disk, // NEW: Keep horizon disk centered on camera (XZ)
-            update_flight_camera,
             debug_flight_data,
             update_maneuver_audio,   // NEW: Wind rip sound
-            update_afterburner_audio, // NEW: Afterburner thump
             // debug_flight_dynamics, // REMOVED: Too noisy
         ).run_if(in_state(GameState::Playing)))
-        .add_systems(PostUpdate, update_lod_levels.run_if(in_state(GameState::Playing))) // MOVED to PostUpdate so trees are spawned before LOD processes them
+        .add_systems(Update, (
+            manage_chunks, // NEW: Infinite world chunk system
+            update_altitude_visuals, // NEW: Sky->Space transition
+        ).run_if(world_active)) // Replays stream the same chunks
         // CRITICAL: Run NaN safety check BEFORE physics (FixedFirst runs before FixedUpdate physics)
         .add_systems(FixedFirst, detect_nan_early.run_if(in_state(GameState::Playing)))
         .add_systems(Update, (
             handle_quit,
-            handle_restart, // R button to restart game
+            (
+                request_restart,
+                handle_restart,
+                spawn_mission_swarm.run_if(on_event::<RestartRequested>), // Fresh swarm
+            ).chain(), // F5 or the pause menu restarts the game
             debug_asset_loading, // Debug model loading
             // debug_tree_hierarchy, // REMOVED: False alarm with Direct Mesh Loading
             handle_shooting_input,
             handle_machine_gun_input,
             update_projectiles,
             update_bullets,
-            update_visual_debris,
             handle_projectile_collisions,
+            handle_terrain_impacts, // Bullets & drone ordnance vs terrain heightfield
             drone_projectile_collision,
             bullet_drone_collision,
             drone_player_collision, // NEW
             update_muzzle_flashes,
-            update_explosion_effects, // Clean up explosion effects
         ).run_if(in_state(GameState::Playing)))
         .run();
 }
@@ -841,29 +769,21 @@ fn setup_scene(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut _images: ResMut<Assets<Image>>, // Kept but unused for now
-    game_assets: Res<GameAssets>,
+    graphics: Res<graphics::GraphicsSettings>,
 ) {
-    // Load HDR Environment Map (now used for Sky Sphere)
-    let skybox_handle = asset_server.load("textures/citrus_orchard_road_puresky_4k.hdr");
-
     commands.spawn((
         DirectionalLight {
             illuminance: 100_000.0, // Physical direct sunlight (matches Exposure::SUNLIGHT EV100=15)
-            shadows_enabled: true,
+            shadows_enabled: graphics.shadows,
             shadow_depth_bias: 0.02,  // Low bias fine — ground is unlit:true, only trees/buildings cast
             shadow_normal_bias: 1.8,  // Default — avoids Peter-Pan on buildings
             ..default()
         },
-        // Tuned shadow config for Flight Sim scale (large distances)
-        CascadeShadowConfigBuilder {
-            num_cascades: 4,
-            minimum_distance: 0.1,
-            maximum_distance: 5000.0, // Increased to 5km to keep ground bright
-            first_cascade_far_bound: 50.0, 
-            overlap_proportion: 0.2,
-        }
-        .build(),
-        Transform::from_rotation(Quat::from_euler(EulerRot::XYZ, -0.5, 0.5, 0.0)),
+        // Tuned shadow config for Flight Sim scale (large distances), sized by the quality preset
+        graphics.cascade_config(),
+        // Rotation and illuminance are driven by the TimeOfDay clock
+        time_of_day::SunLight,
+        Transform::default(),
         GlobalTransform::default(),
     ));
 
@@ -874,65 +794,7 @@ fn setup_scene(
         brightness: 2500.0,
     });
 
-    // === CREATE SHARED GROUND MATERIAL WITH ASSET LOADER TEXTURE ===
-    eprintln!("🌿 STARTUP: Using pre-loaded grass texture from GameAssets...");
-
-    // Use loaded texture instead of generating
-    let grass_texture_handle = game_assets.grass_texture.clone();
-    
-    // Create material with texture immediately
-    let ground_material_handle = materials.add(StandardMaterial {
-        base_color: Color::WHITE, // White so texture shows true colors
-        base_color_texture: Some(grass_texture_handle),
-        normal_map_texture: Some(game_assets.grass_normal.clone()), // Normal map for surface detail
-        perceptual_roughness: 0.9,
-        reflectance: 0.02,
-        metallic: 0.0,
-        unlit: true, // FIX: Unlit ensures grass shows full brightness regardless of shadow cascades
-        ..default()
-    });
-
-    commands.insert_resource(GroundMaterial(ground_material_handle));
-    eprintln!("🌿 STARTUP: Ground material resource created");
-
-    // Spawn Sky Sphere
-    commands.spawn((
-        SkySphere,
-        Mesh3d(meshes.add(Mesh::from(Sphere::new(20000.0)))),
-        MeshMaterial3d(materials.add(StandardMaterial {
-            base_color_texture: Some(skybox_handle.clone()),
-            base_color: Color::srgba(0.5, 0.6, 0.8, 1.0), // Tint to match fog
-            unlit: true, // Sky should glow, not be lit by sun
-            fog_enabled: false, // Don't let fog hide the sky
-            cull_mode: None, // Render both sides (so we see it from inside)
-            ..default()
-        })),
-        Transform::from_scale(Vec3::new(1.0, 1.0, -1.0)), // Invert sphere to show texture inside
-        GlobalTransform::default(),
-    ));
-
-    // --- Visual Sun Disc (emissive sphere, follows camera like sky sphere) ---
-    // Direction must match the DirectionalLight transform above: XYZ(-0.5, 0.5, 0.0)
-    {
-        let sun_rotation = Quat::from_euler(EulerRot::XYZ, -0.5, 0.5, 0.0);
-        let sun_dir = sun_rotation.mul_vec3(Vec3::Z).normalize();
-        let sun_distance = 18000.0_f32; // Inside sky sphere (20km radius)
-        let sun_radius = 1500.0_f32;    // ~9.5° apparent diameter — large dramatic disc
-
-        commands.spawn((
-            SunMarker,
-            Mesh3d(meshes.add(Sphere::new(sun_radius))),
-            MeshMaterial3d(materials.add(StandardMaterial {
-                base_color: Color::srgb(1.0, 0.95, 0.7),
-                emissive: LinearRgba::rgb(5000.0, 4000.0, 1500.0), // Far above bloom threshold
-                unlit: true,
-                fog_enabled: false,
-                ..default()
-            })),
-            Transform::from_translation(sun_dir * sun_distance),
-            GlobalTransform::default(),
-        ));
-    }
+    // Shared ground material is created by BiomePlugin (biome::GroundMaterial)
 
     // GLOBAL GROUND REMOVED - Replaced by Chunk System
 
@@ -985,14 +847,14 @@ fn setup_scene(
 
     // Bevy 0.15: use DistanceFog
     // FIX: Linear fog keeps ground crisp for 10km, gentle fade to 30km (flight sim standard)
-    commands.spawn((
+    let mut camera = commands.spawn((
         Camera3d::default(),
         Camera { hdr: true, ..default() }, // REQUIRED: Bloom needs HDR or all values are clamped to 1.0
         Msaa::Off, // HDR + MSAA causes black screen/artifacts in Bevy 0.15 - must disable
+        // Scene depth for soft cloud sprites (fade where clouds meet terrain)
+        bevy::core_pipeline::prepass::DepthPrepass,
         // ReinhardLuminance does NOT require tonemapping_luts feature (safe fallback)
         bevy::core_pipeline::tonemapping::Tonemapping::ReinhardLuminance,
-        // Bloom::NATURAL preset - proven working in official Bevy 0.15 bloom_3d example
-        bevy::core_pipeline::bloom::Bloom::NATURAL,
         // Physical camera exposure calibrated for outdoor sunlight (matches DirectionalLight 100,000 lux)
         Exposure { ev100: 15.0 }, // Exposure::SUNLIGHT — prevents overexposure of bright scene
         // IBL environment map: provides specular reflections on metallic surfaces (e.g. F-16 fuselage)
@@ -1003,13 +865,13 @@ fn setup_scene(
             intensity: 900.0, // Sky ambient fill — Bevy standard outdoor value (pbr.rs example)
             ..default()
         },
-        SpatialListener::default(), // FIXED: Enable spatial audio by attaching listener to camera
+        SpatialListener::new(audio::EAR_GAP), // Panning only; the mixer does distance rolloff
         Projection::Perspective(PerspectiveProjection {
             far: 100000.0,  // Increased further to cover massive distances
             ..default()
         }),
         DistanceFog {
-            color: Color::srgba(0.5, 0.6, 0.8, 1.0), // Match skybox/ClearColor
+            color: Color::srgba(0.5, 0.6, 0.8, 1.0), // Initial haze; update_altitude_visuals drives it from here
             falloff: FogFalloff::Linear {
                 start: 3000.0, // Fog starts at 3km (inside 8km chunk radius)
                 end: 12000.0,  // Fully opaque at 12km (hides chunk edge)
@@ -1019,6 +881,10 @@ fn setup_scene(
         Transform::from_xyz(0.0, 50.0, 15.0).looking_at(Vec3::ZERO, Vec3::Y),
         GlobalTransform::default(),
     ));
+    if graphics.bloom {
+        // Bloom::NATURAL preset - proven working in official Bevy 0.15 bloom_3d example
+        camera.insert(bevy::core_pipeline::bloom::Bloom::NATURAL);
+    }
 
     // FIX G: TEMPORARY - Bloom test sphere to verify bloom is working
     // TODO: DELETE after verifying bloom works (should see bright green glow halo)
@@ -1036,20 +902,36 @@ fn setup_scene(
     // Drones now spawn via the chunk system (infinite patrols)
 }
 
-/// Helper function to spawn the initial combat challenge
+/// The opening swarm for the mission just picked, sized by difficulty
+/// (also run again on every `RestartRequested`)
+fn spawn_mission_swarm(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    origin: Res<origin::WorldOrigin>,
+    settings: Res<settings::Settings>,
+) {
+    spawn_initial_drone_swarm(&mut commands, &asset_server, &mut meshes, &mut materials, origin.to_local(DVec3::ZERO),
+        settings.difficulty.swarm_size());
+}
+
+/// Helper function to spawn the initial combat challenge around `center` (render space)
 fn spawn_initial_drone_swarm(
     commands: &mut Commands,
     asset_server: &AssetServer,
     meshes: &mut Assets<Mesh>,
     materials: &mut Assets<StandardMaterial>,
+    center: Vec3,
+    count: usize,
 ) {
     let mut rng = rand::thread_rng();
-    println!("🛸 SWARM INITIATED: Spawning 20 drones...");
-    for i in 0..20 {
+    println!("🛸 SWARM INITIATED: Spawning {} drones...", count);
+    for i in 0..count {
         let x = rng.gen_range(-2000.0..2000.0);
         let y = rng.gen_range(400.0..800.0);
         let z = rng.gen_range(-5000.0..-2000.0);
-        crate::drone::spawn_beaver_drone(commands, asset_server, meshes, materials, Vec3::new(x, y, z));
+        crate::drone::spawn_beaver_drone(commands, asset_server, meshes, materials, center + Vec3::new(x, y, z));
         if i % 5 == 0 {
             println!("   > Drone group {} spawned", i / 5 + 1);
         }
@@ -1062,26 +944,25 @@ fn manage_chunks(
     player_query: Query<&Transform, With<PlayerPlane>>,
     mut chunk_manager: ResMut<ChunkManager>,
     chunk_entities: Query<(Entity, &ChunkCoordinate), With<ChunkEntity>>,
-    asset_server: Res<AssetServer>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    ground_material: Res<GroundMaterial>,  // Shared ground material
+    mut assets: ChunkAssets,
 ) {
+    let graphics = &assets.graphics;
     let Ok(player_transform) = player_query.get_single() else {
         eprintln!("❌ manage_chunks: NO PLAYER FOUND");
         return;
     };
-    let player_chunk = ChunkCoordinate::from_world_pos(player_transform.translation);
+    let player_world = assets.origin.to_absolute(player_transform.translation);
+    let player_chunk = ChunkCoordinate::from_world_pos(player_world);
 
-    // Only update if player moved to new chunk or if this is the first run
-    if player_chunk == chunk_manager.last_player_chunk && !chunk_manager.loaded_chunks.is_empty() {
+    // Only update if player moved to new chunk, if this is the first run or the view distance changed
+    if player_chunk == chunk_manager.last_player_chunk && !chunk_manager.loaded_chunks.is_empty() && !graphics.is_changed() {
         return;
     }
     chunk_manager.last_player_chunk = player_chunk;
 
     // DEBUG: Show chunk loading progress
     println!("📦 CHUNKS: Player at world({:.0},{:.0},{:.0}) = chunk({},{}), Loaded: {} chunks",
-        player_transform.translation.x, player_transform.translation.y, player_transform.translation.z,
+        player_world.x, player_world.y, player_world.z,
         player_chunk.x, player_chunk.z, chunk_manager.loaded_chunks.len());
 
     // 1. Unload distant chunks
@@ -1089,7 +970,7 @@ fn manage_chunks(
     for (entity, chunk_coord) in &chunk_entities {
         let dx = player_chunk.x - chunk_coord.x;
         let dz = player_chunk.z - chunk_coord.z;
-        if dx * dx + dz * dz > UNLOAD_RADIUS_CHUNKS * UNLOAD_RADIUS_CHUNKS {
+        if dx * dx + dz * dz > graphics.unload_radius_chunks * graphics.unload_radius_chunks {
             to_unload.push(*chunk_coord);
             commands.entity(entity).despawn_recursive();
         }
@@ -1100,8 +981,9 @@ fn manage_chunks(
     }
 
     // 2. Load nearby chunks
-    for x_offset in -LOAD_RADIUS_CHUNKS..=LOAD_RADIUS_CHUNKS {
-        for z_offset in -LOAD_RADIUS_CHUNKS..=LOAD_RADIUS_CHUNKS {
+    let load_radius = graphics.load_radius_chunks;
+    for x_offset in -load_radius..=load_radius {
+        for z_offset in -load_radius..=load_radius {
             let chunk_coord = ChunkCoordinate {
                 x: player_chunk.x + x_offset,
                 z: player_chunk.z + z_offset,
@@ -1113,27 +995,23 @@ fn manage_chunks(
             
             let dx = x_offset;
             let dz = z_offset;
-            if dx * dx + dz * dz > LOAD_RADIUS_CHUNKS * LOAD_RADIUS_CHUNKS {
+            if dx * dx + dz * dz > load_radius * load_radius {
                 continue;
             }
 
-            let chunk_entity = spawn_chunk(
-                &mut commands,
-                &asset_server,
-                &mut meshes,
-                &mut materials,
-                chunk_coord,
-                ground_material.0.clone(),  // Pass shared material
-            );
+            let chunk_entity = spawn_chunk(&mut commands, &mut assets, chunk_coord);
             chunk_manager.loaded_chunks.insert(chunk_coord, chunk_entity);
         }
     }
 }
 
 fn update_altitude_visuals(
+    time_of_day: Res<time_of_day::TimeOfDay>,
+    weather: Res<weather::Weather>,
+    cloud_cover: Res<clouds::CloudCover>,
     player_query: Query<&Transform, With<PlayerPlane>>,
     mut fog_query: Query<&mut DistanceFog, With<Camera3d>>,
-    mut clear_color: ResMut<ClearColor>,
+    graphics: Res<graphics::GraphicsSettings>,
 ) {
     let Ok(player_transform) = player_query.get_single() else { return };
     let altitude = player_transform.translation.y;
@@ -1149,8 +1027,11 @@ fn update_altitude_visuals(
     // Smooth ease-in-out curve
     let t = transition_factor * transition_factor * (3.0 - 2.0 * transition_factor);
 
-    // Base sky colors: Light blue/gray at low altitude, black in space
-    let earth_sky = Color::srgb(0.5, 0.6, 0.8);
+    // Base sky colors: time-of-day horizon color (greyed by rain) at low altitude, black in space
+    let earth_sky = Color::from(
+        LinearRgba::from(time_of_day.sky_color())
+            .mix(&LinearRgba::from(Color::srgb(0.4, 0.42, 0.45)), weather.precipitation * 0.6),
+    );
     let space_black = Color::srgb(0.0, 0.0, 0.0);
 
     // Interpolate colors based on altitude factor 't'
@@ -1160,94 +1041,103 @@ fn update_altitude_visuals(
         earth_sky.to_linear().blue * (1.0 - t),
     );
 
-    // Update background color
-    clear_color.0 = new_color;
-
     // Update fog configuration
     if let Ok(mut fog) = fog_query.get_single_mut() {
-        fog.color = new_color;
+        // Inside a cloud the world closes in to a grey-out
+        // Never see past the loaded chunks, however clear the day
+        let clear_air = weather.visibility.min(graphics.view_distance());
+        let (visibility, fog_color) = clouds::in_cloud_fog(&cloud_cover, &time_of_day, clear_air, new_color);
+        fog.color = fog_color;
 
         // Keep Linear fog for consistent ground appearance
-        // Only the color darkens with altitude, not the fog density
+        // Weather visibility sets the range; altitude only darkens the color
         fog.falloff = FogFalloff::Linear {
-            start: 3000.0,
-            end: 12000.0,
+            start: visibility * 0.25,
+            end: visibility,
         };
     }
 }
 
-fn update_lod_levels(
-    player_query: Query<&Transform, With<PlayerPlane>>,
-    mut tree_query: Query<(&GlobalTransform, &mut Visibility), With<Tree>>,
-) {
-    let Ok(player_transform) = player_query.get_single() else { return };
-    let player_pos = player_transform.translation;
-
-    // Very simple LOD: Hide trees beyond 20km to save on draw calls
-    const HIDE_DISTANCE_SQ: f32 = 20000.0 * 20000.0;
-
-    let mut visible_count = 0;
-    let mut hidden_count = 0;
-
-    for (tree_global_transform, mut visibility) in &mut tree_query {
-        let dist_sq = player_pos.distance_squared(tree_global_transform.translation());
-        if dist_sq > HIDE_DISTANCE_SQ {
-            if *visibility != Visibility::Hidden {
-                *visibility = Visibility::Hidden;
-            }
-            hidden_count += 1;
-        } else {
-            if *visibility != Visibility::Inherited {
-                *visibility = Visibility::Inherited;
-            }
-            visible_count += 1;
-        }
-    }
-
-    if visible_count > 0 || hidden_count > 0 {
-        // Reduced logging frequency or removed for performance
-        // eprintln!("📊 LOD: {} visible trees, {} hidden trees", visible_count, hidden_count);
+/// Sample terrain heights on the chunk's vertex grid, indexed `[x][z]`.
+/// The render mesh and the heightfield collider are both built from this grid,
+/// so what you see is exactly what you hit.
+fn sample_chunk_heights(chunk_coord: ChunkCoordinate) -> Vec<Vec<f32>> {
+    let chunk_world = chunk_coord.world_position();
+
+    (0..=TERRAIN_SUBDIVISIONS)
+        .map(|x| {
+            let local_x = (x as f32 / TERRAIN_SUBDIVISIONS as f32) * CHUNK_SIZE - CHUNK_SIZE / 2.0;
+            (0..=TERRAIN_SUBDIVISIONS)
+                .map(|z| {
+                    let local_z = (z as f32 / TERRAIN_SUBDIVISIONS as f32) * CHUNK_SIZE - CHUNK_SIZE / 2.0;
+                    get_terrain_height(chunk_world.x + local_x as f64, chunk_world.z + local_z as f64)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Height of the terrain surface as rendered and collided: the chunk grid's
+/// triangles, not the full-detail noise in between their vertices
+fn terrain_surface_height(world_x: f64, world_z: f64) -> f32 {
+    let cell = (CHUNK_SIZE / TERRAIN_SUBDIVISIONS as f32) as f64;
+    let (gx, gz) = ((world_x / cell).floor(), (world_z / cell).floor());
+    let (fx, fz) = ((world_x / cell - gx) as f32, (world_z / cell - gz) as f32);
+    let corner = |dx: f64, dz: f64| get_terrain_height((gx + dx) * cell, (gz + dz) * cell);
+
+    // Same diagonal as create_terrain_mesh: from (x+1, z) to (x, z+1)
+    if fx + fz <= 1.0 {
+        let h00 = corner(0.0, 0.0);
+        h00 + (corner(1.0, 0.0) - h00) * fx + (corner(0.0, 1.0) - h00) * fz
+    } else {
+        let h11 = corner(1.0, 1.0);
+        h11 + (corner(0.0, 1.0) - h11) * (1.0 - fx) + (corner(1.0, 0.0) - h11) * (1.0 - fz)
     }
 }
 
 /// Create terrain mesh with heightmap applied
 fn create_terrain_mesh(
     chunk_coord: ChunkCoordinate,
+    heights: &[Vec<f32>],
     meshes: &mut Assets<Mesh>,
 ) -> Handle<Mesh> {
-    const SUBDIVISIONS: usize = 20; // 20x20 grid = 400 triangles per chunk
+    const SUBDIVISIONS: usize = TERRAIN_SUBDIVISIONS;
     
-    let chunk_world_x = chunk_coord.x as f32 * CHUNK_SIZE;
-    let chunk_world_z = chunk_coord.z as f32 * CHUNK_SIZE;
+    let chunk_world = chunk_coord.world_position();
 
     let mut positions = Vec::new();
     let mut normals = Vec::new();
     let mut uvs = Vec::new();
+    let mut splat_weights = Vec::new();
     let mut indices = Vec::new();
 
     // 1. Generate vertices with heightmap
     for z in 0..=SUBDIVISIONS {
-        for x in 0..=SUBDIVISIONS {
+        for (x, column) in heights.iter().enumerate() {
             let local_x = (x as f32 / SUBDIVISIONS as f32) * CHUNK_SIZE - CHUNK_SIZE / 2.0;
             let local_z = (z as f32 / SUBDIVISIONS as f32) * CHUNK_SIZE - CHUNK_SIZE / 2.0;
 
-            let world_x = chunk_world_x + local_x;
-            let world_z = chunk_world_z + local_z;
+            let world_x = chunk_world.x + local_x as f64;
+            let world_z = chunk_world.z + local_z as f64;
 
-            let height = get_terrain_height(world_x, world_z);
+            let height = column[z];
             positions.push([local_x, height, local_z]);
             
             // 2. CALCULATE SURFACE NORMAL
             // Sample neighbor heights to determine the slope (essential for shading mountains)
-            const EPS: f32 = 1.0; // 1 meter sampling distance
+            const EPS: f64 = 1.0; // 1 meter sampling distance
             let h_r = get_terrain_height(world_x + EPS, world_z);
             let h_l = get_terrain_height(world_x - EPS, world_z);
             let h_d = get_terrain_height(world_x, world_z + EPS);
             let h_u = get_terrain_height(world_x, world_z - EPS);
             
             // Normal = cross product of the two tangent vectors (u and v)
-            let normal = Vec3::new(h_l - h_r, 2.0 * EPS, h_u - h_d).normalize();
+            let normal = Vec3::new(h_l - h_r, 2.0 * EPS as f32, h_u - h_d).normalize();
             normals.push([normal.x, normal.y, normal.z]);
+
+            // 3. BIOME SPLAT WEIGHTS (grass, rock, snow, sand) -> vertex color channel
+            let biome = biome::biome_at_height(world_x, world_z, height);
+            splat_weights.push(biome.splat_weights(1.0 - normal.y));
             
             // UV tiling: Multiply by 10.0 for seamless grass
             let u = (x as f32 / SUBDIVISIONS as f32) * 10.0;
@@ -1281,6 +1171,7 @@ fn create_terrain_mesh(
     mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
     mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
     mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, splat_weights);
     mesh.insert_indices(bevy::render::mesh::Indices::U32(indices));
 
     meshes.add(mesh)
@@ -1288,13 +1179,12 @@ fn create_terrain_mesh(
 
 fn spawn_chunk(
     commands: &mut Commands,
-    asset_server: &AssetServer,
-    meshes: &mut Assets<Mesh>,
-    materials: &mut Assets<StandardMaterial>,
+    assets: &mut ChunkAssets,
     chunk_coord: ChunkCoordinate,
-    ground_material: Handle<StandardMaterial>,  // Use shared material from startup
 ) -> Entity {
-    let chunk_pos = chunk_coord.world_position();
+    // Generation samples absolute coordinates; the chunk itself is placed in render space
+    let chunk_world = chunk_coord.world_position();
+    let chunk_pos = assets.origin.to_local(chunk_world);
 
     // SAFETY: Ensure chunk position is valid
     if chunk_pos.is_nan() || !chunk_pos.is_finite() {
@@ -1304,7 +1194,7 @@ fn spawn_chunk(
     }
 
     println!("🌍 CHUNK SPAWN: coord=({},{}), world_pos=({:.0},{:.0},{:.0})",
-        chunk_coord.x, chunk_coord.z, chunk_pos.x, chunk_pos.y, chunk_pos.z);
+        chunk_coord.x, chunk_coord.z, chunk_world.x, chunk_world.y, chunk_world.z);
 
     let chunk_entity = commands.spawn((
         ChunkEntity,
@@ -1315,19 +1205,22 @@ fn spawn_chunk(
         InheritedVisibility::default(),
     )).id();
 
-    // Ground material is now passed in (shared across all chunks)
+    // Ground material is shared across all chunks
+    let ground_material = assets.ground_material.0.clone();
 
-    commands.entity(chunk_entity).with_children(|parent| {
-        let half_size = CHUNK_SIZE / 2.0;
-        let thickness = 0.5;
+    // One height grid feeds the render mesh, the physics heightfield and the water check
+    let heights = sample_chunk_heights(chunk_coord);
+    let min_height = heights.iter().flatten().copied().fold(f32::INFINITY, f32::min);
 
-        // Final sanity check for collider dimensions
-        if half_size > 0.0 && half_size.is_finite() && thickness > 0.0 {
+    commands.entity(chunk_entity).with_children(|parent| {
+        // Final sanity check: a single bad sample would poison the collider's AABB
+        if heights.iter().flatten().all(|h| h.is_finite()) {
             // Create ground mesh with UV tiling for texture detail
             // REPLACED: Use heightmap mesh instead of flat plane
-            let mesh_handle = create_terrain_mesh(chunk_coord, meshes);
+            let mesh_handle = create_terrain_mesh(chunk_coord, &heights, &mut assets.meshes);
 
             parent.spawn((
+                Terrain,
                 ChunkEntity,
                 chunk_coord,
                 Mesh3d(mesh_handle),
@@ -1337,54 +1230,41 @@ fn spawn_chunk(
                 Visibility::default(),
                 InheritedVisibility::default(),
                 RigidBody::Static,
-                Collider::cuboid(half_size, thickness, half_size),
+                // Heightfield spans the whole chunk centered on the parent; heights are already in meters
+                Collider::heightfield(heights, Vec3::new(CHUNK_SIZE, 1.0, CHUNK_SIZE)),
             ));
         } else {
-            eprintln!("❌ CHUNK SPAWN ERROR: Invalid collider dimensions for chunk {:?}", chunk_coord);
+            eprintln!("❌ CHUNK SPAWN ERROR: Non-finite terrain heights for chunk {:?}", chunk_coord);
         }
     });
 
-    spawn_trees_in_chunk(commands, asset_server, meshes, materials, chunk_coord, chunk_pos, chunk_entity);
-    spawn_rocks_in_chunk(commands, meshes, materials, chunk_coord, chunk_pos, chunk_entity); // Added rocks
-    spawn_meteors_in_chunk(commands, asset_server, chunk_coord, chunk_entity); // Added infinite sky litter
+    water::spawn_water_in_chunk(commands, &assets.water, chunk_entity, min_height); // Lakes/rivers below sea level
+    airfield::spawn_airfield_in_chunk(commands, &assets.airfields, chunk_world, CHUNK_SIZE, chunk_entity); // Runway, if one is centered here
+    spawn_trees_in_chunk(commands, &assets.graphics, chunk_coord, chunk_pos, chunk_entity);
+    spawn_rocks_in_chunk(commands, &mut assets.meshes, &mut assets.materials, chunk_coord, chunk_pos, chunk_entity); // Added rocks
+    spawn_meteors_in_chunk(commands, &assets.asset_server, &assets.lod, assets.graphics.meteors_per_chunk, chunk_coord, chunk_entity); // Added infinite sky litter
+    clouds::spawn_clouds_in_chunk(commands, &assets.clouds, &assets.weather, &assets.origin, chunk_coord); // Cloud layer follows the player
     
     // NEW: Occasionally spawn a drone "Patrol" in new chunks
     // 15% chance per chunk to spawn a drone
     let hash = ((chunk_coord.x.wrapping_mul(1234567)) ^ (chunk_coord.z.wrapping_mul(7654321))) as u32;
     if (hash % 100) < 15 {
         let spawn_pos = chunk_pos + Vec3::new(0.0, 500.0, 0.0);
-        crate::drone::spawn_beaver_drone(commands, asset_server, meshes, materials, spawn_pos);
+        crate::drone::spawn_beaver_drone(commands, &assets.asset_server, &mut assets.meshes, &mut assets.materials, spawn_pos);
         println!("🛸 CHUNK PATROL: Drone spawned in chunk {:?}", chunk_coord);
     }
 
     if should_spawn_village(chunk_coord) {
-        spawn_village_in_chunk(commands, asset_server, meshes, materials, chunk_coord, chunk_pos, chunk_entity);
+        spawn_village_in_chunk(commands, assets, chunk_coord, chunk_pos);
     }
 
     println!("🌍 Chunk ({},{}) spawned with trees & village check", chunk_coord.x, chunk_coord.z);
     chunk_entity
 }
 
-fn propagate_no_frustum_culling(
-    mut commands: Commands,
-    trees: Query<Entity, (With<Tree>, With<bevy::render::view::NoFrustumCulling>)>,
-    children_query: Query<&Children>,
-    no_culling_query: Query<Entity, With<bevy::render::view::NoFrustumCulling>>,
-) {
-    for tree_entity in &trees {
-        for child in children_query.iter_descendants(tree_entity) {
-            if !no_culling_query.contains(child) {
-                commands.entity(child).insert(bevy::render::view::NoFrustumCulling);
-            }
-        }
-    }
-}
-
 fn spawn_trees_in_chunk(
     commands: &mut Commands,
-    asset_server: &AssetServer,
-    _meshes: &mut Assets<Mesh>, // Unused _ removed
-    materials: &mut Assets<StandardMaterial>, // Unused _ removed
+    graphics: &graphics::GraphicsSettings,
     chunk_coord: ChunkCoordinate,
     _chunk_pos: Vec3,
     chunk_entity: Entity,
@@ -1394,66 +1274,60 @@ fn spawn_trees_in_chunk(
     let seed = ((chunk_coord.x as i64 * 73856093) ^ (chunk_coord.z as i64 * 19349663)) as u64;
     let mut chunk_rng = rand::rngs::StdRng::seed_from_u64(seed);
 
-    let tree_count = chunk_rng.gen_range(TREES_PER_CHUNK_MIN..=TREES_PER_CHUNK_MAX);
-    println!("🌲 Spawning {} trees in chunk ({},{})", tree_count, chunk_coord.x, chunk_coord.z);
+    // Biome at the chunk center sets the overall density; each tree re-checks its own spot
+    let chunk_world = chunk_coord.world_position();
+    let chunk_biome = biome::biome_at(chunk_world.x, chunk_world.z);
+    let (min_trees, most_trees) = (graphics.trees_per_chunk_min, graphics.trees_per_chunk_max);
+    let max_trees = min_trees + ((most_trees - min_trees) as f32 * chunk_biome.vegetation_density()) as usize;
+    let tree_count = chunk_rng.gen_range(min_trees..=max_trees);
+    println!("🌲 Spawning up to {} trees in chunk ({},{}) [{:?}]", tree_count, chunk_coord.x, chunk_coord.z, chunk_biome.biome);
 
-    // Add #Mesh0/Primitive0 to target the mesh data directly inside the GLB
-    let tree_models = vec![
-        "fantasy_town/tree.glb",
-        "fantasy_town/tree-crooked.glb",
-        "fantasy_town/tree-high.glb",
-        "fantasy_town/tree-high-crooked.glb",
-        "fantasy_town/tree-high-round.glb",
-    ];
+    let has_village = should_spawn_village(chunk_coord);
+    let mut instances = Vec::with_capacity(tree_count);
 
-    // Create a shared green material for all trees
-    let tree_material = materials.add(StandardMaterial {
-        base_color: Color::srgb(0.1, 0.5, 0.1), // Grass green
-        perceptual_roughness: 0.9,
-        reflectance: 0.1,
-        ..default()
-    });
+    for _ in 0..tree_count {
+        let x = chunk_rng.gen_range(-CHUNK_SIZE/2.0..CHUNK_SIZE/2.0);
+        let z = chunk_rng.gen_range(-CHUNK_SIZE/2.0..CHUNK_SIZE/2.0);
 
-    commands.entity(chunk_entity).with_children(|parent| {
-        for _ in 0..tree_count {
-            let x = chunk_rng.gen_range(-CHUNK_SIZE/2.0..CHUNK_SIZE/2.0);
-            let z = chunk_rng.gen_range(-CHUNK_SIZE/2.0..CHUNK_SIZE/2.0);
+        if has_village && (x*x + z*z < 400.0*400.0) {
+             continue;
+        }
 
-            if should_spawn_village(chunk_coord) && (x*x + z*z < 400.0*400.0) {
-                 continue;
-            }
+        // Use LOCAL coordinates because trees are now children of the chunk
+        // Get terrain height for Y position
+        let world_x = chunk_world.x + x as f64;
+        let world_z = chunk_world.z + z as f64;
+        if airfield::airfield_at(world_x, world_z).is_some() {
+            continue; // Keep runways and aprons clear
+        }
+        let terrain_height = get_terrain_height(world_x, world_z);
 
-            let model_index = chunk_rng.gen_range(0..tree_models.len());
-            let tree_model_path = format!("{}#Mesh0/Primitive0", tree_models[model_index]);
-            let scale = chunk_rng.gen_range(3.0..6.0);
+        // Thin out trees where the local climate can't support them (snow, rock, desert)
+        let tree_biome = biome::biome_at_height(world_x, world_z, terrain_height);
+        let roll: f32 = chunk_rng.gen();
+        if roll > tree_biome.vegetation_density() || terrain_height < water::SEA_LEVEL {
+            continue;
+        }
 
-            // Use LOCAL coordinates because trees are now children of the chunk
-            // Get terrain height for Y position
-            let world_x = chunk_coord.x as f32 * CHUNK_SIZE + x;
-            let world_z = chunk_coord.z as f32 * CHUNK_SIZE + z;
-            let terrain_height = get_terrain_height(world_x, world_z);
-            
-            // Offset by +5.0 because chunk parent is at Y=-5.0
-            let tree_local_pos = Vec3::new(x, terrain_height + 5.0, z);
+        let tree_models = tree_biome.tree_models();
+        let model_index = chunk_rng.gen_range(0..tree_models.len());
+        let scale = chunk_rng.gen_range(3.0..6.0);
 
-            parent.spawn((
-                Tree,
-                ChunkEntity,
-                chunk_coord,
-                LODLevel(0),
-                // DIRECT MESH LOADING (Option 1)
-                Mesh3d(asset_server.load(tree_model_path)),
-                MeshMaterial3d(tree_material.clone()), // Apply green material
-                Transform {
-                    translation: tree_local_pos,
-                    rotation: Quat::from_rotation_y(chunk_rng.gen_range(0.0..std::f32::consts::TAU)),
-                    scale: Vec3::splat(scale),
-                },
-                Visibility::default(),
-                bevy::render::view::NoFrustumCulling,
-            ));
-        }
-    });
+        // Chunk roots sit at Y=0, so the terrain height is already the local Y
+        let tree_local_pos = Vec3::new(x, terrain_height, z);
+
+        instances.push(foliage::TreeInstance {
+            model: tree_models[model_index],
+            transform: Transform {
+                translation: tree_local_pos,
+                rotation: Quat::from_rotation_y(chunk_rng.gen_range(0.0..std::f32::consts::TAU)),
+                scale: Vec3::splat(scale),
+            },
+        });
+    }
+
+    // One merged mesh per model, frustum-culled as a whole
+    foliage::spawn_foliage_batches(commands, chunk_entity, chunk_coord, instances);
 }
 
 fn spawn_rocks_in_chunk(
@@ -1469,7 +1343,10 @@ fn spawn_rocks_in_chunk(
     let seed = ((chunk_coord.x as i64 * 19349663) ^ (chunk_coord.z as i64 * 73856093)) as u64;
     let mut chunk_rng = rand::rngs::StdRng::seed_from_u64(seed);
 
-    let rock_count = chunk_rng.gen_range(2..=4);
+    // Rocky biomes (canyons, mountains) get more boulders than lush flatlands
+    let chunk_world = chunk_coord.world_position();
+    let chunk_biome = biome::biome_at(chunk_world.x, chunk_world.z);
+    let rock_count = (chunk_rng.gen_range(2..=4) as f32 * chunk_biome.rock_density()).round() as usize;
     
     let rock_material = materials.add(StandardMaterial {
         base_color: Color::srgb(0.6, 0.5, 0.4), // Gray-brown rock
@@ -1479,13 +1356,15 @@ fn spawn_rocks_in_chunk(
 
     let rock_mesh = meshes.add(Cuboid::new(20.0, 15.0, 20.0)); // 20m rocks
 
+    let has_village = should_spawn_village(chunk_coord);
+
     commands.entity(chunk_entity).with_children(|parent| {
         for _ in 0..rock_count {
             let x = chunk_rng.gen_range(-CHUNK_SIZE/2.0..CHUNK_SIZE/2.0);
             let z = chunk_rng.gen_range(-CHUNK_SIZE/2.0..CHUNK_SIZE/2.0);
 
             // Avoid village center if necessary, but rocks are tough so maybe it's fine
-            if should_spawn_village(chunk_coord) && (x*x + z*z < 400.0*400.0) {
+            if has_village && (x*x + z*z < 400.0*400.0) {
                  continue;
             }
 
@@ -1493,17 +1372,19 @@ fn spawn_rocks_in_chunk(
             let rotation = Quat::from_rotation_y(chunk_rng.gen_range(0.0..std::f32::consts::TAU));
 
             // Get terrain height
-            let world_x = chunk_coord.x as f32 * CHUNK_SIZE + x;
-            let world_z = chunk_coord.z as f32 * CHUNK_SIZE + z;
+            let world_x = chunk_world.x + x as f64;
+            let world_z = chunk_world.z + z as f64;
+            if airfield::airfield_at(world_x, world_z).is_some() {
+                continue;
+            }
             let terrain_height = get_terrain_height(world_x, world_z);
 
             parent.spawn((
                 ChunkEntity,
                 chunk_coord,
-                Mesh3d(rock_mesh.clone()),
-                MeshMaterial3d(rock_material.clone()),
+                LodGroup::new(LodCategory::Rock),
                 Transform {
-                    translation: Vec3::new(x, terrain_height + 5.0 + (7.5 * scale), z), // +5 for chunk offset, +half_height for pivot
+                    translation: Vec3::new(x, terrain_height + (7.5 * scale), z), // +half_height for pivot
                     rotation,
                     scale: Vec3::splat(scale),
                 },
@@ -1512,7 +1393,16 @@ fn spawn_rocks_in_chunk(
                 InheritedVisibility::default(),
                 RigidBody::Static,
                 Collider::cuboid(10.0, 7.5, 10.0), // Half-extents
-            ));
+            ))
+            .with_children(|rock| {
+                // Collider stays on the root; only the visual is dropped past the cull distance
+                rock.spawn((
+                    LodVariant::levels(0, 3),
+                    Mesh3d(rock_mesh.clone()),
+                    MeshMaterial3d(rock_material.clone()),
+                    Transform::IDENTITY,
+                ));
+            });
         }
     });
 }
@@ -1667,20 +1557,28 @@ fn safety_check_nan(
 
 fn should_spawn_village(chunk_coord: ChunkCoordinate) -> bool {
     let hash = ((chunk_coord.x.wrapping_mul(73856093)) ^ (chunk_coord.z.wrapping_mul(19349663))) as u32;
-    (hash % 100) < 15  // 15% spawn rate (increased from 5% for better visibility)
+    if (hash % 100) >= 15 {  // 15% spawn rate (increased from 5% for better visibility)
+        return false;
+    }
+    // Only settle on habitable land (no villages on peaks, canyon floors, deserts or airfields)
+    let center = chunk_coord.world_position();
+    biome::biome_at(center.x, center.z).is_habitable() && airfield::airfield_near(center.x, center.z).is_none()
 }
 
 fn spawn_village_in_chunk(
     commands: &mut Commands,
-    asset_server: &AssetServer,
-    meshes: &mut Assets<Mesh>,
-    materials: &mut Assets<StandardMaterial>,
+    assets: &mut ChunkAssets,
     chunk_coord: ChunkCoordinate,
     chunk_pos: Vec3,
-    _chunk_entity: Entity,
 ) {
+    let ChunkAssets { asset_server, meshes, materials, lod: lod_assets, .. } = assets;
     println!("🏘️  Spawning village in chunk ({},{})", chunk_coord.x, chunk_coord.z);
+    // Placed around the chunk's render-space center; heights sampled in absolute coordinates
     let village_center = chunk_pos;
+    let village_world = chunk_coord.world_position();
+    let height_at = |x: f32, z: f32| {
+        get_terrain_height(village_world.x + (x - village_center.x) as f64, village_world.z + (z - village_center.z) as f64)
+    };
     const NUM_BUILDINGS: usize = 8;
     const BUILDING_DISTANCE: f32 = 150.0;
 
@@ -1706,21 +1604,25 @@ fn spawn_village_in_chunk(
         ..default()
     });
 
+    let wall_mesh: Handle<Mesh> = asset_server.load("fantasy_town/wall.glb#Mesh0/Primitive0");
+    let roof_scene: Handle<Scene> = asset_server.load("fantasy_town/roof-gable.glb#Scene0");
+    let roof_fallback_mesh = meshes.add(Cuboid::new(2.0, 1.0, 2.0));
+    let lantern_scene: Handle<Scene> = asset_server.load("fantasy_town/lantern.glb#Scene0");
+
     for i in 0..NUM_BUILDINGS {
         let angle = (i as f32 / NUM_BUILDINGS as f32) * std::f32::consts::TAU;
         let building_x = village_center.x + angle.cos() * BUILDING_DISTANCE;
         let building_z = village_center.z + angle.sin() * BUILDING_DISTANCE;
         let rotation = Quat::from_rotation_y(angle + std::f32::consts::PI);
 
-        let terrain_height = get_terrain_height(building_x, building_z);
+        let terrain_height = height_at(building_x, building_z);
 
-        // Building Base (Wall)
+        // Building Base (Wall): full model up close, plain box from LOD 2
         commands.spawn((
             VillageBuilding,
             ChunkEntity,
             chunk_coord,
-            Mesh3d(asset_server.load("fantasy_town/wall.glb#Mesh0/Primitive0")),
-            MeshMaterial3d(wall_material.clone()),
+            LodGroup::new(LodCategory::Building),
             Transform {
                 translation: Vec3::new(building_x, terrain_height - 0.5, building_z),
                 rotation,
@@ -1731,15 +1633,29 @@ fn spawn_village_in_chunk(
             InheritedVisibility::default(),
             RigidBody::Static,
             Collider::cuboid(3.0, 5.0, 3.0),
-        ));
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                LodVariant::levels(0, 1),
+                Mesh3d(wall_mesh.clone()),
+                MeshMaterial3d(wall_material.clone()),
+                Transform::IDENTITY,
+            ));
+            parent.spawn((
+                LodVariant::levels(2, 3),
+                Mesh3d(lod_assets.building_proxy_mesh.clone()),
+                MeshMaterial3d(lod_assets.building_proxy_material.clone()),
+                Transform::IDENTITY,
+            ));
+        });
 
-        // Building Roof (with fallback cube for visibility)
+        // Building Roof (with fallback cube for visibility); too small to matter past LOD 1
         println!("  🏠 Spawning roof at ({}, {}, {})", building_x, terrain_height + 32.0, building_z);
         commands.spawn((
             VillageBuilding,
             ChunkEntity,
             chunk_coord,
-            SceneRoot(asset_server.load("fantasy_town/roof-gable.glb#Scene0")),
+            LodGroup::new(LodCategory::Building),
             Transform {
                 translation: Vec3::new(building_x, terrain_height + 32.0, building_z),
                 rotation,
@@ -1750,23 +1666,67 @@ fn spawn_village_in_chunk(
             InheritedVisibility::default(),
         ))
         .with_children(|parent| {
+            parent.spawn((
+                LodVariant::levels(0, 1),
+                SceneRoot(roof_scene.clone()),
+                Transform::IDENTITY,
+            ));
             // Fallback visual cube (bright red for debugging)
             parent.spawn((
-                Mesh3d(meshes.add(Cuboid::new(2.0, 1.0, 2.0))),
+                LodVariant::levels(0, 1),
+                Mesh3d(roof_fallback_mesh.clone()),
                 MeshMaterial3d(roof_material.clone()),
                 Transform::from_xyz(0.0, 0.0, 0.0),
             ));
         });
+
+        // Street lantern in front of each building; lit at night by the TimeOfDay systems
+        let lantern_x = village_center.x + angle.cos() * (BUILDING_DISTANCE - 30.0);
+        let lantern_z = village_center.z + angle.sin() * (BUILDING_DISTANCE - 30.0);
+        let lantern_height = height_at(lantern_x, lantern_z);
+        commands.spawn((
+            VillageBuilding,
+            ChunkEntity,
+            chunk_coord,
+            LodGroup::new(LodCategory::Building),
+            Transform {
+                translation: Vec3::new(lantern_x, lantern_height, lantern_z),
+                rotation,
+                scale: Vec3::splat(6.0),
+            },
+            GlobalTransform::default(),
+            Visibility::default(),
+            InheritedVisibility::default(),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                LodVariant::levels(0, 1),
+                SceneRoot(lantern_scene.clone()),
+                Transform::IDENTITY,
+            ));
+            // Light stays on longer than the model so villages glow from a distance
+            parent.spawn((
+                LodVariant::levels(0, 2),
+                time_of_day::Lantern,
+                PointLight {
+                    color: Color::srgb(1.0, 0.7, 0.4),
+                    intensity: 0.0,
+                    range: 40.0,
+                    shadows_enabled: false,
+                    ..default()
+                },
+                Transform::from_xyz(0.0, 1.2, 0.0),
+            ));
+        });
     }
 
     // Central Tower
-    let tower_height = get_terrain_height(village_center.x, village_center.z);
+    let tower_height = height_at(village_center.x, village_center.z);
     commands.spawn((
         VillageBuilding,
         ChunkEntity,
         chunk_coord,
-        Mesh3d(asset_server.load("fantasy_town/wall.glb#Mesh0/Primitive0")),
-        MeshMaterial3d(wall_material.clone()),
+        LodGroup::new(LodCategory::Building),
         Transform {
             translation: Vec3::new(village_center.x, tower_height - 0.5, village_center.z),
             rotation: Quat::IDENTITY,
@@ -1777,131 +1737,29 @@ fn spawn_village_in_chunk(
         InheritedVisibility::default(),
         RigidBody::Static,
         Collider::cuboid(10.0, 20.0, 10.0),
-    ));
-}
-
-/// Spawn realistic cloud layer using FX cloud alpha textures
-fn spawn_realistic_clouds(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
-    let mut rng = rand::thread_rng();
-
-    // Load all 10 cloud textures
-    let cloud_textures = vec![
-        "textures/clouds/FX_CloudAlpha01.png",
-        "textures/clouds/FX_CloudAlpha02.png",
-        "textures/clouds/FX_CloudAlpha03.png",
-        "textures/clouds/FX_CloudAlpha04.png",
-        "textures/clouds/FX_CloudAlpha05.png",
-        "textures/clouds/FX_CloudAlpha06.png",
-        "textures/clouds/FX_CloudAlpha07.png",
-        "textures/clouds/FX_CloudAlpha08.png",
-        "textures/clouds/FX_CloudAlpha09.png",
-        "textures/clouds/FX_CloudAlpha10.png",
-    ];
-
-    // Cloud layer configuration
-    const CLOUD_COUNT: usize = 50;
-    const CLOUD_ALTITUDE_MIN: f32 = 800.0;
-    const CLOUD_ALTITUDE_MAX: f32 = 1500.0;
-    const CLOUD_SPREAD: f32 = 40000.0;
-
-    for _ in 0..CLOUD_COUNT {
-        let x = rng.gen_range(-CLOUD_SPREAD..CLOUD_SPREAD);
-        let y = rng.gen_range(CLOUD_ALTITUDE_MIN..CLOUD_ALTITUDE_MAX);
-        let z = rng.gen_range(-CLOUD_SPREAD..CLOUD_SPREAD);
-
-        let texture_index = rng.gen_range(0..cloud_textures.len());
-        let texture_path = cloud_textures[texture_index];
-
-        let base_size = 150.0;
-        let size = base_size + rng.gen_range(-50.0..100.0);
-
-        let cloud_material = materials.add(StandardMaterial {
-            base_color_texture: Some(asset_server.load(texture_path)),
-            base_color: Color::srgba(1.0, 1.0, 1.0, 0.8),
-            alpha_mode: AlphaMode::Blend,
-            unlit: true,
-            double_sided: true,
-            cull_mode: None,
-            ..default()
-        });
-
-        let cloud_mesh = meshes.add(Mesh::from(Rectangle::new(size, size * 0.7)));
-
-        commands.spawn((
-            Cloud,
-            Mesh3d(cloud_mesh),
-            MeshMaterial3d(cloud_material),
-            Transform::from_xyz(x, y, z)
-                .with_rotation(Quat::from_rotation_y(rng.gen_range(0.0..std::f32::consts::TAU))),
-            GlobalTransform::default(),
-            Visibility::default(),
-            InheritedVisibility::default(),
-        ));
-    }
-
-    println!("☁️  Realistic cloud layer spawned - {} clouds at 800-1500m altitude", CLOUD_COUNT);
-}
-
-/// Update clouds to face camera (billboarding)
-fn update_cloud_billboards(
-    mut cloud_query: Query<&mut Transform, (With<Cloud>, Without<Camera3d>)>,
-    camera_query: Query<&Transform, (With<Camera3d>, Without<Cloud>)>,
-) {
-    if let Ok(camera_transform) = camera_query.get_single() {
-        for mut cloud_transform in &mut cloud_query {
-            cloud_transform.look_at(camera_transform.translation, Vec3::Y);
-        }
-    }
-}
-
-/// Keep the sky sphere centered on the camera to create an infinite sky effect
-fn update_sky_sphere(
-    mut sky_sphere_query: Query<&mut Transform, With<SkySphere>>,
-    camera_query: Query<&Transform, (With<Camera3d>, Without<SkySphere>)>,
-) {
-    if let Ok(camera_transform) = camera_query.get_single() {
-        for mut sky_transform in &mut sky_sphere_query {
-            sky_transform.translation = camera_transform.translation;
-        }
-    }
-}
-
-/// Keep the visual sun disc at a fixed direction from the camera (matches DirectionalLight rotation)
-fn update_sun_position(
-    mut sun_query: Query<&mut Transform, With<SunMarker>>,
-    camera_query: Query<&Transform, (With<Camera3d>, Without<SunMarker>)>,
-) {
-    let Ok(cam) = camera_query.get_single() else { return };
-    let sun_rotation = Quat::from_euler(EulerRot::XYZ, -0.5, 0.5, 0.0);
-    let sun_dir = sun_rotation.mul_vec3(Vec3::Z).normalize();
-    for mut sun_transform in &mut sun_query {
-        sun_transform.translation = cam.translation + sun_dir * 18000.0;
-    }
-}
-
-/// Keep the horizon disk centered on the camera (XZ only)
-fn update_horizon_disk(
-    mut horizon_query: Query<&mut Transform, With<HorizonDisk>>,
-    camera_query: Query<&Transform, (With<Camera3d>, Without<HorizonDisk>)>,
-) {
-    if let Ok(camera_transform) = camera_query.get_single() {
-        for mut transform in &mut horizon_query {
-            transform.translation.x = camera_transform.translation.x;
-            transform.translation.z = camera_transform.translation.z;
-            // Y remains fixed at -5.0
-        }
-    }
+    ))
+    .with_children(|parent| {
+        parent.spawn((
+            LodVariant::levels(0, 1),
+            Mesh3d(wall_mesh),
+            MeshMaterial3d(wall_material),
+            Transform::IDENTITY,
+        ));
+        parent.spawn((
+            LodVariant::levels(2, 3),
+            Mesh3d(lod_assets.building_proxy_mesh.clone()),
+            MeshMaterial3d(lod_assets.building_proxy_material.clone()),
+            Transform::IDENTITY,
+        ));
+    });
 }
 
 /// NEW: Spawn random meteor obstacles in the sky per chunk
 fn spawn_meteors_in_chunk(
     commands: &mut Commands,
     asset_server: &AssetServer,
+    lod_assets: &lod::LodAssets,
+    meteor_count: usize,
     chunk_coord: ChunkCoordinate,
     chunk_entity: Entity,
 ) {
@@ -1921,11 +1779,9 @@ fn spawn_meteors_in_chunk(
         .map(|path| asset_server.load(*path))
         .collect();
 
-    // High density: 40 meteors per 1km chunk
-    const METEOR_COUNT: usize = 40;
-    
+    // High density: up to 40 meteors per 1km chunk (graphics preset)
     commands.entity(chunk_entity).with_children(|parent| {
-        for _ in 0..METEOR_COUNT {
+        for _ in 0..meteor_count {
             // Local position within the 1km chunk
             // Adjusted altitude range: 1500m to 6000m (Avoids new 1.4km mountains)
             let pos = Vec3::new(
@@ -1964,7 +1820,7 @@ fn spawn_meteors_in_chunk(
             parent.spawn((
                 Meteor,
                 ChunkEntity,
-                SceneRoot(model_handle),
+                LodGroup::new(LodCategory::Meteor),
                 Transform {
                     translation: pos,
                     rotation,
@@ -1980,7 +1836,17 @@ fn spawn_meteors_in_chunk(
                 GravityScale(0.0), // Zero Gravity (Floating)
                 LinearDamping(0.5), // Air resistance / Space drag
                 AngularDamping(0.5), // Rotational drag
-            ));
+            ))
+            .with_children(|meteor| {
+                meteor.spawn((LodVariant::levels(0, 1), SceneRoot(model_handle), Transform::IDENTITY));
+                // Low-poly rock matching the collider once the model is just a few pixels
+                meteor.spawn((
+                    LodVariant::levels(2, 3),
+                    Mesh3d(lod_assets.meteor_proxy_mesh.clone()),
+                    MeshMaterial3d(lod_assets.meteor_proxy_material.clone()),
+                    Transform::IDENTITY,
+                ));
+            });
         }
     });
 }
@@ -2000,8 +1866,8 @@ fn spawn_objectives(
     ];
 
     for (i, pos) in positions.iter().enumerate() {
-        // Sample height so they don't spawn underground
-        let terrain_y = get_terrain_height(pos.x, pos.z);
+        // Sample height so they don't spawn underground (origin is still at zero here)
+        let terrain_y = get_terrain_height(pos.x as f64, pos.z as f64);
         let spawn_pos = Vec3::new(pos.x, terrain_y, pos.z);
 
         commands.spawn((
@@ -2038,7 +1904,7 @@ fn spawn_turrets(
     ];
 
     for pos in positions {
-        let terrain_y = get_terrain_height(pos.x, pos.z);
+        let terrain_y = get_terrain_height(pos.x as f64, pos.z as f64);
         let spawn_pos = Vec3::new(pos.x, terrain_y, pos.z);
 
         commands.spawn((
@@ -2066,8 +1932,8 @@ fn update_turrets(
     player_query: Query<&Transform, With<PlayerPlane>>,
     mut turret_query: Query<(&mut Transform, &mut Turret), Without<PlayerPlane>>,
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    weapon_assets: Res<WeaponAssets>,
+    mut particles: EventWriter<EmitParticles>,
 ) {
     if let Ok(player_transform) = player_query.get_single() {
         for (mut transform, mut turret) in &mut turret_query {
@@ -2082,46 +1948,27 @@ fn update_turrets(
                 let direction = (target_pos - muzzle_pos).normalize();
                 let velocity = direction * 300.0; // Slower than player bullets
                 
-                spawn_missile(&mut commands, &mut meshes, &mut materials, muzzle_pos, transform.rotation, velocity);
-                spawn_muzzle_flash(&mut commands, &mut meshes, &mut materials, muzzle_pos, None);
+                spawn_missile(&mut commands, &weapon_assets, muzzle_pos, transform.rotation, velocity);
+                spawn_muzzle_flash(&mut commands, &mut particles, muzzle_pos, None);
             }
         }
     }
 }
 
-/// Dynamic engine and environmental audio system
-fn update_engine_audio(
-    game_state: Res<State<GameState>>,
-    player_query: Query<(&PlayerInput, &LinearVelocity, &Transform), With<PlayerPlane>>,
-    mut engine_audio_query: Query<&AudioSink, (With<EngineSound>, Without<WindSound>, Without<WarningSound>)>,
-    mut wind_audio_query: Query<&AudioSink, (With<WindSound>, Without<EngineSound>, Without<WarningSound>)>,
-    mut warning_audio_query: Query<&AudioSink, (With<WarningSound>, Without<EngineSound>, Without<WindSound>)>,
+/// Environmental airflow audio (the engine itself is in engine_audio.rs)
+fn update_wind_audio(
+    player_query: Query<&LinearVelocity, With<PlayerPlane>>,
+    mut wind_audio_query: Query<(&mut audio::MixerSound, Option<&AudioSink>), With<WindSound>>,
 ) {
-    // Pause all audio when game is paused
-    if game_state.get() == &GameState::Paused {
-        for sink in &mut engine_audio_query {
-            sink.pause();
-        }
-        for sink in &mut wind_audio_query {
-            sink.pause();
-        }
-        return;
-    }
-
-    if let Ok((input, velocity, transform)) = player_query.get_single() {
+    if let Ok(velocity) = player_query.get_single() {
         let speed = velocity.0.length();
-        
-        // Engine Sound: Scales with throttle
-        for sink in &mut engine_audio_query {
-            sink.set_volume(0.1 + input.throttle * 0.7);
-            sink.set_speed(0.8 + input.throttle * 0.7);
-        }
 
         // Wind/Airflow Sound: Scales with actual speed (max volume at 500 m/s)
-        let wind_volume = (speed / 500.0).min(1.0) * 0.5;
-        for sink in &mut wind_audio_query {
-            sink.set_volume(wind_volume);
-            sink.set_speed(0.9 + (speed / 500.0) * 0.3);
+        for (mut sound, sink) in &mut wind_audio_query {
+            sound.volume = (speed / 500.0).min(1.0) * 0.5;
+            if let Some(sink) = sink {
+                sink.set_speed(0.9 + (speed / 500.0) * 0.3);
+            }
         }
     }
 }
@@ -2130,19 +1977,42 @@ fn spawn_player(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     sounds: Res<GameAssets>,
+    bindings: Res<controls::Bindings>,
+    mission_start: Res<airfield::MissionStart>,
+    origin: Res<origin::WorldOrigin>,
 ) {
-    // Print controls on startup
+    // Print controls on startup, straight from the bindings so they can't go stale
+    let pair = |a: Action, b: Action| format!("{}/{}", bindings.describe(a), bindings.describe(b));
+    let rows = [
+        (pair(Action::PitchDown, Action::PitchUp), "Pitch Down/Up"),
+        (pair(Action::RollLeft, Action::RollRight), "Roll Left/Right"),
+        (pair(Action::YawLeft, Action::YawRight), "Yaw Left/Right"),
+        (bindings.describe(Action::ThrottleUp), "Increase Throttle (Boost)"),
+        (bindings.describe(Action::ThrottleDown), "Decrease Throttle"),
+        (bindings.describe(Action::FireMissile), Action::FireMissile.label()),
+        (bindings.describe(Action::FireGun), Action::FireGun.label()),
+        (bindings.describe(Action::ToggleRocket), Action::ToggleRocket.label()),
+        (bindings.describe(Action::ToggleSas), Action::ToggleSas.label()),
+        (bindings.describe(Action::ToggleMouseAim), Action::ToggleMouseAim.label()),
+        (bindings.describe(Action::ToggleGear), Action::ToggleGear.label()),
+        (bindings.describe(Action::CycleFlaps), Action::CycleFlaps.label()),
+        (bindings.describe(Action::Brake), Action::Brake.label()),
+        (bindings.describe(Action::CycleCamera), Action::CycleCamera.label()),
+        (pair(Action::TimeSlower, Action::TimeFaster), "Time of Day Slower/Faster"),
+        (bindings.describe(Action::CycleWeather), Action::CycleWeather.label()),
+        (bindings.describe(Action::ToggleLodStats), Action::ToggleLodStats.label()),
+        (bindings.describe(Action::Pause), Action::Pause.label()),
+        (bindings.describe(Action::Replay), Action::Replay.label()),
+        (bindings.describe(Action::Restart), Action::Restart.label()),
+        (bindings.describe(Action::Quit), Action::Quit.label()),
+        ("F1".to_string(), "Rebind Controls"),
+    ];
     println!("\n╔══════════════════════════════════════════════╗");
     println!("║       F-16 FIGHTER JET - CONTROLS           ║");
     println!("╠══════════════════════════════════════════════╣");
-    println!("║  W/S        - Pitch Up/Down                  ║");
-    println!("║  A/D        - Roll Left/Right                ║");
-    println!("║  Q/E        - Yaw Left/Right                 ║");
-    println!("║  Shift      - Increase Throttle (Boost)      ║");
-    println!("║  Ctrl       - Decrease Throttle              ║");
-    println!("║  SPACE      - Fire Missiles                  ║");
-    println!("║  R          - Restart Game                   ║");
-    println!("║  ESC        - Quit                           ║");
+    for (keys, label) in rows {
+        println!("║  {:<10} - {:<30}║", keys, label);
+    }
     println!("╚══════════════════════════════════════════════╝\n");
 
     // Load the F-16 Template (High Quality)
@@ -2150,28 +2020,37 @@ fn spawn_player(
     // Alternative: let model_handle = asset_server.load("models/low_poly_f16/scene.gltf#Scene0");
     // Alternative: let model_handle = asset_server.load("models/fighter_jet_enhanced.gltf#Scene0");
 
+    // Lined up on the home field runway, or already flying over it
+    let spawn = airfield::PlayerSpawn::new(*mission_start);
+    println!("🛫 SPAWN: {} at {}", mission_start.label(), airfield::home_airfield().name());
+
     let player = commands.spawn((
         PlayerPlane,
-        Transform::from_xyz(0.0, 500.0, 0.0), // Start high up
+        Transform::from_translation(origin.to_local(spawn.position)).with_rotation(spawn.rotation),
         GlobalTransform::default(),
         Visibility::default(),
         InheritedVisibility::default(),
         RigidBody::Dynamic,
         Mass(MASS_KG), // REAL MASS
-        LinearVelocity(Vec3::new(0.0, 0.0, -100.0)), // Start at 100 m/s - gentler start
+        LinearVelocity(spawn.velocity()),
         AngularVelocity::default(),
         ExternalForce::default(),
         ExternalTorque::default(),
         Collider::cuboid(2.0, 1.0, 4.0),
         PlayerInput::default(),
-        FlightCamera::default(),
+        camera::FlightCamera::default(),
     ))
     .insert(FlightControlComputer::default())
     .insert(DiagnosticTimer(Timer::from_seconds(0.5, TimerMode::Repeating)))
     .insert(AfterburnerParticles::default())
     .insert(LastShotTime::default())
     .insert(MachineGunState::default())
-    .insert(RocketMode::default())
+    .insert(space::RocketEngine::default())
+    .insert(space::ReentryHeat::default())
+    .insert(engine_audio::EngineSpool::default())
+    .insert(landing_gear::LandingGear::new(spawn.on_ground))
+    .insert(resupply::FuelTank::default())
+    .insert(resupply::Stores::default())
     .id();
 
     commands.entity(player)
@@ -2188,26 +2067,28 @@ fn spawn_player(
             SceneRoot(model_handle),
         ));
 
-        // Looping engine sound
+        // Re-entry glow on the nose (dark until the airframe heats up)
         parent.spawn((
-            EngineSound,
-            AudioPlayer(sounds.engine_loop.clone()),
-            PlaybackSettings {
-                mode: bevy::audio::PlaybackMode::Loop,
-                volume: bevy::audio::Volume::new(0.2), // Start quiet
+            space::ReentryGlow,
+            PointLight {
+                color: Color::srgb(1.0, 0.45, 0.25),
+                intensity: 0.0,
+                range: 60.0,
+                shadows_enabled: false,
                 ..default()
             },
+            Transform::from_xyz(0.0, 0.0, -6.0),
         ));
 
+        // Looping engine layers (whine, mil power, afterburner)
+        engine_audio::spawn_engine_layers(parent, &sounds);
+
         // Wind/Airflow loop
         parent.spawn((
             WindSound,
             AudioPlayer(sounds.wind.clone()),
-            PlaybackSettings {
-                mode: bevy::audio::PlaybackMode::Loop,
-                volume: bevy::audio::Volume::new(0.0), // Starts silent
-                ..default()
-            },
+            PlaybackSettings::LOOP,
+            audio::MixerSound::new(audio::AudioBus::Ambient, 0.0), // Starts silent
         ));
 
         // Warning alarm loop
@@ -2216,65 +2097,67 @@ fn spawn_player(
             AudioPlayer(sounds.warning.clone()),
             PlaybackSettings {
                 mode: bevy::audio::PlaybackMode::Loop,
-                volume: bevy::audio::Volume::new(0.0), // Starts silent
-                paused: true, // Only plays in danger
+                paused: true,
                 ..default()
             },
+            audio::MixerSound::stopped(audio::AudioBus::Voice), // Only plays in danger
         ));
 
         // Air Rip / Wing Stress loop (Cinematic)
         parent.spawn((
             WindStressSound,
             AudioPlayer(sounds.air_rip.clone()),
-            PlaybackSettings {
-                mode: bevy::audio::PlaybackMode::Loop,
-                volume: bevy::audio::Volume::new(0.0), // Silent until high-G turn
-                ..default()
-            },
+            PlaybackSettings::LOOP,
+            audio::MixerSound::new(audio::AudioBus::Ambient, 0.0), // Silent until high-G turn
         ));
     });
 }
 
 fn read_player_input(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    mut player_query: Query<(&mut PlayerInput, &AngularVelocity, &Transform, &mut FlightControlComputer, &mut RocketMode), With<PlayerPlane>>,
+    actions: Res<ActionState>,
+    mut player_query: Query<(&mut PlayerInput, &AngularVelocity, &Transform, &mut FlightControlComputer, &mut space::RocketEngine), With<PlayerPlane>>,
 ) {
-    for (mut input, _ang_vel, _transform, mut fbw, mut rocket_mode) in &mut player_query {
-        // Toggle Rocket Mode with R key
-        if keyboard_input.just_pressed(KeyCode::KeyR) {
-            rocket_mode.enabled = !rocket_mode.enabled;
-            println!("🚀 ROCKET MODE: {}", if rocket_mode.enabled { "ENABLED" } else { "DISABLED" });
+    for (mut input, _ang_vel, _transform, mut fbw, mut rocket) in &mut player_query {
+        // Toggle Rocket Engine (only lights while there is propellant)
+        if actions.just_pressed(Action::ToggleRocket) {
+            if rocket.propellant <= 0.0 {
+                println!("🚀 ROCKET: No propellant");
+            } else {
+                rocket.enabled = !rocket.enabled;
+                println!("🚀 ROCKET: {} ({:.0}% propellant)",
+                    if rocket.enabled { "IGNITED" } else { "SHUT DOWN" }, rocket.fuel_fraction() * 100.0);
+            }
         }
 
-        // Toggle SAS with K key (for legacy FBW, not used with arcade physics)
-        if keyboard_input.just_pressed(KeyCode::KeyK) {
+        // Toggle SAS (for legacy FBW, not used with arcade physics)
+        if actions.just_pressed(Action::ToggleSas) {
             fbw.sas_enabled = !fbw.sas_enabled;
             println!("⚙️  SAS: {}", if fbw.sas_enabled { "ENABLED ✓" } else { "DISABLED ⚠️" });
         }
         
         // ARCADE PHYSICS: Full authority control (no SAS limiting)
-        // Pitch (W/S) - Direct control (inverted: W pitches down, S pitches up)
-        let target_pitch = if keyboard_input.pressed(KeyCode::KeyW) { -1.0 }
-                          else if keyboard_input.pressed(KeyCode::KeyS) { 1.0 }
-                          else { 0.0 };
-        input.pitch = VectorSpace::lerp(input.pitch, target_pitch, 0.1);
-
-        // Roll (A/D) - Direct control
-        let target_roll = if keyboard_input.pressed(KeyCode::KeyA) { -1.0 }
-                         else if keyboard_input.pressed(KeyCode::KeyD) { 1.0 }
-                         else { 0.0 };
-        input.roll = VectorSpace::lerp(input.roll, target_roll, 0.1);
-
-        // Yaw (Q/E) - Direct control
-        let target_yaw = if keyboard_input.pressed(KeyCode::KeyQ) { 1.0 } 
-                        else if keyboard_input.pressed(KeyCode::KeyE) { -1.0 } 
-                        else { 0.0 };
-        input.yaw = VectorSpace::lerp(input.yaw, target_yaw, 0.1);
-
-        // Throttle (Shift/Ctrl)
-        if keyboard_input.pressed(KeyCode::ShiftLeft) {
+        // An analog stick sets the surfaces directly; buttons ramp toward full deflection
+        // Pitch - Direct control (inverted: W / stick forward pitches down)
+        input.pitch = actions.axis(FlightAxis::Pitch).unwrap_or_else(|| {
+            VectorSpace::lerp(input.pitch, actions.digital_axis(Action::PitchUp, Action::PitchDown), 0.1)
+        });
+
+        // Roll - Direct control
+        input.roll = actions.axis(FlightAxis::Roll).unwrap_or_else(|| {
+            VectorSpace::lerp(input.roll, actions.digital_axis(Action::RollRight, Action::RollLeft), 0.1)
+        });
+
+        // Yaw - Direct control (positive = left)
+        input.yaw = actions.axis(FlightAxis::Yaw).unwrap_or_else(|| {
+            VectorSpace::lerp(input.yaw, actions.digital_axis(Action::YawLeft, Action::YawRight), 0.1)
+        });
+
+        // Throttle: a lever sets it outright when moved, buttons nudge it
+        if let Some(lever) = actions.axis(FlightAxis::Throttle) {
+            input.throttle = lever;
+        } else if actions.pressed(Action::ThrottleUp) {
             input.throttle = (input.throttle + 0.01).min(1.0); // Afterburner
-        } else if keyboard_input.pressed(KeyCode::ControlLeft) {
+        } else if actions.pressed(Action::ThrottleDown) {
             input.throttle = (input.throttle - 0.01).max(0.0);
         }
     }
@@ -2484,7 +2367,7 @@ fn apply_aerodynamics(
         // Drag: Base from alpha + airbrake + speed penalty
         // Add exponential drag at high speeds to limit max velocity naturally
         let speed_drag_factor = (speed / 200.0).powi(2) * 0.3; // Ramps up aggressively
-        let cd = aero.cd_alpha.sample(alpha) + 0.05 * input._brake + speed_drag_factor;
+        let cd = aero.cd_alpha.sample(alpha) + 0.05 * input.brake + speed_drag_factor;
 
         let cy = aero.cy_beta * beta;
 
@@ -2601,6 +2484,9 @@ fn apply_aerodynamics(
 /// This replaces the JSBSim aerodynamics with simple, fun, stable controls.
 /// Player input directly controls rotation rates - no complex feedback loops.
 fn arcade_flight_physics(
+    time: Res<Time>,
+    weather: Res<weather::Weather>,
+    origin: Res<origin::WorldOrigin>,
     mut player_query: Query<
         (
             &PlayerInput,
@@ -2608,7 +2494,7 @@ fn arcade_flight_physics(
             &LinearVelocity,
             &mut AngularVelocity,
             &mut ExternalForce,
-            &RocketMode,
+            &resupply::FuelTank,
         ),
         With<PlayerPlane>,
     >,
@@ -2622,31 +2508,47 @@ fn arcade_flight_physics(
     const SMOOTHING_FACTOR: f32 = 0.15;
     const BOOST_MULTIPLIER: f32 = 3.5;
     const BOOST_THRESHOLD: f32 = 0.8;
+    const GUST_ACCEL: f32 = 0.5; // m/s² of bump per m/s of gust
+    const GUST_ROTATION: f32 = 0.015; // rad/s of buffet per m/s of gust
 
-    for (input, transform, velocity, mut ang_vel, mut ext_force, rocket_mode) in &mut player_query {
+    for (input, transform, velocity, mut ang_vel, mut ext_force, tank) in &mut player_query {
         ext_force.clear();
 
+        // Thin air: wings, intake and wind all fade out toward the Kármán line
+        // (the space module's RCS and rocket take over up there)
+        let air = space::atmosphere_factor(transform.translation.y);
+
         // ===== 1. LOCAL-SPACE ROTATION (Gemini's elegant approach) =====
         // Use transform basis vectors for proper 3D rotation in aircraft's local frame
         let right = transform.right().as_vec3();
         let up = transform.up().as_vec3();
         let forward = transform.forward().as_vec3();
 
-        // Target rotation rates in LOCAL space (around plane's own axes)
+        // Gusts at the jet's position (zero in calm air). Sampled in absolute space so
+        // a recenter doesn't jump the jet into a different patch of turbulence.
+        let air_sample_point = origin.to_absolute(transform.translation).as_vec3();
+        let gust = weather.gust_at(air_sample_point, time.elapsed_secs()) * air;
+
+        // Target rotation rates in LOCAL space (around plane's own axes), plus turbulent buffet
         let target_omega = right * input.pitch * PITCH_RATE +
                           up * input.yaw * YAW_RATE +
-                          forward * input.roll * ROLL_RATE;
+                          forward * input.roll * ROLL_RATE +
+                          (right * gust.y + forward * gust.x) * GUST_ROTATION;
 
         // Smooth interpolation for natural feel - NaN PROTECTION
+        // Control surfaces lose their bite as the air thins out
         if !target_omega.is_nan() && target_omega.is_finite() {
-             ang_vel.0 = ang_vel.0.lerp(target_omega, SMOOTHING_FACTOR);
+             ang_vel.0 = ang_vel.0.lerp(target_omega, SMOOTHING_FACTOR * air);
         }
 
-        // ===== 2. DRAG =====
-        let speed = velocity.length();
+        // ===== 2. DRAG (relative to the moving air mass) =====
+        // This is also what carries the jet downwind: there is no separate drift force
+        let wind = weather.wind_at(air_sample_point, time.elapsed_secs()) * air - gust;
+        let air_velocity = velocity.0 - wind;
+        let speed = air_velocity.length();
         if speed > 1.0 && speed.is_finite() {
             // SAFE NORMALIZATION: Prevent division by zero if velocity is tiny
-            let drag_force = -velocity.0.normalize_or_zero() * speed * speed * DRAG_COEFFICIENT;
+            let drag_force = -air_velocity.normalize_or_zero() * speed * speed * DRAG_COEFFICIENT * air;
             if !drag_force.is_nan() && drag_force.is_finite() {
                 ext_force.apply_force(drag_force);
             }
@@ -2677,54 +2579,27 @@ fn arcade_flight_physics(
         // Apply boost multiplier when throttle is high
         let mut boost_mult = if safe_throttle > BOOST_THRESHOLD { BOOST_MULTIPLIER } else { 1.0 };
         
-        // Rocket mode overrides everything with massive thrust
-        if rocket_mode.enabled {
-            boost_mult = ROCKET_THRUST_MULTIPLIER;
-        }
-        
         // Final safety clamp on boost
         boost_mult = boost_mult.clamp(1.0, 20.0);
 
-        let thrust_force = (forward * forward_component + up * vertical_component) * boost_mult;
+        // Air-breathing: the turbine starves with altitude (the rocket is separate, see space.rs),
+        // and dies outright with dry tanks
+        let fuel_cut = if tank.is_empty() { 0.0 } else { 1.0 };
+        let thrust_force = (forward * forward_component + up * vertical_component) * boost_mult * air * fuel_cut;
         
         if !thrust_force.is_nan() && thrust_force.is_finite() {
             ext_force.apply_force(thrust_force);
         }
 
         // ===== 4. GRAVITY =====
-        // Handled by Avian3D - no manual application needed
-    }
-}
+        // Handled by Avian3D; space.rs adds curved-planet relief at altitude
 
-fn update_flight_camera(
-    time: Res<Time>,
-    mut camera_query: Query<&mut Transform, (With<Camera3d>, Without<PlayerPlane>)>,
-    player_query: Query<(&Transform, &FlightCamera, &LinearVelocity), With<PlayerPlane>>,
-) {
-    if let (Ok(mut camera_transform), Ok((player_transform, _flight_camera, _velocity))) =
-        (camera_query.get_single_mut(), player_query.get_single()) {
-
-        // === STEP 1: Set camera position directly (no smoothing) ===
-        // Position: 15 units behind plane, 5 units above plane center
-        let local_offset = Vec3::new(0.0, 5.0, 15.0);
-        camera_transform.translation = player_transform.transform_point(local_offset);
-
-        // === STEP 2: Calculate desired camera rotation ===
-        // Camera looks at plane center, using plane's up vector as camera up
-        let look_target = player_transform.translation;
-        let camera_up = player_transform.up().as_vec3();
-
-        let temp_transform = Transform::IDENTITY
-            .with_translation(camera_transform.translation)
-            .looking_at(look_target, camera_up);
-
-        let target_rotation = temp_transform.rotation;
-
-        // === STEP 3: Smoothly rotate camera to follow plane ===
-        // Smooth rotation prevents jerky head movement (15 rad/s interpolation speed)
-        let t_rot = (15.0 * time.delta_secs()).min(1.0);
-        camera_transform.rotation =
-            camera_transform.rotation.slerp(target_rotation, t_rot);
+        // ===== 5. TURBULENCE =====
+        // Gust bumps (steady wind acts through the air-relative drag above)
+        let gust_force = gust * GUST_ACCEL * MASS_KG;
+        if gust_force.is_finite() {
+            ext_force.apply_force(gust_force);
+        }
     }
 }
 
@@ -2892,96 +2767,89 @@ fn debug_flight_data(
 }
 
 /// Handle global pause toggle (P key)
-/// Runs in all states to allow unpausing
+/// Runs in all states to allow unpausing; the menu plugin freezes physics on the way in and out
 fn handle_pause_input(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    actions: Res<ActionState>,
     state: Res<State<GameState>>,
     mut next_state: ResMut<NextState<GameState>>,
-    mut physics_time: ResMut<Time<Physics>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::KeyP) {
+    if actions.just_pressed(Action::Pause) {
         match state.get() {
-            GameState::Playing => {
-                next_state.set(GameState::Paused);
-                physics_time.pause();
-                println!("⏸️  PAUSED - Physics Frozen");
-            }
-            GameState::Paused => {
-                next_state.set(GameState::Playing);
-                physics_time.unpause();
-                println!("▶️  RESUMED - Physics Active");
-            }
+            GameState::Playing => next_state.set(GameState::Paused),
+            GameState::Paused => next_state.set(GameState::Playing),
             _ => {}
         }
     }
 }
 
 fn handle_quit(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    actions: Res<ActionState>,
     mut exit: EventWriter<AppExit>,
 ) {
-    // Use F10 to quit (ESC is now respawn)
-    if keyboard_input.pressed(KeyCode::F10) {
+    // F10 by default (kept off ESC to avoid accidental quits)
+    if actions.pressed(Action::Quit) {
         exit.send(AppExit::Success);
     }
 }
 
+/// Sent by the pause menu's "Restart Mission"; handled like the restart key
+#[derive(Event)]
+pub struct RestartRequested;
+
+/// F5 (by default) asks for a restart, the same way the pause menu does
+fn request_restart(
+    actions: Res<ActionState>,
+    mut restart: EventWriter<RestartRequested>,
+) {
+    if actions.just_pressed(Action::Restart) {
+        restart.send(RestartRequested);
+    }
+}
+
+/// Put the jet back at the mission start with a clean slate and clear the sky.
+/// `spawn_mission_swarm` brings in the fresh swarm on the same event.
 fn handle_restart(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut restart_requests: EventReader<RestartRequested>,
     mut player_query: Query<
-        (
-            &mut Transform,
-            &mut LinearVelocity,
-            &mut AngularVelocity,
-            &mut PlayerInput,
-            &mut FlightControlComputer,
-        ),
+        (Entity, &mut Transform, &mut LinearVelocity, &mut AngularVelocity, &mut landing_gear::LandingGear),
         With<PlayerPlane>,
     >,
     mut commands: Commands,
     drone_query: Query<Entity, With<Drone>>,
     projectile_query: Query<Entity, With<Projectile>>, // Added Projectile query
-    asset_server: Res<AssetServer>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    origin: Res<origin::WorldOrigin>,
+    mission_start: Res<airfield::MissionStart>,
 ) {
-    // F5 to respawn (ESC removed to avoid accidental restarts)
-    if keyboard_input.just_pressed(KeyCode::F5) {
-        if let Ok((mut transform, mut lin_vel, mut ang_vel, mut input, mut fbw)) =
-            player_query.get_single_mut()
-        {
-            println!("🔄 RESPAWNING PLAYER AND RESETTING SWARM");
-            
-            // 1. Clear existing drones
-            for drone_entity in &drone_query {
-                commands.entity(drone_entity).despawn_recursive();
-            }
-
-            // 2. Clear existing projectiles (and their attached sounds)
-            for proj_entity in &projectile_query {
-                commands.entity(proj_entity).despawn_recursive();
-            }
-
-            // 3. Reset position to spawn point
-            transform.translation = Vec3::new(0.0, 500.0, 0.0);
-            transform.rotation = Quat::IDENTITY;
+    if restart_requests.read().count() == 0 {
+        return;
+    }
+    let Ok((player, mut transform, mut lin_vel, mut ang_vel, mut gear)) = player_query.get_single_mut() else { return };
+    println!("🔄 RESPAWNING PLAYER AND RESETTING SWARM");
 
-            // 3. Reset velocity and rotation
-            lin_vel.0 = Vec3::new(0.0, 0.0, -100.0);
-            ang_vel.0 = Vec3::ZERO;
+    // 1. Clear existing drones
+    for drone_entity in &drone_query {
+        commands.entity(drone_entity).despawn_recursive();
+    }
 
-            // 4. Reset input state
-            *input = PlayerInput::default();
+    // 2. Clear existing projectiles (and their attached sounds)
+    for proj_entity in &projectile_query {
+        commands.entity(proj_entity).despawn_recursive();
+    }
 
-            // 5. Reset FBW state
-            *fbw = FlightControlComputer::default();
+    // 3. Back to the mission's start (runway or air start), gear to match
+    airfield::PlayerSpawn::new(*mission_start)
+        .place(&origin, &mut transform, &mut lin_vel, &mut ang_vel, &mut gear);
 
-            // 6. Spawn fresh fresh swarm
-            spawn_initial_drone_swarm(&mut commands, &*asset_server, &mut *meshes, &mut *materials);
+    // 4. Reset input and FBW state, refuel and rearm
+    commands.entity(player).insert((
+        PlayerInput::default(),
+        FlightControlComputer::default(),
+        space::RocketEngine::default(),
+        resupply::FuelTank::default(),
+        resupply::Stores::default(),
+    ));
 
-            println!("\n🔄 GAME RESTARTED\n");
-        }
-    }
+    println!("\n🔄 GAME RESTARTED\n");
 }
 
 fn debug_asset_loading(
@@ -3002,18 +2870,19 @@ fn debug_asset_loading(
 }
 
 fn handle_shooting_input(
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mouse: Res<ButtonInput<MouseButton>>, // Added mouse input
+    actions: Res<ActionState>,
     time: Res<Time>,
-    mut player_query: Query<(Entity, &Transform, &LinearVelocity, &mut LastShotTime), With<PlayerPlane>>,
+    mut player_query: Query<(Entity, &Transform, &LinearVelocity, &mut LastShotTime, &mut resupply::Stores), With<PlayerPlane>>,
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    weapon_assets: Res<WeaponAssets>,
     sounds: Res<GameAssets>,
+    mut play_sound: EventWriter<audio::PlaySound>,
+    mut particles: EventWriter<EmitParticles>,
 ) {
-    if let Ok((player_entity, player_transform, player_velocity, mut last_shot)) = player_query.get_single_mut() {
+    if let Ok((player_entity, player_transform, player_velocity, mut last_shot, mut stores)) = player_query.get_single_mut() {
         let current_time = time.elapsed_secs();
-        let can_shoot = (keyboard.pressed(KeyCode::Space) || mouse.pressed(MouseButton::Right))
+        let can_shoot = actions.pressed(Action::FireMissile)
+            && stores.missiles > 0
             && (current_time - last_shot.time >= FIRE_COOLDOWN);
 
         if can_shoot {
@@ -3025,10 +2894,10 @@ fn handle_shooting_input(
             let bullet_velocity = player_velocity.0 + (forward * BULLET_SPEED);
 
             // Spawn missile with proper orientation and visuals
-            let missile_entity = spawn_missile(&mut commands, &mut meshes, &mut materials, gun_position_world, player_transform.rotation, bullet_velocity);
+            let missile_entity = spawn_missile(&mut commands, &weapon_assets, gun_position_world, player_transform.rotation, bullet_velocity);
             
             // Pass local GUN_OFFSET and parent to plane
-            spawn_muzzle_flash(&mut commands, &mut meshes, &mut materials, GUN_OFFSET, Some(player_entity));
+            spawn_muzzle_flash(&mut commands, &mut particles, GUN_OFFSET, Some(player_entity));
 
             // Play missile launch sound
             // Logic: Play heavy "Hero" sound MORE often (1 in 5)
@@ -3042,30 +2911,17 @@ fn handle_shooting_input(
             // Add slight pitch variation to everything
             let pitch_speed = rng.gen_range(0.9..1.1);
 
-            // Attach audio to missile with MANUAL attenuation (fixes stereo file issues)
-            commands.entity(missile_entity).with_children(|parent| {
-                parent.spawn((
-                    AudioPlayer(sound),
-                    PlaybackSettings {
-                        mode: bevy::audio::PlaybackMode::Despawn,
-                        volume: bevy::audio::Volume::new(volume),
-                        speed: pitch_speed,
-                        spatial: false, // DISABLED: Handled manually
-                        ..default()
-                    },
-                    ManualAttenuation {
-                        max_distance: 10000.0, // 10km audible range
-                        reference_distance: 400.0, // 400m for long cinematic fade tail
-                        base_volume: volume,
-                        age: 0.0, // Start age at 0
-                        doppler_ramp_time: 0.5, // 0.5s ramp to full Doppler (keeps initial punch)
-                    },
-                    Transform::IDENTITY, // REQUIRED: Allow position inheritance
-                    Visibility::default(),
-                ));
-            });
+            // Rides on the missile so it pans and Doppler-shifts as it pulls away
+            play_sound.send(
+                audio::PlaySound::new(sound, audio::AudioBus::Weapons)
+                    .attached(missile_entity)
+                    .with_volume(volume)
+                    .with_speed(pitch_speed)
+                    .with_range(400.0, 10000.0), // 400m for long cinematic fade tail, 10km audible range
+            );
 
             last_shot.time = current_time;
+            stores.missiles -= 1;
         }
     }
 }
@@ -3124,17 +2980,19 @@ fn update_projectiles(
 }
 
 fn handle_machine_gun_input(
-    mouse: Res<ButtonInput<MouseButton>>,
+    actions: Res<ActionState>,
     time: Res<Time>,
-    mut player_query: Query<(Entity, &Transform, &LinearVelocity, &mut MachineGunState), With<PlayerPlane>>,
+    mut player_query: Query<(Entity, &Transform, &LinearVelocity, &mut MachineGunState, &mut resupply::Stores), With<PlayerPlane>>,
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    weapon_assets: Res<WeaponAssets>,
     sounds: Res<GameAssets>,
+    mut play_sound: EventWriter<audio::PlaySound>,
+    mut particles: EventWriter<EmitParticles>,
 ) {
-    if let Ok((player_entity, player_transform, player_velocity, mut mg_state)) = player_query.get_single_mut() {
+    if let Ok((player_entity, player_transform, player_velocity, mut mg_state, mut stores)) = player_query.get_single_mut() {
         let current_time = time.elapsed_secs();
-        let can_shoot = mouse.pressed(MouseButton::Left)
+        let can_shoot = actions.pressed(Action::FireGun)
+            && stores.rounds > 0
             && (current_time - mg_state.last_fired >= MG_FIRE_RATE);
 
         if can_shoot {
@@ -3193,10 +3051,10 @@ fn handle_machine_gun_input(
                 rng.gen_range(2.0..5.0)
             };
             
-            spawn_bullet(&mut commands, &mut meshes, &mut materials, bullet_position_world, player_transform.rotation, bullet_velocity, length_mult, glow_mult, is_tracer);
+            spawn_bullet(&mut commands, &weapon_assets, bullet_position_world, player_transform.rotation, bullet_velocity, length_mult, glow_mult, is_tracer);
             
             // Pass local offset and parent to plane
-            spawn_muzzle_flash(&mut commands, &mut meshes, &mut materials, offset, Some(player_entity));
+            spawn_muzzle_flash(&mut commands, &mut particles, offset, Some(player_entity));
 
             // Play machine gun sound (Non-spatial for "punch")
             // Pitch shift tracers slightly higher for feedback
@@ -3206,18 +3064,14 @@ fn handle_machine_gun_input(
                 rng.gen_range(0.95..1.05)
             };
 
-            commands.spawn((
-                AudioPlayer(sounds.machine_gun.clone()),
-                PlaybackSettings {
-                    mode: bevy::audio::PlaybackMode::Despawn,
-                    volume: bevy::audio::Volume::new(0.5),
-                    speed: pitch_speed,
-                    spatial: false,
-                    ..default()
-                },
-            ));
+            play_sound.send(
+                audio::PlaySound::new(sounds.machine_gun.clone(), audio::AudioBus::Weapons)
+                    .with_volume(0.5)
+                    .with_speed(pitch_speed),
+            );
 
             mg_state.last_fired = current_time;
+            stores.rounds -= 1;
         }
     }
 }
@@ -3226,8 +3080,7 @@ fn update_bullets(
     time: Res<Time>,
     mut commands: Commands,
     mut bullet_query: Query<(Entity, &mut Bullet, &Transform, Option<&LinearVelocity>)>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particles: EventWriter<EmitParticles>,
 ) {
     let delta = time.delta_secs();
 
@@ -3244,13 +3097,6 @@ fn update_bullets(
         if bullet.is_tracer {
             // Spawn 2 tiny red lingering sparks per frame for density
             for i in 0..2 {
-                let mesh = meshes.add(Sphere::new(0.12)); // Buffed from 0.04
-                let material = materials.add(StandardMaterial {
-                    base_color: Color::srgb(1.0, 0.0, 0.0),
-                    emissive: LinearRgba::rgb(300.0, 0.0, 0.0), // Massive trail brightness
-                    ..default()
-                });
-
                 // Offset trail slightly in LOCAL space
                 let local_offset = Vec3::new(
                     (i as f32 * 0.2) - 0.1,
@@ -3259,18 +3105,8 @@ fn update_bullets(
                 );
                 let world_offset = transform.rotation.mul_vec3(local_offset);
 
-                commands.spawn((
-                    VisualDebris {
-                        velocity: Vec3::ZERO, // Linger in air
-                        lifetime: 0.2,
-                    },
-                    Transform::from_translation(transform.translation + world_offset),
-                    GlobalTransform::default(),
-                    Visibility::default(),
-                    InheritedVisibility::default(),
-                    Mesh3d(mesh),
-                    MeshMaterial3d(material),
-                ));
+                // Zero velocity: embers linger in the air behind the round
+                particles.send(EmitParticles::new(ParticleType::TracerEmber, transform.translation + world_offset));
             }
         }
 
@@ -3287,9 +3123,10 @@ fn bullet_drone_collision(
     mut commands: Commands,
     mut bullets: Query<(Entity, &Transform, &mut Bullet)>,
     mut drones: Query<(Entity, &Transform, &mut drone::Drone), Without<Bullet>>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
     game_assets: Res<GameAssets>,
+    mut play_sound: EventWriter<audio::PlaySound>,
+    mut particles: EventWriter<EmitParticles>,
+    mut effects: EventWriter<SpawnEffect>,
 ) {
     for (bullet_entity, bullet_transform, mut bullet) in &mut bullets {
         let current_pos = bullet_transform.translation;
@@ -3329,7 +3166,7 @@ fn bullet_drone_collision(
                 } else {
                     current_pos
                 };
-                spawn_hit_spark(&mut commands, &mut meshes, &mut materials, hit_visual_pos);
+                spawn_hit_spark(&mut particles, hit_visual_pos);
 
                 // Despawn bullet on hit
                 if commands.get_entity(bullet_entity).is_some() {
@@ -3348,28 +3185,14 @@ fn bullet_drone_collision(
                         game_assets.explosion_standard.clone()
                     };
 
-                    commands.spawn((
-                        AudioPlayer(explosion_sound),
-                        PlaybackSettings {
-                            mode: bevy::audio::PlaybackMode::Despawn,
-                            volume: bevy::audio::Volume::new(1.0),
-                            spatial: true,
-                            ..default()
-                        },
-                        Transform::from_translation(drone_transform.translation),
-                    ));
+                    play_sound.send(
+                        audio::PlaySound::new(explosion_sound, audio::AudioBus::Weapons)
+                            .at(drone_transform.translation)
+                            .with_range(EXPLOSION_SOUND_REFERENCE, EXPLOSION_SOUND_RANGE),
+                    );
 
                     // Spawn explosion visual effect
-                    commands.spawn((
-                        Mesh3d(meshes.add(Mesh::from(Sphere { radius: 30.0 }))),
-                        MeshMaterial3d(materials.add(StandardMaterial {
-                            base_color: Color::srgb(1.0, 0.5, 0.0),
-                            emissive: LinearRgba::rgb(5.0, 2.0, 0.0),
-                            ..default()
-                        })),
-                        Transform::from_translation(drone_transform.translation),
-                        ExplosionEffect { lifetime: 0.0, max_lifetime: 1.0 },
-                    ));
+                    effects.send(SpawnEffect::new(EffectType::AirExplosion, drone_transform.translation, 1.0));
 
                     commands.entity(drone_entity).despawn_recursive();
                 }
@@ -3382,10 +3205,65 @@ fn bullet_drone_collision(
     }
 }
 
+/// Build the shared bullet/missile visuals once; shots only clone handles
+fn setup_weapon_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(WeaponAssets {
+        // Half length 1.5m, stretched per shot by spawn_bullet
+        bullet_mesh: meshes.add(Capsule3d::new(0.12, 1.5)),
+        // FIX F: Reduced emissive for new bloom settings (200/150→15/12)
+        // Subtle warm yellow glow, doesn't compete with tracers
+        bullet_material: materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 1.0, 0.0),
+            emissive: LinearRgba::rgb(15.0, 12.0, 0.0),
+            unlit: true, // CRITICAL: Emissive materials should be unlit to show properly
+            ..default()
+        }),
+        tracer_mesh: meshes.add(Capsule3d::new(0.18, 1.5)),
+        // FIX F: Reduced emissive for new bloom settings (500→40)
+        // With intensity: 0.3 bloom, this creates visible soft red glow (~1-2m halo)
+        // Added green/blue tint (2.0, 2.0) for warmer glow
+        tracer_material: materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.0, 0.0),
+            emissive: LinearRgba::rgb(40.0, 2.0, 2.0),
+            unlit: true,
+            ..default()
+        }),
+        // Elongated cylinder, rotated to point along -Z when spawned
+        missile_body_mesh: meshes.add(Cylinder::new(MISSILE_BODY_RADIUS, MISSILE_LENGTH)),
+        missile_body_material: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.8, 0.8, 0.8), // Light gray
+            metallic: 0.8,
+            perceptual_roughness: 0.2,
+            ..default()
+        }),
+        missile_nose_mesh: meshes.add(Sphere::new(MISSILE_BODY_RADIUS * 1.2)),
+        missile_nose_material: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.8, 0.1, 0.1), // Dark red
+            metallic: 0.5,
+            ..default()
+        }),
+        missile_exhaust_mesh: meshes.add(Sphere::new(MISSILE_BODY_RADIUS * 0.8)),
+        missile_exhaust_material: materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.5, 0.0), // Orange
+            emissive: LinearRgba::rgb(5.0, 2.0, 0.0), // Bright orange glow
+            ..default()
+        }),
+        missile_fin_mesh: meshes.add(Cuboid::new(MISSILE_FIN_SIZE, 0.02, MISSILE_FIN_SIZE)),
+        missile_fin_material: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.3, 0.3, 0.3), // Dark gray
+            metallic: 0.9,
+            ..default()
+        }),
+    });
+}
+
 fn spawn_bullet(
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
+    weapon_assets: &WeaponAssets,
     position: Vec3,
     orientation: Quat, // Plane's orientation - needed for forward offset
     velocity: Vec3,
@@ -3397,31 +3275,12 @@ fn spawn_bullet(
     // At high flight speeds, we need physical width to avoid pixel-thin lines
     // FIX C: Reduced tracer radius 0.25→0.18 (bloom glow provides visual size)
     let radius = if is_tracer { 0.18 } else { 0.12 };
-    let capsule_half_length = 1.5 * length_mult;
-    let mesh = meshes.add(Capsule3d::new(radius, capsule_half_length));
-    
-    let (base_color, emissive) = if is_tracer {
-        eprintln!("🔴 CREATING RED TRACER MATERIAL");
-        // FIX F: Reduced emissive for new bloom settings (500→40)
-        // With intensity: 0.3 bloom, this creates visible soft red glow (~1-2m halo)
-        // Added green/blue tint (2.0, 2.0) for warmer glow
-        (Color::srgb(1.0, 0.0, 0.0), LinearRgba::rgb(40.0, 2.0, 2.0))
+    let (mesh, material) = if is_tracer {
+        (weapon_assets.tracer_mesh.clone(), weapon_assets.tracer_material.clone())
     } else {
-        eprintln!("🟡 Creating yellow bullet material");
-        // FIX F: Reduced emissive for new bloom settings (200/150→15/12)
-        // Subtle warm yellow glow, doesn't compete with tracers
-        (Color::srgb(1.0, 1.0, 0.0), LinearRgba::rgb(15.0, 12.0, 0.0))
+        (weapon_assets.bullet_mesh.clone(), weapon_assets.bullet_material.clone())
     };
 
-    eprintln!("   Material: base_color={:?}, emissive={:?}", base_color, emissive);
-
-    let material = materials.add(StandardMaterial {
-        base_color,
-        emissive,
-        unlit: true, // CRITICAL: Emissive materials should be unlit to show properly
-        ..default()
-    });
-
     // Capsule3d is Y-axis aligned by default — rotate Y axis to point along velocity
     // (Previous "fix" used Vec3::NEG_Z which made bullets fly sideways — now corrected)
     let rotation = if velocity.length_squared() > 0.001 {
@@ -3491,67 +3350,39 @@ fn spawn_bullet(
         LinearVelocity(velocity),
         Collider::capsule(radius, 1.5),
         GravityScale(0.0),
-        Mesh3d(mesh),
-        MeshMaterial3d(material),
-    ));
+    ))
+    .with_children(|parent| {
+        // Streak length varies per shot; stretch the shared capsule on a child so
+        // the collider keeps its size
+        parent.spawn((
+            Mesh3d(mesh),
+            MeshMaterial3d(material),
+            Transform::from_scale(Vec3::new(1.0, length_mult, 1.0)),
+        ));
+    });
 }
 
 fn spawn_hit_spark(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
+    particles: &mut EventWriter<EmitParticles>,
     position: Vec3,
 ) {
     let mut rng = rand::thread_rng();
-    
-    // Spawn 8-12 tiny debris sparks for a "shattering" effect
-    for _ in 0..rng.gen_range(8..13) {
-        let length = rng.gen_range(0.2..1.5);
-        // Using a thin capsule for sparks to look like motion-blurred debris
-        let mesh = meshes.add(Capsule3d::new(0.03, length));
-        let material = materials.add(StandardMaterial {
-            base_color: Color::srgb(1.0, 0.6, 0.1),
-            emissive: LinearRgba::rgb(40.0, 15.0, 0.0),
-            ..default()
-        });
-
-        // Give sparks a random "pop" velocity
-        let velocity = Vec3::new(
-            rng.gen_range(-40.0..40.0),
-            rng.gen_range(-40.0..40.0),
-            rng.gen_range(-40.0..40.0)
-        );
-
-        // SAFETY: Handle near-zero velocity to prevent NaN rotation
-        let rotation = if velocity.length_squared() > 0.0001 {
-            Quat::from_rotation_arc(Vec3::Z, velocity.normalize())
-        } else {
-            Quat::IDENTITY
-        };
 
-        commands.spawn((
-            VisualDebris { 
-                velocity,
-                lifetime: rng.gen_range(0.2..0.6) 
-            }, 
-            Transform::from_translation(position)
-                .with_rotation(rotation),
-            GlobalTransform::default(),
-            Visibility::default(),
-            InheritedVisibility::default(),
-            Mesh3d(mesh),
-            MeshMaterial3d(material),
-        ));
-    }
+    // 8-12 motion-blurred streaks popping out in every direction for a "shattering" effect
+    particles.send(
+        EmitParticles::new(ParticleType::BulletImpact, position)
+            .with_velocity(Vec3::ZERO, 40.0)
+            .with_count(rng.gen_range(8..13)),
+    );
 }
 
 fn drone_projectile_collision(
     mut commands: Commands,
     projectiles: Query<(Entity, &Transform), With<Projectile>>,
     mut drones: Query<(Entity, &mut Drone, &Transform)>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
     game_assets: Res<GameAssets>,
+    mut play_sound: EventWriter<audio::PlaySound>,
+    mut effects: EventWriter<SpawnEffect>,
 ) {
     // Collision detection loop
     for (proj_entity, proj_transform) in &projectiles {
@@ -3582,30 +3413,15 @@ fn drone_projectile_collision(
                         game_assets.explosion_standard.clone() // 70% Chance: Standard Snap
                     };
 
-                    // Play 3D Explosion Sound
-                    commands.spawn((
-                        AudioPlayer(explosion_sound),
-                        PlaybackSettings {
-                            mode: bevy::audio::PlaybackMode::Despawn,
-                            volume: bevy::audio::Volume::new(1.0),
-                            spatial: true, // Enable 3D audio
-                            ..default()
-                        },
-                        Transform::from_translation(drone_transform.translation),
-                    ));
+                    // Play 3D Explosion Sound (arrives late from far away)
+                    play_sound.send(
+                        audio::PlaySound::new(explosion_sound, audio::AudioBus::Weapons)
+                            .at(drone_transform.translation)
+                            .with_range(EXPLOSION_SOUND_REFERENCE, EXPLOSION_SOUND_RANGE),
+                    );
 
                     // Spawn explosion visual effect
-                    commands.spawn((
-                        Mesh3d(meshes.add(Mesh::from(Sphere { radius: 30.0 }))),
-                        MeshMaterial3d(materials.add(StandardMaterial {
-                            base_color: Color::srgb(1.0, 0.5, 0.0),  // Orange
-                            emissive: LinearRgba::rgb(5.0, 2.0, 0.0),  // Glow
-                            ..default()
-                        })),
-                        Transform::from_translation(drone_transform.translation),
-                        // Add explosion effect component to auto-despawn
-                        ExplosionEffect { lifetime: 0.0, max_lifetime: 1.0 },
-                    ));
+                    effects.send(SpawnEffect::new(EffectType::AirExplosion, drone_transform.translation, 1.0));
 
                     // Despawn the drone
                     commands.entity(drone_entity).despawn();
@@ -3618,12 +3434,12 @@ fn drone_projectile_collision(
 fn drone_player_collision(
     mut commands: Commands,
     drone_query: Query<(Entity, &Transform), With<Drone>>,
-    player_query: Query<&Transform, With<PlayerPlane>>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut player_query: Query<(&Transform, &mut engine_audio::EngineSpool), With<PlayerPlane>>,
     sounds: Res<GameAssets>,
+    mut play_sound: EventWriter<audio::PlaySound>,
+    mut effects: EventWriter<SpawnEffect>,
 ) {
-    let Ok(player_transform) = player_query.get_single() else { return };
+    let Ok((player_transform, mut engine)) = player_query.get_single_mut() else { return };
     let player_pos = player_transform.translation;
 
     for (drone_entity, drone_transform) in &drone_query {
@@ -3633,17 +3449,15 @@ fn drone_player_collision(
             println!("💥 KAMIKAZE HIT! Drone exploded on player!");
             
             // Spawn explosion at collision point
-            spawn_huge_explosion(&mut commands, &mut meshes, &mut materials, drone_transform.translation);
+            effects.send(SpawnEffect::new(EffectType::AirExplosion, drone_transform.translation, 1.5));
+            engine.fire = engine_audio::ENGINE_FIRE_TIME; // Shrapnel in the engine bay
 
             // Play explosion sound
-            commands.spawn((
-                AudioPlayer(sounds.explosion.clone()),
-                PlaybackSettings {
-                    mode: bevy::audio::PlaybackMode::Despawn,
-                    volume: bevy::audio::Volume::new(1.0),
-                    ..default()
-                },
-            ));
+            play_sound.send(
+                audio::PlaySound::new(sounds.explosion.clone(), audio::AudioBus::Weapons)
+                    .at(drone_transform.translation)
+                    .with_range(EXPLOSION_SOUND_REFERENCE, EXPLOSION_SOUND_RANGE),
+            );
 
             // Despawn drone
             commands.entity(drone_entity).despawn_recursive();
@@ -3657,9 +3471,9 @@ fn handle_projectile_collisions(
     ground_query: Query<Entity, (With<Collider>, Without<Projectile>, Without<PlayerPlane>, Without<Objective>)>,
     objective_query: Query<(Entity, &Transform), With<Objective>>,
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
     sounds: Res<GameAssets>,
+    mut play_sound: EventWriter<audio::PlaySound>,
+    mut effects: EventWriter<SpawnEffect>,
 ) {
     for Collision(contacts) in collision_events.read() {
         let projectile_entity = if projectile_query.contains(contacts.entity1) {
@@ -3693,23 +3507,56 @@ fn handle_projectile_collisions(
                     commands.entity(target_entity).despawn_recursive();
                     commands.entity(bullet).despawn_recursive();
                     
-                    spawn_huge_explosion(&mut commands, &mut meshes, &mut materials, explosion_pos);
+                    effects.send(SpawnEffect::new(EffectType::GroundExplosion, explosion_pos, 1.0));
 
                     // Play explosion sound
-                    commands.spawn((
-                        AudioPlayer(sounds.explosion.clone()),
-                        PlaybackSettings {
-                            mode: bevy::audio::PlaybackMode::Despawn,
-                            volume: bevy::audio::Volume::new(1.0),
-                            ..default()
-                        },
-                    ));
+                    play_sound.send(
+                        audio::PlaySound::new(sounds.explosion.clone(), audio::AudioBus::Weapons)
+                            .at(explosion_pos)
+                            .with_range(EXPLOSION_SOUND_REFERENCE, EXPLOSION_SOUND_RANGE),
+                    );
                 }
             }
         }
     }
 }
 
+/// Drone missiles and drone gun rounds
+type DroneOrdnanceQuery<'w, 's> = Query<'w, 's, (), Or<(With<drone::Missile>, With<drone::Bullet>)>>;
+
+/// Despawn gun rounds and drone ordnance that strike the terrain heightfield.
+/// Player missiles are handled in `handle_projectile_collisions`; this covers the rest.
+fn handle_terrain_impacts(
+    mut collision_events: EventReader<Collision>,
+    terrain_query: Query<(), With<Terrain>>,
+    bullet_query: Query<&Transform, With<Bullet>>,
+    drone_ordnance_query: DroneOrdnanceQuery,
+    mut commands: Commands,
+    mut particles: EventWriter<EmitParticles>,
+) {
+    for Collision(contacts) in collision_events.read() {
+        let other_entity = if terrain_query.contains(contacts.entity1) {
+            contacts.entity2
+        } else if terrain_query.contains(contacts.entity2) {
+            contacts.entity1
+        } else {
+            continue;
+        };
+
+        // Check if the round still exists (prevent B0003 warning)
+        if commands.get_entity(other_entity).is_none() {
+            continue;
+        }
+
+        if let Ok(bullet_transform) = bullet_query.get(other_entity) {
+            spawn_hit_spark(&mut particles, bullet_transform.translation);
+            commands.entity(other_entity).despawn_recursive();
+        } else if drone_ordnance_query.contains(other_entity) {
+            commands.entity(other_entity).despawn_recursive();
+        }
+    }
+}
+
 fn update_muzzle_flashes(
     time: Res<Time>,
     mut commands: Commands,
@@ -3725,8 +3572,7 @@ fn update_muzzle_flashes(
 
 fn spawn_muzzle_flash(
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
+    particles: &mut EventWriter<EmitParticles>,
     position: Vec3,
     parent: Option<Entity>,
 ) {
@@ -3746,63 +3592,26 @@ fn spawn_muzzle_flash(
         InheritedVisibility::default(),
     )).id();
 
-    // 2. Visual "Flash" (Increased for visibility)
-    let mesh = meshes.add(Sphere::new(0.25)); 
-    let material = materials.add(StandardMaterial {
-        base_color: Color::srgb(1.0, 0.9, 0.5),
-        emissive: LinearRgba::rgb(150.0, 100.0, 30.0), // High intensity
-        ..default()
-    });
-
-    let mesh_entity = commands.spawn((
-        MuzzleFlash { lifetime: 0.0 }, // Re-use MuzzleFlash component for cleanup
-        Mesh3d(mesh),
-        MeshMaterial3d(material),
-        Transform::from_translation(position).with_scale(Vec3::splat(1.0)), 
-        GlobalTransform::default(),
-        Visibility::default(),
-        InheritedVisibility::default(),
-    )).id();
+    // 2. Visual "Flash" from the particle pool
+    let flash = EmitParticles::new(ParticleType::MuzzleFlash, position);
 
     // If a parent is provided (the jet), attach the flash so it moves with the jet
     if let Some(p) = parent {
         commands.entity(p).add_child(light_entity);
-        commands.entity(p).add_child(mesh_entity);
+        particles.send(flash.attached_to(p));
+    } else {
+        particles.send(flash);
     }
 }
 
 /// Spawn a realistic-looking missile (AIM-120 style)
 fn spawn_missile(
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
+    weapon_assets: &WeaponAssets,
     position: Vec3,
     orientation: Quat,
     velocity: Vec3,
 ) -> Entity {
-    // Create missile body (elongated cylinder pointing along -Z)
-    let body_mesh = meshes.add(Cylinder::new(MISSILE_BODY_RADIUS, MISSILE_LENGTH));
-    let body_material = materials.add(StandardMaterial {
-        base_color: Color::srgb(0.8, 0.8, 0.8), // Light gray
-        metallic: 0.8,
-        perceptual_roughness: 0.2,
-        ..default()
-    });
-
-    // Nose cone material (red tip)
-    let nose_material = materials.add(StandardMaterial {
-        base_color: Color::srgb(0.8, 0.1, 0.1), // Dark red
-        metallic: 0.5,
-        ..default()
-    });
-
-    // Exhaust glow material
-    let exhaust_material = materials.add(StandardMaterial {
-        base_color: Color::srgb(1.0, 0.5, 0.0), // Orange
-        emissive: LinearRgba::rgb(5.0, 2.0, 0.0), // Bright orange glow
-        ..default()
-    });
-
     // Spawn missile parent entity with physics
     commands.spawn((
         Projectile { lifetime: BULLET_LIFETIME },
@@ -3819,8 +3628,8 @@ fn spawn_missile(
     .with_children(|parent| {
         // Missile body (rotated 90° because Bevy's Cylinder is Y-axis aligned)
         parent.spawn((
-            Mesh3d(body_mesh),
-            MeshMaterial3d(body_material),
+            Mesh3d(weapon_assets.missile_body_mesh.clone()),
+            MeshMaterial3d(weapon_assets.missile_body_material.clone()),
             Transform::from_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
             GlobalTransform::default(),
             Visibility::default(),
@@ -3829,8 +3638,8 @@ fn spawn_missile(
 
         // Nose cone (small sphere at front)
         parent.spawn((
-            Mesh3d(meshes.add(Sphere::new(MISSILE_BODY_RADIUS * 1.2))),
-            MeshMaterial3d(nose_material.clone()),
+            Mesh3d(weapon_assets.missile_nose_mesh.clone()),
+            MeshMaterial3d(weapon_assets.missile_nose_material.clone()),
             Transform::from_xyz(0.0, 0.0, -MISSILE_LENGTH * 0.6),
             GlobalTransform::default(),
             Visibility::default(),
@@ -3839,23 +3648,15 @@ fn spawn_missile(
 
         // Exhaust glow (small sphere at back)
         parent.spawn((
-            Mesh3d(meshes.add(Sphere::new(MISSILE_BODY_RADIUS * 0.8))),
-            MeshMaterial3d(exhaust_material),
+            Mesh3d(weapon_assets.missile_exhaust_mesh.clone()),
+            MeshMaterial3d(weapon_assets.missile_exhaust_material.clone()),
             Transform::from_xyz(0.0, 0.0, MISSILE_LENGTH * 0.6),
             GlobalTransform::default(),
             Visibility::default(),
             InheritedVisibility::default(),
         ));
 
-        // Add 4 fins (small boxes around the body)
-        let fin_mesh = meshes.add(Cuboid::new(MISSILE_FIN_SIZE, 0.02, MISSILE_FIN_SIZE));
-        let fin_material = materials.add(StandardMaterial {
-            base_color: Color::srgb(0.3, 0.3, 0.3), // Dark gray
-            metallic: 0.9,
-            ..default()
-        });
-
-        // Fins at 90° intervals
+        // Add 4 fins (small boxes around the body) at 90° intervals
         for i in 0..4 {
             let angle = i as f32 * std::f32::consts::FRAC_PI_2;
             let offset = Vec3::new(
@@ -3865,8 +3666,8 @@ fn spawn_missile(
             );
             
             parent.spawn((
-                Mesh3d(fin_mesh.clone()),
-                MeshMaterial3d(fin_material.clone()),
+                Mesh3d(weapon_assets.missile_fin_mesh.clone()),
+                MeshMaterial3d(weapon_assets.missile_fin_material.clone()),
                 Transform::from_translation(offset),
                 GlobalTransform::default(),
                 Visibility::default(),
@@ -3876,195 +3677,42 @@ fn spawn_missile(
     })
     .id()
 }
-/// Component for explosion effects that despawn after a time
-#[derive(Component)]
-struct ExplosionEffect {
-    lifetime: f32,
-    max_lifetime: f32,
-}
 
-/// Check for ground collision and create explosion effect.
+/// Safety resets. Water impacts are handled by `water::water_impact` and touching
+/// land (wheels, belly or a crash) by `landing_gear::wheel_contact`, both right after this.
 /// Resets rotation and angular velocity on respawn to avoid physics AABB panic (invalid bounds).
 fn check_ground_collision(
-    mut commands: Commands,
     mut player_query: Query<(
-        Entity,
         &mut Transform,
         &mut LinearVelocity,
         &mut AngularVelocity,
+        &mut landing_gear::LandingGear,
     ), With<PlayerPlane>>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    sounds: Res<GameAssets>,
+    origin: Res<origin::WorldOrigin>,
+    mission_start: Res<airfield::MissionStart>,
 ) {
-    const SOFT_CEILING: f32 = 0.5; // Don't let physics see us below this (avoids AABB edge cases)
+    let spawn = airfield::PlayerSpawn::new(*mission_start);
 
-    for (_entity, mut transform, mut velocity, mut ang_vel) in &mut player_query {
+    for (mut transform, mut velocity, mut ang_vel, mut gear) in &mut player_query {
         // SAFETY FIX: Check for NaN values that crash avian3d physics
         if transform.translation.is_nan() || transform.translation.x.is_nan() || transform.translation.y.is_nan() || transform.translation.z.is_nan()
             || velocity.x.is_nan() || velocity.y.is_nan() || velocity.z.is_nan() {
             eprintln!("⚠️ SAFETY: Detected NaN in player transform/velocity! Resetting to safe position.");
-            transform.translation = Vec3::new(0.0, 500.0, 0.0);
-            *velocity = LinearVelocity::ZERO;
-            *ang_vel = AngularVelocity::ZERO;
+            spawn.place(&origin, &mut transform, &mut velocity, &mut ang_vel, &mut gear);
             continue;
         }
 
-        // SAFETY FIX: Check for Extreme Values (Dark Bar Glitch)
-        if transform.translation.y > 100_000.0 || transform.translation.y < -1000.0 {
+        // SAFETY FIX: Check for Extreme Values (Dark Bar Glitch / escaped the planet)
+        if transform.translation.y > space::ALTITUDE_LIMIT || transform.translation.y < -1000.0 {
              eprintln!("⚠️ SAFETY: Detected Extreme Y Position! Resetting.");
-             transform.translation = Vec3::new(0.0, 500.0, 0.0);
-             *velocity = LinearVelocity::ZERO;
-             *ang_vel = AngularVelocity::ZERO;
-             continue;
-        }
-
-        // Calculate dynamic terrain height at player position
-        let terrain_height = get_terrain_height(transform.translation.x, transform.translation.z);
-        // Collision threshold is terrain height + safety margin (need clearance above ground)
-        const CRASH_MARGIN: f32 = 5.0; // Reduced from 50.0 to fix mid-air collisions
-        let ground_level = terrain_height + CRASH_MARGIN;
-
-        if transform.translation.y <= ground_level {
-            let crash_speed = velocity.length();
-
-            if crash_speed > 50.0 {
-                println!("💥 MASSIVE EXPLOSION! Speed: {:.0} m/s", crash_speed);
-                spawn_huge_explosion(&mut commands, &mut meshes, &mut materials, transform.translation);
-
-                // Play crash sound
-                commands.spawn((
-                    AudioPlayer(sounds.crash.clone()),
-                    PlaybackSettings {
-                        mode: bevy::audio::PlaybackMode::Despawn,
-                        volume: bevy::audio::Volume::new(1.0),
-                        ..default()
-                    },
-                ));
-
-                // Full reset so physics never sees invalid rotation (avoids AABB panic)
-                transform.translation = Vec3::new(0.0, 500.0, 0.0);
-                transform.rotation = Quat::IDENTITY;
-                *velocity = LinearVelocity(Vec3::new(0.0, 0.0, -200.0));
-                *ang_vel = AngularVelocity::default();
-            } else {
-                // Soft landing: keep above ground and zero downward velocity
-                transform.translation.y = ground_level + SOFT_CEILING;
-                velocity.0.y = velocity.0.y.max(0.0);
-
-                // FIX: Reset rotation if plane is severely tilted (prevents stuck on side)
-                // Check roll angle - if more than 45° tilted, reset to upright
-                let (pitch, yaw, roll) = transform.rotation.to_euler(bevy::math::EulerRot::XYZ);
-                if roll.abs() > 0.785 || pitch.abs() > 1.57 {  // 45° roll or 90° pitch
-                    eprintln!("⚠️ SOFT LANDING: Resetting rotation (roll={:.1}°, pitch={:.1}°)",
-                        roll.to_degrees(), pitch.to_degrees());
-
-                    // Reset to level flight orientation
-                    transform.rotation = Quat::IDENTITY;
-
-                    // Give small forward velocity to help unstick
-                    *velocity = LinearVelocity(Vec3::new(0.0, 0.0, -50.0));
-                    *ang_vel = AngularVelocity::ZERO;
-                }
-            }
-        } else if transform.translation.y < ground_level + SOFT_CEILING {
-            // Clamp just above ground so we never penetrate (helps collision/physics stability)
-            transform.translation.y = ground_level + SOFT_CEILING;
-        }
-    }
-}
-
-/// Spawn a huge explosion with particles and light
-fn spawn_huge_explosion(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-    position: Vec3,
-) {
-    let fireball_mesh = meshes.add(Sphere::new(15.0));
-    let fireball_material = materials.add(StandardMaterial {
-        base_color: Color::srgb(1.0, 0.3, 0.0),
-        emissive: LinearRgba::rgb(10.0, 3.0, 0.0),
-        ..default()
-    });
-
-    commands.spawn((
-        Mesh3d(fireball_mesh),
-        MeshMaterial3d(fireball_material),
-        Transform::from_translation(position + Vec3::Y * 10.0),
-        GlobalTransform::default(),
-        Visibility::default(),
-        InheritedVisibility::default(),
-        ExplosionEffect { lifetime: 0.0, max_lifetime: 2.0 },
-    ));
-
-    commands.spawn((
-        PointLight {
-            intensity: 50000.0,
-            color: Color::srgb(1.0, 0.6, 0.2),
-            range: 200.0,
-            shadows_enabled: true,
-            ..default()
-        },
-        Transform::from_translation(position + Vec3::Y * 10.0),
-        GlobalTransform::default(),
-        Visibility::default(),
-        InheritedVisibility::default(),
-        ExplosionEffect { lifetime: 0.0, max_lifetime: 1.0 },
-    ));
-
-    for i in 0..20 {
-        let angle = (i as f32 / 20.0) * std::f32::consts::TAU;
-        let speed = 50.0 + (i as f32 * 5.0);
-        let debris_velocity = Vec3::new(
-            angle.cos() * speed,
-            30.0 + (i as f32 * 2.0),
-            angle.sin() * speed,
-        );
-
-        let debris_mesh = meshes.add(Sphere::new(0.5));
-        let debris_material = materials.add(StandardMaterial {
-            base_color: Color::srgb(0.3, 0.3, 0.3),
-            metallic: 0.8,
-            ..default()
-        });
-
-        commands.spawn((
-            Mesh3d(debris_mesh),
-            MeshMaterial3d(debris_material),
-            Transform::from_translation(position),
-            GlobalTransform::default(),
-            Visibility::default(),
-            InheritedVisibility::default(),
-            RigidBody::Dynamic,
-            Collider::sphere(0.5), // FIX: Add collider to dynamic rigid body
-            LinearVelocity(debris_velocity),
-            GravityScale(1.0),
-            ExplosionEffect { lifetime: 0.0, max_lifetime: 5.0 },
-        ));
-    }
-}
-
-/// Update and despawn explosion effects
-fn update_explosion_effects(
-    time: Res<Time>,
-    mut commands: Commands,
-    mut query: Query<(Entity, &mut ExplosionEffect)>,
-) {
-    for (entity, mut effect) in &mut query {
-        effect.lifetime += time.delta_secs();
-        if effect.lifetime >= effect.max_lifetime {
-            commands.entity(entity).despawn_recursive();
+             spawn.place(&origin, &mut transform, &mut velocity, &mut ang_vel, &mut gear);
         }
     }
 }
 
 fn spawn_afterburner_particles(
-    mut commands: Commands,
     time: Res<Time>,
-    asset_server: Res<AssetServer>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particles: EventWriter<EmitParticles>,
     player_query: Query<(&Transform, &PlayerInput, &LinearVelocity), With<PlayerPlane>>,
     mut emitter_query: Query<&mut AfterburnerParticles, With<PlayerPlane>>,
 ) {
@@ -4093,8 +3741,9 @@ fn spawn_afterburner_particles(
     let distance_count = (distance / 0.5) as usize;
     let total_to_spawn = (base_count + distance_count).clamp(1, 50);
 
-    let smoke_handle = asset_server.load("textures/clouds/FX_CloudAlpha01.png"); 
-    
+    // Blue afterburner plume above 90% throttle, orange dry exhaust below
+    let particle_type = if input.throttle > 0.9 { ParticleType::Afterburner } else { ParticleType::Exhaust };
+
     for i in 0..total_to_spawn {
         let t = i as f32 / total_to_spawn as f32;
         let spawn_pos = last_pos.lerp(current_spawn_pos, t);
@@ -4108,118 +3757,12 @@ fn spawn_afterburner_particles(
         );
         let velocity = inherited_velocity + engine_blast + jitter;
 
-        let is_afterburner = input.throttle > 0.9;
-        
-        let (start_color, end_color, start_scale, end_scale, emissive) = if is_afterburner {
-            (
-                LinearRgba::rgb(0.2, 0.8, 1.0),
-                LinearRgba::rgb(0.3, 0.3, 0.35),
-                1.5,
-                8.0,
-                LinearRgba::rgb(10.0, 40.0, 100.0)
-            )
-        } else {
-            (
-                LinearRgba::rgb(0.8, 0.4, 0.1),
-                LinearRgba::rgb(0.2, 0.2, 0.2),
-                0.8,
-                5.0,
-                LinearRgba::rgb(4.0, 1.0, 0.0)
-            )
-        };
-
-        let material = materials.add(StandardMaterial {
-            base_color_texture: Some(smoke_handle.clone()),
-            base_color: Color::from(start_color),
-            emissive: emissive,
-            alpha_mode: AlphaMode::Blend,
-            unlit: true,
-            ..default()
-        });
-
-        let mesh = meshes.add(Mesh::from(Rectangle::new(1.0, 1.0)));
-
-        commands.spawn((
-            Particle {
-                lifetime_remaining: emitter.particle_lifetime,
-                lifetime_max: emitter.particle_lifetime,
-                velocity,
-                start_scale,
-                end_scale,
-                start_color,
-                end_color,
-                base_emissive: emissive,
-                p_type: ParticleType::Exhaust,
-            },
-            Transform {
-                translation: spawn_pos,
-                scale: Vec3::splat(start_scale),
-                ..default()
-            },
-            GlobalTransform::default(),
-            Visibility::default(),
-            InheritedVisibility::default(),
-            MeshMaterial3d(material),
-            Mesh3d(mesh),
-        ));
+        particles.send(EmitParticles::new(particle_type, spawn_pos).with_velocity(velocity, 0.0));
     }
 
     emitter.last_spawn_pos = Some(current_spawn_pos);
 }
 
-fn update_visual_debris(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut query: Query<(Entity, &mut Transform, &mut VisualDebris)>,
-) {
-    let delta = time.delta_secs();
-    for (entity, mut transform, mut debris) in &mut query {
-        transform.translation += debris.velocity * delta;
-        debris.lifetime -= delta;
-        if debris.lifetime <= 0.0 {
-            commands.entity(entity).despawn_recursive();
-        }
-    }
-}
-
-fn update_particles(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut particle_query: Query<(Entity, &mut Transform, &mut Particle, &mut MeshMaterial3d<StandardMaterial>)>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    camera_query: Query<&Transform, (With<Camera3d>, Without<Particle>)>,
-) {
-    let camera_transform = camera_query.get_single().ok();
-    let dt = time.delta_secs();
-
-    for (entity, mut transform, mut particle, material_handle) in &mut particle_query {
-        particle.lifetime_remaining -= dt;
-        if particle.lifetime_remaining <= 0.0 || transform.translation.is_nan() {
-            commands.entity(entity).despawn();
-            continue;
-        }
-
-        let age_t = (1.0 - (particle.lifetime_remaining / particle.lifetime_max)).clamp(0.0, 1.0);
-        let buoyancy = Vec3::Y * 4.0 * age_t; 
-        transform.translation += (particle.velocity + buoyancy) * dt;
-
-        let current_scale = particle.start_scale + (particle.end_scale - particle.start_scale) * age_t;
-        transform.scale = Vec3::splat(current_scale);
-
-        if let Some(material) = materials.get_mut(&material_handle.0) {
-            let current_color = VectorSpace::lerp(particle.start_color, particle.end_color, age_t);
-            let alpha = if age_t > 0.8 { (1.0 - age_t) / 0.2 } else { 1.0 };
-            material.base_color = Color::from(current_color).with_alpha(alpha);
-            let emissive_fade = (1.0 - (age_t * 2.0)).max(0.0);
-            material.emissive = particle.base_emissive * emissive_fade;
-        }
-
-        if let Some(cam_transform) = camera_transform {
-            transform.look_at(cam_transform.translation, Vec3::Y);
-        }
-    }
-}
-
 fn debug_tree_hierarchy(
     query: Query<(Entity, &Children), With<Tree>>,
 ) {
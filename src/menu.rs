@@ -0,0 +1,653 @@
+use std::{fmt::Write as _, path::Path};
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use crate::{
+    airfield::MissionStart,
+    assets::GameAssets,
+    controls::RebindMenu,
+    landing_gear::Landed,
+    graphics::{GraphicsQuality, GraphicsSettings, GRAPHICS_PATH, SHADOW_MAP_SIZES},
+    replay,
+    settings::{Difficulty, Settings, SETTINGS_PATH},
+    time_of_day::TimeOfDay,
+    weather::{Weather, WeatherPreset},
+    GameState, RestartRequested,
+};
+
+// ============================================================================
+// MISSIONS
+// ============================================================================
+
+/// Starting conditions picked from the mission select screen
+pub struct Mission {
+    pub name: &'static str,
+    pub briefing: &'static str,
+    /// Local time at takeoff (0..24)
+    pub hour: f32,
+    pub weather: WeatherPreset,
+    /// On the home runway or already airborne
+    pub start: MissionStart,
+}
+
+pub const MISSIONS: [Mission; 4] = [
+    Mission {
+        name: "Free Flight",
+        briefing: "Open skies over the valley. Drones patrol the hills - hunt them at your own pace.",
+        hour: 14.0,
+        weather: WeatherPreset::Scattered,
+        start: MissionStart::Runway,
+    },
+    Mission {
+        name: "Dawn Patrol",
+        briefing: "Sun on the horizon and unlimited visibility. Sweep the canyons before the swarm wakes up.",
+        hour: 6.0,
+        weather: WeatherPreset::Clear,
+        start: MissionStart::Runway,
+    },
+    Mission {
+        name: "Storm Front",
+        briefing: "Low ceiling, heavy rain and gusts. Fly the instruments and watch the ridgelines.",
+        hour: 17.0,
+        weather: WeatherPreset::Storm,
+        start: MissionStart::Airborne,
+    },
+    Mission {
+        name: "Night Intercept",
+        briefing: "Moonlight only. Find the drones by their lights and their tracers.",
+        hour: 23.0,
+        weather: WeatherPreset::Clear,
+        start: MissionStart::Airborne,
+    },
+];
+
+// ============================================================================
+// MENU STATE
+// ============================================================================
+
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+enum MenuScreen {
+    #[default]
+    Title,
+    Missions,
+    Settings,
+    Pause,
+    Debrief,
+}
+
+/// Adjustable line of the settings screen (Left/Right to change)
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SettingRow {
+    DisplayMode,
+    VSync,
+    GraphicsQuality,
+    Shadows,
+    ShadowResolution,
+    ViewDistance,
+    Vegetation,
+    SkyDebris,
+    Bloom,
+    ParticleDensity,
+    ExplosionShadows,
+    MasterVolume,
+    EngineVolume,
+    WeaponsVolume,
+    AmbientVolume,
+    VoiceVolume,
+    MouseAimSensitivity,
+    AutoGcas,
+    Difficulty,
+}
+
+impl SettingRow {
+    const ALL: [SettingRow; 19] = [
+        SettingRow::DisplayMode,
+        SettingRow::VSync,
+        SettingRow::GraphicsQuality,
+        SettingRow::Shadows,
+        SettingRow::ShadowResolution,
+        SettingRow::ViewDistance,
+        SettingRow::Vegetation,
+        SettingRow::SkyDebris,
+        SettingRow::Bloom,
+        SettingRow::ParticleDensity,
+        SettingRow::ExplosionShadows,
+        SettingRow::MasterVolume,
+        SettingRow::EngineVolume,
+        SettingRow::WeaponsVolume,
+        SettingRow::AmbientVolume,
+        SettingRow::VoiceVolume,
+        SettingRow::MouseAimSensitivity,
+        SettingRow::AutoGcas,
+        SettingRow::Difficulty,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            SettingRow::DisplayMode => "Display Mode",
+            SettingRow::VSync => "VSync",
+            SettingRow::GraphicsQuality => "Graphics Quality",
+            SettingRow::Shadows => "  Shadows",
+            SettingRow::ShadowResolution => "  Shadow Resolution",
+            SettingRow::ViewDistance => "  View Distance",
+            SettingRow::Vegetation => "  Vegetation",
+            SettingRow::SkyDebris => "  Sky Debris",
+            SettingRow::Bloom => "  Bloom",
+            SettingRow::ParticleDensity => "  Particles",
+            SettingRow::ExplosionShadows => "  Explosion Shadows",
+            SettingRow::MasterVolume => "Master Volume",
+            SettingRow::EngineVolume => "Engine Volume",
+            SettingRow::WeaponsVolume => "Weapons Volume",
+            SettingRow::AmbientVolume => "Ambient Volume",
+            SettingRow::VoiceVolume => "Voice Volume",
+            SettingRow::MouseAimSensitivity => "Mouse Aim Sensitivity",
+            SettingRow::AutoGcas => "Auto-GCAS",
+            SettingRow::Difficulty => "Difficulty",
+        }
+    }
+
+    /// Lives in `GraphicsSettings` (and config/graphics.cfg) rather than `Settings`
+    fn is_graphics(self) -> bool {
+        matches!(
+            self,
+            SettingRow::GraphicsQuality
+                | SettingRow::Shadows
+                | SettingRow::ShadowResolution
+                | SettingRow::ViewDistance
+                | SettingRow::Vegetation
+                | SettingRow::SkyDebris
+                | SettingRow::Bloom
+                | SettingRow::ParticleDensity
+                | SettingRow::ExplosionShadows
+        )
+    }
+
+    fn value(self, settings: &Settings, graphics: &GraphicsSettings) -> String {
+        let on_off = |on: bool| if on { "On" } else { "Off" }.to_string();
+        match self {
+            SettingRow::DisplayMode => if settings.fullscreen { "Fullscreen" } else { "Windowed" }.to_string(),
+            SettingRow::VSync => on_off(settings.vsync),
+            SettingRow::GraphicsQuality => format!(
+                "{}{}",
+                graphics.quality.label(),
+                if graphics.is_custom() { " (custom)" } else { "" },
+            ),
+            SettingRow::Shadows => on_off(graphics.shadows),
+            SettingRow::ShadowResolution => format!("{}px", graphics.shadow_map_size),
+            SettingRow::ViewDistance => format!("{} km", graphics.load_radius_chunks),
+            SettingRow::Vegetation => format!("{}-{} trees/km²", graphics.trees_per_chunk_min, graphics.trees_per_chunk_max),
+            SettingRow::SkyDebris => format!("{} meteors/km²", graphics.meteors_per_chunk),
+            SettingRow::Bloom => on_off(graphics.bloom),
+            SettingRow::ParticleDensity => format!("{:.0}%", graphics.particle_density * 100.0),
+            SettingRow::ExplosionShadows => on_off(graphics.effect_light_shadows),
+            SettingRow::MasterVolume => format!("{:.0}%", settings.master_volume * 100.0),
+            SettingRow::EngineVolume => format!("{:.0}%", settings.engine_volume * 100.0),
+            SettingRow::WeaponsVolume => format!("{:.0}%", settings.weapons_volume * 100.0),
+            SettingRow::AmbientVolume => format!("{:.0}%", settings.ambient_volume * 100.0),
+            SettingRow::VoiceVolume => format!("{:.0}%", settings.voice_volume * 100.0),
+            SettingRow::MouseAimSensitivity => format!("{:.2}x", settings.mouse_aim_sensitivity),
+            SettingRow::AutoGcas => on_off(settings.auto_gcas),
+            SettingRow::Difficulty => format!(
+                "{} ({} drones, {} enemy missiles)",
+                settings.difficulty.label(),
+                settings.difficulty.swarm_size(),
+                settings.difficulty.max_enemy_missiles(),
+            ),
+        }
+    }
+
+    /// Step a player setting one notch up (+1) or down (-1)
+    fn adjust(self, settings: &mut Settings, step: i32) {
+        let volume = |v: f32| (v + step as f32 * 0.1).clamp(0.0, 1.0);
+        match self {
+            SettingRow::DisplayMode => settings.fullscreen = !settings.fullscreen,
+            SettingRow::VSync => settings.vsync = !settings.vsync,
+            SettingRow::MasterVolume => settings.master_volume = volume(settings.master_volume),
+            SettingRow::EngineVolume => settings.engine_volume = volume(settings.engine_volume),
+            SettingRow::WeaponsVolume => settings.weapons_volume = volume(settings.weapons_volume),
+            SettingRow::AmbientVolume => settings.ambient_volume = volume(settings.ambient_volume),
+            SettingRow::VoiceVolume => settings.voice_volume = volume(settings.voice_volume),
+            SettingRow::MouseAimSensitivity => {
+                settings.mouse_aim_sensitivity = (settings.mouse_aim_sensitivity + step as f32 * 0.25).clamp(0.25, 3.0);
+            }
+            SettingRow::AutoGcas => settings.auto_gcas = !settings.auto_gcas,
+            SettingRow::Difficulty => settings.difficulty = cycle(&Difficulty::ALL, settings.difficulty, step),
+            _ => {}
+        }
+    }
+
+    /// Step a graphics option; picking a preset throws away the overrides
+    fn adjust_graphics(self, graphics: &mut GraphicsSettings, step: i32) {
+        match self {
+            SettingRow::GraphicsQuality => {
+                *graphics = GraphicsSettings::preset(cycle(&GraphicsQuality::ALL, graphics.quality, step));
+            }
+            SettingRow::Shadows => graphics.shadows = !graphics.shadows,
+            SettingRow::ShadowResolution => {
+                graphics.shadow_map_size = cycle_levels(&SHADOW_MAP_SIZES, graphics.shadow_map_size, step);
+            }
+            SettingRow::ViewDistance => {
+                // Keep the preset's gap between loading and unloading
+                let margin = graphics.unload_radius_chunks - graphics.load_radius_chunks;
+                graphics.load_radius_chunks += step;
+                graphics.sanitize();
+                graphics.unload_radius_chunks = graphics.load_radius_chunks + margin;
+            }
+            SettingRow::Vegetation => {
+                let levels = [15, 40, 60, 80, 120];
+                let max = cycle_levels(&levels, graphics.trees_per_chunk_max, step);
+                graphics.trees_per_chunk_max = max;
+                graphics.trees_per_chunk_min = max / 8;
+            }
+            SettingRow::SkyDebris => {
+                graphics.meteors_per_chunk = cycle_levels(&[0, 8, 20, 30, 40], graphics.meteors_per_chunk, step);
+            }
+            SettingRow::Bloom => graphics.bloom = !graphics.bloom,
+            SettingRow::ParticleDensity => {
+                graphics.particle_density = (graphics.particle_density + step as f32 * 0.1).clamp(0.1, 2.0);
+            }
+            SettingRow::ExplosionShadows => graphics.effect_light_shadows = !graphics.effect_light_shadows,
+            _ => {}
+        }
+        graphics.sanitize();
+    }
+}
+
+/// Next/previous entry of `options` after `current` (the first one if it isn't listed)
+fn cycle<T: Copy + PartialEq>(options: &[T], current: T, step: i32) -> T {
+    let count = options.len() as i32;
+    match options.iter().position(|&option| option == current) {
+        Some(index) => options[(index as i32 + step).rem_euclid(count) as usize],
+        None => options[0],
+    }
+}
+
+/// `cycle` over ascending levels, where a hand-edited value in between steps to
+/// its neighbour in that direction instead of jumping back to the first level
+fn cycle_levels<T: Copy + PartialOrd>(levels: &[T], current: T, step: i32) -> T {
+    if levels.contains(&current) {
+        return cycle(levels, current, step);
+    }
+    let next = if step > 0 {
+        levels.iter().find(|&&level| level > current).or(levels.first())
+    } else {
+        levels.iter().rev().find(|&&level| level < current).or(levels.last())
+    };
+    next.copied().unwrap_or(current)
+}
+
+/// One selectable line on a menu screen
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MenuItem {
+    StartMission,
+    Mission(usize),
+    OpenSettings,
+    Setting(SettingRow),
+    RebindControls,
+    Resume,
+    KeepFlying,
+    Restart,
+    Back,
+    Quit,
+}
+
+impl MenuItem {
+    fn label(self) -> &'static str {
+        match self {
+            MenuItem::StartMission => "Start Mission",
+            MenuItem::Mission(index) => MISSIONS[index].name,
+            MenuItem::OpenSettings => "Settings",
+            MenuItem::Setting(row) => row.label(),
+            MenuItem::RebindControls => "Rebind Controls (F1)",
+            MenuItem::Resume => "Resume",
+            MenuItem::KeepFlying => "Keep Flying",
+            MenuItem::Restart => "Restart Mission",
+            MenuItem::Back => "Back",
+            MenuItem::Quit => "Quit to Desktop",
+        }
+    }
+}
+
+impl MenuScreen {
+    fn items(self) -> Vec<MenuItem> {
+        match self {
+            MenuScreen::Title => vec![MenuItem::StartMission, MenuItem::OpenSettings, MenuItem::Quit],
+            MenuScreen::Missions => (0..MISSIONS.len()).map(MenuItem::Mission).chain([MenuItem::Back]).collect(),
+            MenuScreen::Settings => SettingRow::ALL.into_iter()
+                .map(MenuItem::Setting)
+                .chain([MenuItem::RebindControls, MenuItem::Back])
+                .collect(),
+            MenuScreen::Pause => vec![MenuItem::Resume, MenuItem::Restart, MenuItem::OpenSettings, MenuItem::Quit],
+            MenuScreen::Debrief => vec![MenuItem::Restart, MenuItem::KeepFlying, MenuItem::Quit],
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            MenuScreen::Title => "MAIN MENU",
+            MenuScreen::Missions => "SELECT MISSION",
+            MenuScreen::Settings => "SETTINGS",
+            MenuScreen::Pause => "PAUSED",
+            MenuScreen::Debrief => "MISSION COMPLETE",
+        }
+    }
+}
+
+/// Which screen is showing and what is highlighted on it
+#[derive(Resource, Default)]
+struct Menu {
+    screen: MenuScreen,
+    selected: usize,
+    message: String,
+    /// Index into `MISSIONS` of the mission being flown, for "Restart Mission"
+    mission: usize,
+    /// Landing report waiting for the next pause, which then opens on the debrief
+    debrief: String,
+}
+
+impl Menu {
+    fn show(&mut self, screen: MenuScreen) {
+        self.screen = screen;
+        self.selected = 0;
+        self.message.clear();
+    }
+}
+
+/// Everything spawned for the main or pause menu, despawned when it closes
+#[derive(Component)]
+struct MenuRoot;
+
+/// Draws the main menu while no flight camera exists yet
+#[derive(Component)]
+struct MenuCamera;
+
+#[derive(Component)]
+struct MenuText;
+
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Menu>()
+            .add_systems(OnEnter(GameState::MainMenu), (skip_menu_for_replay, spawn_main_menu).chain())
+            .add_systems(OnExit(GameState::MainMenu), despawn_menu)
+            .add_systems(OnEnter(GameState::Paused), (pause_physics, spawn_pause_menu))
+            .add_systems(OnExit(GameState::Paused), (resume_physics, despawn_menu))
+            .add_systems(Update, (
+                // After the rebinding menu so the key that closes it doesn't also navigate here
+                menu_input.after(crate::controls::rebind_menu_input),
+                update_menu_text,
+            ).chain().run_if(in_state(GameState::MainMenu).or(in_state(GameState::Paused))))
+            .add_systems(Update, end_mission_on_landing.run_if(in_state(GameState::Playing)));
+    }
+}
+
+// ============================================================================
+// SETUP
+// ============================================================================
+
+/// `--replay <file>` goes straight to the viewer, which needs a world to stand in
+fn skip_menu_for_replay(mut next_state: ResMut<NextState<GameState>>) {
+    if replay::requested_on_command_line().is_some() {
+        println!("🎬 MENU: Replay requested on the command line - skipping the main menu");
+        next_state.set(GameState::Spawning);
+    }
+}
+
+fn spawn_main_menu(
+    mut commands: Commands,
+    game_assets: Res<GameAssets>,
+    mut menu: ResMut<Menu>,
+) {
+    menu.show(MenuScreen::Title);
+    commands.spawn((MenuCamera, MenuRoot, Camera2d));
+    spawn_menu_panel(&mut commands, Some(game_assets.logo.clone()), Color::srgba(0.02, 0.03, 0.05, 1.0));
+}
+
+fn spawn_pause_menu(mut commands: Commands, mut menu: ResMut<Menu>) {
+    let debrief = std::mem::take(&mut menu.debrief);
+    if debrief.is_empty() {
+        menu.show(MenuScreen::Pause);
+    } else {
+        menu.show(MenuScreen::Debrief);
+        menu.message = debrief;
+    }
+    spawn_menu_panel(&mut commands, None, Color::srgba(0.0, 0.0, 0.0, 0.6));
+}
+
+/// Full-screen backdrop with the (optional) logo above the menu listing
+fn spawn_menu_panel(commands: &mut Commands, logo: Option<Handle<Image>>, backdrop: Color) {
+    commands.spawn((
+        MenuRoot,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            row_gap: Val::Px(24.0),
+            ..default()
+        },
+        BackgroundColor(backdrop),
+        GlobalZIndex(5), // Under the F1 rebinding overlay
+    ))
+    .with_children(|parent| {
+        if let Some(logo) = logo {
+            parent.spawn((
+                ImageNode::new(logo),
+                Node {
+                    width: Val::Px(360.0),
+                    ..default()
+                },
+            ));
+        }
+        parent.spawn((
+            MenuText,
+            Text::new(""),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.0, 1.0, 0.0)), // HUD Green
+            Node {
+                padding: UiRect::all(Val::Px(16.0)),
+                min_width: Val::Px(480.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        ));
+    });
+}
+
+fn despawn_menu(mut commands: Commands, roots: Query<Entity, With<MenuRoot>>) {
+    for entity in &roots {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn pause_physics(mut physics_time: ResMut<Time<Physics>>) {
+    physics_time.pause();
+    println!("⏸️  PAUSED - Physics Frozen");
+}
+
+fn resume_physics(mut physics_time: ResMut<Time<Physics>>) {
+    physics_time.unpause();
+    println!("▶️  RESUMED - Physics Active");
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// Up/Down (D-pad) select, Enter (A) activates, Left/Right change a setting,
+/// Esc (B) goes back. Settings are saved as soon as they change.
+#[allow(clippy::too_many_arguments)]
+fn menu_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut menu: ResMut<Menu>,
+    mut rebind_menu: ResMut<RebindMenu>,
+    mut settings: ResMut<Settings>,
+    mut graphics: ResMut<GraphicsSettings>,
+    mut restart: EventWriter<RestartRequested>,
+    mut exit: EventWriter<AppExit>,
+) {
+    // The rebinding overlay has the keyboard while it is up (or just went down)
+    if rebind_menu.is_open() || rebind_menu.is_changed() {
+        return;
+    }
+    let pressed = |key: KeyCode, button: GamepadButton| {
+        keyboard.just_pressed(key) || gamepads.iter().any(|gamepad| gamepad.just_pressed(button))
+    };
+
+    let items = menu.screen.items();
+    if pressed(KeyCode::ArrowUp, GamepadButton::DPadUp) {
+        menu.selected = (menu.selected + items.len() - 1) % items.len();
+    }
+    if pressed(KeyCode::ArrowDown, GamepadButton::DPadDown) {
+        menu.selected = (menu.selected + 1) % items.len();
+    }
+    let item = items[menu.selected.min(items.len() - 1)];
+    let back_to = if *state.get() == GameState::Paused { MenuScreen::Pause } else { MenuScreen::Title };
+
+    if pressed(KeyCode::Escape, GamepadButton::East) {
+        match menu.screen {
+            MenuScreen::Title => {}
+            MenuScreen::Pause | MenuScreen::Debrief => next_state.set(GameState::Playing),
+            MenuScreen::Missions | MenuScreen::Settings => menu.show(back_to),
+        }
+        return;
+    }
+
+    if let MenuItem::Setting(row) = item {
+        let step = if pressed(KeyCode::ArrowLeft, GamepadButton::DPadLeft) {
+            -1
+        } else if pressed(KeyCode::ArrowRight, GamepadButton::DPadRight) || pressed(KeyCode::Enter, GamepadButton::South) {
+            1
+        } else {
+            0
+        };
+        if step != 0 {
+            // Only touch the resource that changes, so the other isn't re-applied
+            let (path, saved) = if row.is_graphics() {
+                row.adjust_graphics(&mut graphics, step);
+                (GRAPHICS_PATH, graphics.save(Path::new(GRAPHICS_PATH)))
+            } else {
+                row.adjust(&mut settings, step);
+                (SETTINGS_PATH, settings.save(Path::new(SETTINGS_PATH)))
+            };
+            menu.message = match saved {
+                Ok(()) if row == SettingRow::Difficulty => "Drone swarm size applies from the next mission start or restart".to_string(),
+                Ok(()) if matches!(row, SettingRow::Vegetation | SettingRow::SkyDebris) => {
+                    "Loaded terrain is rebuilt when you resume".to_string()
+                }
+                Ok(()) => String::new(),
+                Err(err) => {
+                    eprintln!("⚠️  SETTINGS: Could not save {}: {}", path, err);
+                    format!("Could not save {}: {}", path, err)
+                }
+            };
+        }
+        return;
+    }
+
+    if !pressed(KeyCode::Enter, GamepadButton::South) {
+        return;
+    }
+    match item {
+        MenuItem::StartMission => menu.show(MenuScreen::Missions),
+        MenuItem::Mission(index) => {
+            let mission = &MISSIONS[index];
+            println!("🛫 MISSION: {} ({:02.0}:00, {:?}, {})", mission.name, mission.hour, mission.weather, mission.start.label());
+            commands.insert_resource(TimeOfDay::new(mission.hour, 1.0));
+            commands.insert_resource(Weather::preset(mission.weather));
+            commands.insert_resource(mission.start);
+            menu.mission = index;
+            next_state.set(GameState::Spawning);
+        }
+        MenuItem::OpenSettings => menu.show(MenuScreen::Settings),
+        MenuItem::Setting(_) => {}
+        MenuItem::RebindControls => rebind_menu.open(),
+        MenuItem::Resume | MenuItem::KeepFlying => next_state.set(GameState::Playing),
+        MenuItem::Restart => {
+            let mission = &MISSIONS[menu.mission];
+            commands.insert_resource(TimeOfDay::new(mission.hour, 1.0));
+            commands.insert_resource(Weather::preset(mission.weather));
+            restart.send(RestartRequested);
+            next_state.set(GameState::Playing);
+        }
+        MenuItem::Back => menu.show(back_to),
+        MenuItem::Quit => {
+            exit.send(AppExit::Success);
+        }
+    }
+}
+
+/// A full stop on a runway ends the mission: pause on a debrief of the touchdown
+fn end_mission_on_landing(
+    mut landed: EventReader<Landed>,
+    mut menu: ResMut<Menu>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(landing) = landed.read().last() else { return };
+    let touchdown = landing.touchdown;
+    let mut debrief = format!(
+        "{} - full stop at {}\n{} touchdown: {:.1} m/s sink at {:.0} m/s, crab {:.0}°",
+        MISSIONS[menu.mission].name, landing.airfield.name(), touchdown.grade.label(),
+        touchdown.sink_rate, touchdown.speed, touchdown.crab.to_degrees(),
+    );
+    match touchdown.runway {
+        Some((_, misalignment, offset)) => {
+            let _ = write!(debrief, "\nLined up {:.0}° off the runway heading, {:.0} m off the centerline",
+                misalignment.to_degrees(), offset.abs());
+        }
+        None => debrief.push_str("\nTouched down short of (or beside) the runway"),
+    }
+    menu.debrief = debrief;
+    next_state.set(GameState::Paused);
+}
+
+fn update_menu_text(
+    menu: Res<Menu>,
+    settings: Res<Settings>,
+    graphics: Res<GraphicsSettings>,
+    mut text_query: Query<&mut Text, With<MenuText>>,
+) {
+    if !menu.is_changed() && !settings.is_changed() && !graphics.is_changed() {
+        return;
+    }
+    let Ok(mut text) = text_query.get_single_mut() else { return };
+
+    let mut listing = format!("{}\n\n", menu.screen.title());
+    for (index, item) in menu.screen.items().into_iter().enumerate() {
+        let marker = if index == menu.selected { ">" } else { " " };
+        match item {
+            MenuItem::Setting(row) => {
+                let _ = writeln!(listing, "{} {:<22} < {} >", marker, row.label(), row.value(&settings, &graphics));
+            }
+            _ => {
+                let _ = writeln!(listing, "{} {}", marker, item.label());
+            }
+        }
+    }
+
+    if let Some(MenuItem::Mission(index)) = menu.screen.items().get(menu.selected) {
+        let mission = &MISSIONS[*index];
+        let _ = write!(listing, "\n{:02.0}:00 - {:?} - {}\n{}\n", mission.hour, mission.weather, mission.start.label(), mission.briefing);
+    }
+    listing.push_str(match menu.screen {
+        MenuScreen::Title => "\nUp/Down select, Enter confirm",
+        MenuScreen::Settings => "\nLeft/Right change, Esc back",
+        MenuScreen::Pause => "\nEnter select, Esc resume",
+        MenuScreen::Debrief => "\nEnter select, Esc keep flying",
+        MenuScreen::Missions => "\nEnter fly, Esc back",
+    });
+    if !menu.message.is_empty() {
+        let _ = write!(listing, "\n{}", menu.message);
+    }
+    text.0 = listing;
+}
@@ -0,0 +1,276 @@
+use avian3d::prelude::*;
+use bevy::{
+    input::mouse::MouseMotion,
+    prelude::*,
+    window::{CursorGrabMode, PrimaryWindow},
+};
+use crate::{
+    biome::smoothstep,
+    camera::{CameraRig, CameraView},
+    controls::{Action, ActionState, FlightAxis},
+    settings::Settings,
+    GameState, PlayerInput, PlayerPlane,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Radians the aim direction turns per pixel of mouse motion
+const AIM_SENSITIVITY: f32 = 0.0025;
+/// Keeps the aim off the poles, where yaw stops making sense
+const AIM_PITCH_LIMIT: f32 = 1.45;
+/// How far out the reticle and nose marker are drawn (meters)
+const RETICLE_DISTANCE: f32 = 1500.0;
+const RETICLE_SIZE: f32 = 28.0;
+const NOSE_MARKER_SIZE: f32 = 8.0;
+
+/// Instructor gains: stick deflection per radian of error, minus per rad/s of rate
+const PITCH_GAIN: f32 = 4.0;
+const PITCH_DAMPING: f32 = 0.5;
+const ROLL_GAIN: f32 = 2.5;
+const ROLL_DAMPING: f32 = 0.35;
+const YAW_GAIN: f32 = 3.0;
+const YAW_DAMPING: f32 = 0.5;
+/// Off-boresight angles (radians) between which the instructor goes from
+/// rudder-and-wings-level tracking to banking into the turn
+const BANK_BLEND_START: f32 = 0.05;
+const BANK_BLEND_FULL: f32 = 0.3;
+/// Targets this far below the nose are reached by pushing instead of rolling inverted
+const PUSH_LIMIT: f32 = 0.6;
+/// Share of a push the instructor is willing to use (pilots hate negative G)
+const PUSH_AUTHORITY: f32 = 0.5;
+
+// ============================================================================
+// RESOURCES & COMPONENTS
+// ============================================================================
+
+/// War Thunder style mouse aim: the mouse swings a direction in world space and
+/// an instructor flies the jet toward it by writing `PlayerInput`
+#[derive(Resource)]
+pub struct MouseAim {
+    pub enabled: bool,
+    /// Where the player wants the nose (unit vector, world space)
+    pub direction: Vec3,
+    yaw: f32,
+    pitch: f32,
+}
+
+impl Default for MouseAim {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            direction: Vec3::NEG_Z,
+            yaw: 0.0,
+            pitch: 0.0,
+        }
+    }
+}
+
+impl MouseAim {
+    /// Aim where the jet is pointing right now
+    fn align_with(&mut self, forward: Vec3) {
+        self.yaw = (-forward.x).atan2(-forward.z);
+        self.pitch = forward.y.clamp(-1.0, 1.0).asin().clamp(-AIM_PITCH_LIMIT, AIM_PITCH_LIMIT);
+        self.update_direction();
+    }
+
+    fn update_direction(&mut self) {
+        self.direction = Quat::from_euler(EulerRot::YXZ, self.yaw, self.pitch, 0.0) * Vec3::NEG_Z;
+    }
+
+    /// Orientation looking down the aim direction, horizon level (for the chase camera)
+    pub fn rotation(&self) -> Quat {
+        Quat::from_euler(EulerRot::YXZ, self.yaw, self.pitch, 0.0)
+    }
+}
+
+/// Circle showing where the mouse is aiming
+#[derive(Component)]
+struct AimReticle;
+
+/// Small square showing where the nose actually points
+#[derive(Component)]
+struct NoseMarker;
+
+pub struct MouseAimPlugin;
+
+impl Plugin for MouseAimPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MouseAim>()
+            .add_systems(OnEnter(GameState::Spawning), setup_reticle)
+            .add_systems(Update, (
+                toggle_mouse_aim,
+                steer_mouse_aim,
+                // Between reading the controls and flying them, like a second pilot
+                mouse_aim_instructor
+                    .after(crate::read_player_input)
+                    .before(crate::arcade_flight_physics),
+            ).chain().run_if(in_state(GameState::Playing)))
+            .add_systems(Update, (update_reticle, grab_cursor));
+    }
+}
+
+fn setup_reticle(mut commands: Commands) {
+    commands.spawn((
+        AimReticle,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(RETICLE_SIZE),
+            height: Val::Px(RETICLE_SIZE),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BorderColor(Color::srgb(0.0, 1.0, 0.0)), // HUD Green
+        BorderRadius::MAX,
+        Visibility::Hidden,
+    ));
+    commands.spawn((
+        NoseMarker,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(NOSE_MARKER_SIZE),
+            height: Val::Px(NOSE_MARKER_SIZE),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.0, 1.0, 0.0)),
+        Visibility::Hidden,
+    ));
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+fn toggle_mouse_aim(
+    actions: Res<ActionState>,
+    mut aim: ResMut<MouseAim>,
+    player_query: Query<&Transform, With<PlayerPlane>>,
+) {
+    if !actions.just_pressed(Action::ToggleMouseAim) {
+        return;
+    }
+    aim.enabled = !aim.enabled;
+    if let Ok(transform) = player_query.get_single() {
+        aim.align_with(transform.forward().as_vec3());
+    }
+    println!("🎯 MOUSE AIM: {}", if aim.enabled { "ON" } else { "OFF" });
+}
+
+/// The mouse swings the aim, except in views where it already turns the camera
+/// (the instructor then holds the last aim)
+fn steer_mouse_aim(
+    rig: Res<CameraRig>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut aim: ResMut<MouseAim>,
+    settings: Res<Settings>,
+) {
+    let motion: Vec2 = mouse_motion.read().map(|m| m.delta).sum();
+    if !aim.enabled || matches!(rig.view, CameraView::Cockpit | CameraView::Orbit) || motion == Vec2::ZERO {
+        return;
+    }
+    let sensitivity = AIM_SENSITIVITY * settings.mouse_aim_sensitivity;
+    aim.yaw -= motion.x * sensitivity;
+    aim.pitch = (aim.pitch - motion.y * sensitivity).clamp(-AIM_PITCH_LIMIT, AIM_PITCH_LIMIT);
+    aim.update_direction();
+}
+
+/// Fly toward the aim: roll the lift vector onto it and pull, then level the
+/// wings and track with rudder and elevator once it is close to the nose.
+/// Any axis the player is flying by hand is left alone.
+pub fn mouse_aim_instructor(
+    aim: Res<MouseAim>,
+    actions: Res<ActionState>,
+    mut player_query: Query<(&mut PlayerInput, &Transform, &AngularVelocity), With<PlayerPlane>>,
+) {
+    if !aim.enabled {
+        return;
+    }
+    let Ok((mut input, transform, ang_vel)) = player_query.get_single_mut() else { return };
+
+    // Everything in the jet's frame: -Z nose, +Y canopy, +X right wing
+    let to_aim = transform.rotation.inverse() * aim.direction;
+    let rates = transform.rotation.inverse() * ang_vel.0;
+    let (pitch_rate, yaw_rate, roll_rate) = (rates.x, rates.y, -rates.z);
+    if !to_aim.is_finite() || !rates.is_finite() {
+        return;
+    }
+
+    let angle_off = to_aim.angle_between(Vec3::NEG_Z);
+    let pitch_error = to_aim.y.atan2(-to_aim.z);
+    let yaw_error = (-to_aim.x).atan2(-to_aim.z);
+
+    // Far off the nose: bank so the lift vector points at the aim (or, for a
+    // shallow dive, so pushing gets there). Close in: wings level.
+    let pushing = to_aim.y < 0.0 && pitch_error > -PUSH_LIMIT;
+    let bank_error = if pushing { -to_aim.x.atan2(-to_aim.y) } else { to_aim.x.atan2(to_aim.y) };
+    let level_error = transform.right().y.clamp(-1.0, 1.0).asin();
+    let bank_weight = smoothstep(BANK_BLEND_START, BANK_BLEND_FULL, angle_off);
+    let roll_error = bank_error * bank_weight + level_error * (1.0 - bank_weight);
+
+    let pitch_authority = if pitch_error < 0.0 { PUSH_AUTHORITY } else { 1.0 };
+    let pitch = ((PITCH_GAIN * pitch_error - PITCH_DAMPING * pitch_rate) * pitch_authority).clamp(-1.0, 1.0);
+    let roll = (ROLL_GAIN * roll_error - ROLL_DAMPING * roll_rate).clamp(-1.0, 1.0);
+    let yaw = ((YAW_GAIN * yaw_error - YAW_DAMPING * yaw_rate) * (1.0 - bank_weight)).clamp(-1.0, 1.0);
+
+    if actions.axis(FlightAxis::Pitch).is_none() && actions.digital_axis(Action::PitchUp, Action::PitchDown) == 0.0 {
+        input.pitch = pitch;
+    }
+    if actions.axis(FlightAxis::Roll).is_none() && actions.digital_axis(Action::RollRight, Action::RollLeft) == 0.0 {
+        input.roll = roll;
+    }
+    if actions.axis(FlightAxis::Yaw).is_none() && actions.digital_axis(Action::YawLeft, Action::YawRight) == 0.0 {
+        input.yaw = yaw;
+    }
+}
+
+/// A screen marker's node, excluding the other marker `W` so both can be borrowed mutably
+type MarkerQuery<'w, 's, M, W> = Query<'w, 's, (&'static mut Node, &'static mut Visibility), (With<M>, Without<W>)>;
+
+/// Project the aim and the nose onto the screen
+fn update_reticle(
+    state: Res<State<GameState>>,
+    aim: Res<MouseAim>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    player_query: Query<&Transform, With<PlayerPlane>>,
+    mut reticle_query: MarkerQuery<AimReticle, NoseMarker>,
+    mut nose_query: MarkerQuery<NoseMarker, AimReticle>,
+) {
+    let active = aim.enabled && *state.get() == GameState::Playing;
+    let (Ok((camera, camera_transform)), Ok(player)) = (camera_query.get_single(), player_query.get_single()) else { return };
+
+    let targets = [
+        (reticle_query.get_single_mut(), aim.direction, RETICLE_SIZE),
+        (nose_query.get_single_mut(), player.forward().as_vec3(), NOSE_MARKER_SIZE),
+    ];
+    for (marker, direction, size) in targets {
+        let Ok((mut node, mut visibility)) = marker else { continue };
+        let screen = camera.world_to_viewport(camera_transform, player.translation + direction * RETICLE_DISTANCE);
+        let wanted = match screen {
+            Ok(position) if active => {
+                node.left = Val::Px(position.x - size * 0.5);
+                node.top = Val::Px(position.y - size * 0.5);
+                Visibility::Visible
+            }
+            _ => Visibility::Hidden,
+        };
+        if *visibility != wanted {
+            *visibility = wanted;
+        }
+    }
+}
+
+/// Lock and hide the cursor while the mouse is flying the jet
+fn grab_cursor(
+    state: Res<State<GameState>>,
+    aim: Res<MouseAim>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Ok(mut window) = windows.get_single_mut() else { return };
+    let grab = aim.enabled && *state.get() == GameState::Playing;
+    let mode = if grab { CursorGrabMode::Locked } else { CursorGrabMode::None };
+    if window.cursor_options.grab_mode != mode {
+        window.cursor_options.grab_mode = mode;
+        window.cursor_options.visible = !grab;
+    }
+}
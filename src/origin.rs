@@ -0,0 +1,112 @@
+use bevy::{math::DVec3, prelude::*, transform::TransformSystem};
+use crate::{world_active, PlayerPlane, AfterburnerParticles, CHUNK_SIZE};
+
+// ============================================================================
+// FLOATING ORIGIN
+// ============================================================================
+
+/// Horizontal distance from the origin at which the world is re-centered on the
+/// player. f32 keeps sub-millimeter precision out to here.
+const RECENTER_DISTANCE: f32 = 5.0 * CHUNK_SIZE;
+
+/// Absolute (64-bit) world position of the render/physics origin.
+///
+/// Every `Transform` and avian `Position` is relative to this point, so they stay
+/// small and precise no matter how far the player flies. World generation
+/// (`get_terrain_height`, biomes, `ChunkCoordinate`) works in absolute
+/// coordinates and converts at the boundary with `to_absolute`/`to_local`.
+///
+/// Only X/Z ever shift: `y` stays absolute everywhere, so `translation.y` is
+/// still altitude above the datum for the HUD, weather, clouds and space regime.
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct WorldOrigin {
+    pub offset: DVec3,
+}
+
+impl WorldOrigin {
+    /// Absolute world position of a render-space point
+    pub fn to_absolute(self, local: Vec3) -> DVec3 {
+        self.offset + local.as_dvec3()
+    }
+
+    /// Render-space position of an absolute world point
+    pub fn to_local(self, absolute: DVec3) -> Vec3 {
+        (absolute - self.offset).as_vec3()
+    }
+
+    /// Terrain height under a render-space point
+    pub fn terrain_height_at(&self, local: Vec3) -> f32 {
+        let absolute = self.to_absolute(local);
+        crate::get_terrain_height(absolute.x, absolute.z)
+    }
+}
+
+/// Sent after the world has been shifted; `delta` was subtracted from every
+/// top-level `Transform`. Systems caching render-space positions should apply it.
+#[derive(Event, Clone, Copy)]
+pub struct OriginShifted {
+    pub delta: Vec3,
+}
+
+pub struct FloatingOriginPlugin;
+
+impl Plugin for FloatingOriginPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WorldOrigin>()
+            .add_event::<OriginShifted>()
+            // After gameplay and the camera have moved everything for this frame,
+            // before transforms are propagated for rendering. Avian picks the
+            // shifted transforms up as a teleport at the start of its next step.
+            .add_systems(
+                PostUpdate,
+                (recenter_world, shift_cached_positions)
+                    .chain()
+                    .before(TransformSystem::TransformPropagate)
+                    .run_if(world_active),
+            );
+    }
+}
+
+/// Once the player strays too far, move the origin under them in whole chunks
+/// and shift every root entity the other way
+fn recenter_world(
+    mut origin: ResMut<WorldOrigin>,
+    player_query: Query<&Transform, With<PlayerPlane>>,
+    mut roots: Query<&mut Transform, (Without<Parent>, Without<Node>)>,
+    mut shifted: EventWriter<OriginShifted>,
+) {
+    let Ok(player) = player_query.get_single() else { return };
+    let position = player.translation;
+    if !position.is_finite() || (position.x.abs() < RECENTER_DISTANCE && position.z.abs() < RECENTER_DISTANCE) {
+        return;
+    }
+
+    // Whole chunks keep chunk roots on exact multiples of CHUNK_SIZE
+    let delta = Vec3::new(
+        (position.x / CHUNK_SIZE).round() * CHUNK_SIZE,
+        0.0,
+        (position.z / CHUNK_SIZE).round() * CHUNK_SIZE,
+    );
+    origin.offset += delta.as_dvec3();
+    for mut transform in &mut roots {
+        transform.translation -= delta;
+    }
+
+    println!("🧭 ORIGIN: Re-centered by ({:.0}, {:.0}), world origin now ({:.0}, {:.0})",
+        delta.x, delta.z, origin.offset.x, origin.offset.z);
+    shifted.send(OriginShifted { delta });
+}
+
+/// Render-space positions held outside of transforms
+fn shift_cached_positions(
+    mut shifted: EventReader<OriginShifted>,
+    mut afterburners: Query<&mut AfterburnerParticles>,
+) {
+    for event in shifted.read() {
+        for mut emitter in &mut afterburners {
+            if let Some(last) = emitter.last_spawn_pos.as_mut() {
+                *last -= event.delta;
+            }
+        }
+    }
+}
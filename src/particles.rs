@@ -0,0 +1,641 @@
+use bevy::{pbr::NotShadowCaster, prelude::*};
+use rand::Rng;
+use crate::{graphics::GraphicsSettings, GameState, world_active};
+
+// ============================================================================
+// PARTICLE TYPES
+// ============================================================================
+
+/// Every kind of particle the game emits. Each type has a fixed `ParticleStyle`
+/// (shape, curves, lifetime) and its own pre-built material gradient.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParticleType {
+    Exhaust,     // Fire transitioning to smoke (dry thrust)
+    Afterburner, // Blue-white core fading to grey smoke
+    BulletImpact,
+    MuzzleFlash,
+    TracerEmber, // Lingering red heat behind tracer rounds
+    Explosion,
+    Smoke,
+    Rain,
+    Condensation, // Droplets beading on the canopy inside clouds
+    ReentryPlasma, // Glowing shock layer around a hot airframe
+    RcsPuff,       // Cold-gas jet from an attitude thruster
+}
+
+impl ParticleType {
+    pub const ALL: [ParticleType; 11] = [
+        ParticleType::Exhaust,
+        ParticleType::Afterburner,
+        ParticleType::BulletImpact,
+        ParticleType::MuzzleFlash,
+        ParticleType::TracerEmber,
+        ParticleType::Explosion,
+        ParticleType::Smoke,
+        ParticleType::Rain,
+        ParticleType::Condensation,
+        ParticleType::ReentryPlasma,
+        ParticleType::RcsPuff,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// How a particle is drawn
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ParticleShape {
+    /// Textured quad that always faces the camera
+    Billboard,
+    /// Thin capsule stretched along the velocity (motion-blurred sparks)
+    Streak,
+    /// Small glowing sphere
+    Glow,
+}
+
+impl ParticleShape {
+    const COUNT: usize = 3;
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Pool capacity per shape; when full the oldest particle is recycled
+    fn pool_size(self) -> usize {
+        match self {
+            ParticleShape::Billboard => 2500,
+            ParticleShape::Streak => 1500, // Rain shares this pool with sparks
+            ParticleShape::Glow => 400,
+        }
+    }
+}
+
+/// Keyframes over normalized age (0.0 = birth, 1.0 = death)
+type Keys<T> = &'static [(f32, T)];
+
+struct ParticleStyle {
+    shape: ParticleShape,
+    texture: Option<&'static str>,
+    /// Linear RGBA base color (alpha fades the particle)
+    color: Keys<[f32; 4]>,
+    emissive: Keys<[f32; 3]>,
+    /// Uniform scale (length for streaks), multiplied by the emit `size`
+    scale: Keys<f32>,
+    lifetime: (f32, f32),
+    /// Upward drift that grows with age (hot gas rising)
+    buoyancy: f32,
+    gravity: f32,
+    /// Fraction of velocity lost per second
+    drag: f32,
+}
+
+fn style(particle_type: ParticleType) -> &'static ParticleStyle {
+    const SMOKE_TEXTURE: Option<&str> = Some("textures/clouds/FX_CloudAlpha01.png");
+    const EXPLOSION_TEXTURE: Option<&str> = Some("textures/clouds/FX_CloudAlpha04.png");
+
+    static STYLES: [ParticleStyle; 11] = [
+        // Exhaust
+        ParticleStyle {
+            shape: ParticleShape::Billboard,
+            texture: SMOKE_TEXTURE,
+            color: &[(0.0, [0.8, 0.4, 0.1, 1.0]), (0.8, [0.32, 0.24, 0.18, 1.0]), (1.0, [0.2, 0.2, 0.2, 0.0])],
+            emissive: &[(0.0, [4.0, 1.0, 0.0]), (0.5, [0.0, 0.0, 0.0])],
+            scale: &[(0.0, 0.8), (1.0, 5.0)],
+            lifetime: (1.5, 1.5),
+            buoyancy: 4.0,
+            gravity: 0.0,
+            drag: 0.0,
+        },
+        // Afterburner
+        ParticleStyle {
+            shape: ParticleShape::Billboard,
+            texture: SMOKE_TEXTURE,
+            color: &[(0.0, [0.2, 0.8, 1.0, 1.0]), (0.8, [0.28, 0.4, 0.48, 1.0]), (1.0, [0.3, 0.3, 0.35, 0.0])],
+            emissive: &[(0.0, [10.0, 40.0, 100.0]), (0.5, [0.0, 0.0, 0.0])],
+            scale: &[(0.0, 1.5), (1.0, 8.0)],
+            lifetime: (1.5, 1.5),
+            buoyancy: 4.0,
+            gravity: 0.0,
+            drag: 0.0,
+        },
+        // BulletImpact
+        ParticleStyle {
+            shape: ParticleShape::Streak,
+            texture: None,
+            color: &[(0.0, [1.0, 0.6, 0.1, 1.0]), (1.0, [0.6, 0.2, 0.05, 1.0])],
+            emissive: &[(0.0, [40.0, 15.0, 0.0]), (1.0, [8.0, 2.0, 0.0])],
+            scale: &[(0.0, 1.2), (1.0, 0.4)],
+            lifetime: (0.2, 0.6),
+            buoyancy: 0.0,
+            gravity: 9.81,
+            drag: 0.5,
+        },
+        // MuzzleFlash
+        ParticleStyle {
+            shape: ParticleShape::Glow,
+            texture: None,
+            color: &[(0.0, [1.0, 0.9, 0.5, 1.0]), (1.0, [1.0, 0.7, 0.3, 1.0])],
+            emissive: &[(0.0, [150.0, 100.0, 30.0]), (1.0, [60.0, 30.0, 5.0])],
+            scale: &[(0.0, 0.25), (1.0, 0.35)],
+            lifetime: (0.05, 0.05),
+            buoyancy: 0.0,
+            gravity: 0.0,
+            drag: 0.0,
+        },
+        // TracerEmber
+        ParticleStyle {
+            shape: ParticleShape::Glow,
+            texture: None,
+            color: &[(0.0, [1.0, 0.0, 0.0, 1.0]), (1.0, [0.5, 0.0, 0.0, 1.0])],
+            emissive: &[(0.0, [300.0, 0.0, 0.0]), (1.0, [30.0, 0.0, 0.0])],
+            scale: &[(0.0, 0.12), (1.0, 0.06)],
+            lifetime: (0.2, 0.2),
+            buoyancy: 0.0,
+            gravity: 0.0,
+            drag: 0.0,
+        },
+        // Explosion
+        ParticleStyle {
+            shape: ParticleShape::Billboard,
+            texture: EXPLOSION_TEXTURE,
+            color: &[(0.0, [1.0, 0.55, 0.15, 1.0]), (0.35, [0.6, 0.25, 0.08, 0.9]), (1.0, [0.1, 0.1, 0.1, 0.0])],
+            emissive: &[(0.0, [30.0, 10.0, 1.0]), (0.4, [2.0, 0.5, 0.0]), (0.6, [0.0, 0.0, 0.0])],
+            scale: &[(0.0, 0.4), (0.2, 1.0), (1.0, 1.6)],
+            lifetime: (0.8, 1.6),
+            buoyancy: 6.0,
+            gravity: 0.0,
+            drag: 2.0,
+        },
+        // Smoke
+        ParticleStyle {
+            shape: ParticleShape::Billboard,
+            texture: SMOKE_TEXTURE,
+            color: &[(0.0, [0.15, 0.14, 0.13, 0.0]), (0.1, [0.15, 0.14, 0.13, 0.8]), (1.0, [0.35, 0.35, 0.36, 0.0])],
+            emissive: &[(0.0, [0.0, 0.0, 0.0])],
+            scale: &[(0.0, 0.5), (1.0, 2.5)],
+            lifetime: (3.0, 6.0),
+            buoyancy: 10.0,
+            gravity: 0.0,
+            drag: 0.8,
+        },
+        // Rain: velocity (fall + wind) comes from the emitter, so no gravity here
+        ParticleStyle {
+            shape: ParticleShape::Streak,
+            texture: None,
+            color: &[(0.0, [0.55, 0.6, 0.7, 1.0])],
+            emissive: &[(0.0, [0.05, 0.05, 0.07])],
+            scale: &[(0.0, 2.5)],
+            lifetime: (0.8, 1.2),
+            buoyancy: 0.0,
+            gravity: 0.0,
+            drag: 0.0,
+        },
+        // Condensation: emitted attached to the camera, grows then evaporates
+        ParticleStyle {
+            shape: ParticleShape::Glow,
+            texture: None,
+            color: &[(0.0, [0.8, 0.85, 0.9, 1.0])],
+            emissive: &[(0.0, [0.3, 0.3, 0.35])],
+            scale: &[(0.0, 0.004), (0.6, 0.012), (1.0, 0.0)],
+            lifetime: (1.0, 2.5),
+            buoyancy: 0.0,
+            gravity: 0.0,
+            drag: 0.0,
+        },
+        // ReentryPlasma: short-lived, swept back by the airflow the emitter gives it
+        ParticleStyle {
+            shape: ParticleShape::Billboard,
+            texture: SMOKE_TEXTURE,
+            color: &[(0.0, [1.0, 0.5, 0.3, 0.9]), (0.5, [1.0, 0.3, 0.5, 0.5]), (1.0, [0.6, 0.2, 0.6, 0.0])],
+            emissive: &[(0.0, [60.0, 20.0, 8.0]), (0.5, [25.0, 6.0, 12.0]), (1.0, [0.0, 0.0, 0.0])],
+            scale: &[(0.0, 2.0), (1.0, 6.0)],
+            lifetime: (0.2, 0.4),
+            buoyancy: 0.0,
+            gravity: 0.0,
+            drag: 0.0,
+        },
+        // RcsPuff
+        ParticleStyle {
+            shape: ParticleShape::Billboard,
+            texture: SMOKE_TEXTURE,
+            color: &[(0.0, [0.9, 0.9, 0.95, 0.7]), (1.0, [0.9, 0.9, 0.95, 0.0])],
+            emissive: &[(0.0, [0.5, 0.5, 0.55])],
+            scale: &[(0.0, 0.3), (1.0, 1.5)],
+            lifetime: (0.3, 0.5),
+            buoyancy: 0.0,
+            gravity: 0.0,
+            drag: 1.5,
+        },
+    ];
+
+    &STYLES[particle_type.index()]
+}
+
+/// Number of pre-built materials per type. Particles step through them as they age
+/// instead of mutating a private material, so thousands of particles share a handful.
+const GRADIENT_STEPS: usize = 16;
+
+fn sample_scalar(keys: Keys<f32>, t: f32) -> f32 {
+    sample(keys, t, |a, b, f| a + (b - a) * f)
+}
+
+fn sample<T: Copy>(keys: Keys<T>, t: f32, lerp: impl Fn(T, T, f32) -> T) -> T {
+    let Some(&(first_t, first)) = keys.first() else { unreachable!("curve without keys") };
+    if t <= first_t {
+        return first;
+    }
+    for pair in keys.windows(2) {
+        let (t0, v0) = pair[0];
+        let (t1, v1) = pair[1];
+        if t <= t1 {
+            return lerp(v0, v1, ((t - t0) / (t1 - t0).max(0.0001)).clamp(0.0, 1.0));
+        }
+    }
+    keys[keys.len() - 1].1
+}
+
+fn lerp_array<const N: usize>(a: [f32; N], b: [f32; N], f: f32) -> [f32; N] {
+    std::array::from_fn(|i| a[i] + (b[i] - a[i]) * f)
+}
+
+// ============================================================================
+// EVENTS, COMPONENTS & RESOURCES
+// ============================================================================
+
+/// Request a burst of pooled particles. `count` particles start at `position` with
+/// `velocity` plus a random offset of up to `spread` m/s in every direction.
+#[derive(Event, Clone, Copy)]
+pub struct EmitParticles {
+    pub particle_type: ParticleType,
+    pub position: Vec3,
+    pub velocity: Vec3,
+    pub spread: f32,
+    pub count: u32,
+    /// Multiplier on the style's scale curve
+    pub size: f32,
+    /// Stick to an entity (e.g. muzzle flash on the jet); `position` is then local
+    pub attach_to: Option<Entity>,
+}
+
+impl EmitParticles {
+    pub fn new(particle_type: ParticleType, position: Vec3) -> Self {
+        Self {
+            particle_type,
+            position,
+            velocity: Vec3::ZERO,
+            spread: 0.0,
+            count: 1,
+            size: 1.0,
+            attach_to: None,
+        }
+    }
+
+    pub fn with_velocity(mut self, velocity: Vec3, spread: f32) -> Self {
+        self.velocity = velocity;
+        self.spread = spread;
+        self
+    }
+
+    pub fn with_count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn attached_to(mut self, entity: Entity) -> Self {
+        self.attach_to = Some(entity);
+        self
+    }
+}
+
+/// Continuous source of particles (smoke from a wreck, trails...). Emits `rate`
+/// particles per second from its entity's position while `active`.
+#[derive(Component)]
+pub struct ParticleEmitter {
+    pub particle_type: ParticleType,
+    pub rate: f32,
+    pub velocity: Vec3,
+    pub spread: f32,
+    pub size: f32,
+    pub active: bool,
+    accumulator: f32,
+}
+
+impl ParticleEmitter {
+    pub fn new(particle_type: ParticleType, rate: f32) -> Self {
+        Self {
+            particle_type,
+            rate,
+            velocity: Vec3::ZERO,
+            spread: 0.0,
+            size: 1.0,
+            active: true,
+            accumulator: 0.0,
+        }
+    }
+
+    pub fn with_velocity(mut self, velocity: Vec3, spread: f32) -> Self {
+        self.velocity = velocity;
+        self.spread = spread;
+        self
+    }
+
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+}
+
+/// A pooled particle. Inactive particles stay hidden until the pool hands them out again.
+#[derive(Component)]
+pub struct Particle {
+    particle_type: ParticleType,
+    active: bool,
+    age: f32,
+    lifetime: f32,
+    velocity: Vec3,
+    size: f32,
+    attach_to: Option<(Entity, Vec3)>,
+    gradient_step: usize,
+}
+
+/// Ring buffers of pre-spawned particle entities, one per shape
+#[derive(Resource)]
+struct ParticlePool {
+    rings: [Vec<Entity>; ParticleShape::COUNT],
+    cursors: [usize; ParticleShape::COUNT],
+}
+
+impl ParticlePool {
+    /// Next slot for a shape (oldest first, so a full pool recycles the oldest particle)
+    fn next(&mut self, shape: ParticleShape) -> Option<Entity> {
+        let ring = &self.rings[shape.index()];
+        if ring.is_empty() {
+            return None;
+        }
+        let cursor = &mut self.cursors[shape.index()];
+        let entity = ring[*cursor];
+        *cursor = (*cursor + 1) % ring.len();
+        Some(entity)
+    }
+}
+
+/// Per-type material gradients shared by every pooled particle
+#[derive(Resource)]
+struct ParticleAssets {
+    gradients: Vec<[Handle<StandardMaterial>; GRADIENT_STEPS]>,
+}
+
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<EmitParticles>()
+            .add_systems(OnEnter(GameState::Spawning), setup_particle_pool)
+            .add_systems(Update, (
+                update_particle_emitters,
+                emit_particles,
+                update_particles,
+            ).chain().run_if(world_active));
+    }
+}
+
+// ============================================================================
+// SETUP
+// ============================================================================
+
+fn setup_particle_pool(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let shape_meshes = [
+        meshes.add(Rectangle::new(1.0, 1.0)),
+        meshes.add(Capsule3d::new(0.03, 1.0)), // Y-aligned, rotated onto the velocity
+        meshes.add(Sphere::new(1.0)),
+    ];
+
+    let gradients = ParticleType::ALL
+        .iter()
+        .map(|particle_type| {
+            let style = style(*particle_type);
+            let texture = style.texture.map(|path| asset_server.load(path));
+            std::array::from_fn(|step| {
+                let t = step as f32 / (GRADIENT_STEPS - 1) as f32;
+                let [r, g, b, a] = sample(style.color, t, lerp_array);
+                let [er, eg, eb] = sample(style.emissive, t, lerp_array);
+                let billboard = style.shape == ParticleShape::Billboard;
+                materials.add(StandardMaterial {
+                    base_color: Color::LinearRgba(LinearRgba::new(r, g, b, a)),
+                    base_color_texture: texture.clone(),
+                    emissive: LinearRgba::rgb(er, eg, eb),
+                    alpha_mode: if billboard { AlphaMode::Blend } else { AlphaMode::Opaque },
+                    unlit: billboard,
+                    double_sided: billboard,
+                    cull_mode: if billboard { None } else { Some(bevy::render::render_resource::Face::Back) },
+                    ..default()
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let shapes = [ParticleShape::Billboard, ParticleShape::Streak, ParticleShape::Glow];
+    let rings = shapes.map(|shape| {
+        // Any type of the right shape works as the placeholder until the slot is used
+        let placeholder = ParticleType::ALL
+            .into_iter()
+            .find(|t| style(*t).shape == shape)
+            .unwrap_or(ParticleType::Exhaust);
+
+        (0..shape.pool_size())
+            .map(|_| {
+                commands.spawn((
+                    Particle {
+                        particle_type: placeholder,
+                        active: false,
+                        age: 0.0,
+                        lifetime: 1.0,
+                        velocity: Vec3::ZERO,
+                        size: 1.0,
+                        attach_to: None,
+                        gradient_step: 0,
+                    },
+                    Mesh3d(shape_meshes[shape.index()].clone()),
+                    MeshMaterial3d(gradients[placeholder.index()][0].clone()),
+                    Transform::default(),
+                    Visibility::Hidden,
+                    NotShadowCaster,
+                )).id()
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let total: usize = rings.iter().map(Vec::len).sum();
+    commands.insert_resource(ParticlePool { rings, cursors: [0; ParticleShape::COUNT] });
+    commands.insert_resource(ParticleAssets { gradients });
+    eprintln!("✨ STARTUP: Particle pool ready ({} particles, {} shared materials)", total, ParticleType::ALL.len() * GRADIENT_STEPS);
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+fn update_particle_emitters(
+    time: Res<Time>,
+    mut emitters: Query<(&GlobalTransform, &mut ParticleEmitter)>,
+    mut emit: EventWriter<EmitParticles>,
+) {
+    let dt = time.delta_secs();
+    for (transform, mut emitter) in &mut emitters {
+        if !emitter.active {
+            emitter.accumulator = 0.0;
+            continue;
+        }
+        emitter.accumulator += emitter.rate * dt;
+        let count = emitter.accumulator.floor();
+        if count < 1.0 {
+            continue;
+        }
+        emitter.accumulator -= count;
+        emit.send(
+            EmitParticles::new(emitter.particle_type, transform.translation())
+                .with_velocity(emitter.velocity, emitter.spread)
+                .with_count(count as u32)
+                .with_size(emitter.size),
+        );
+    }
+}
+
+/// Hand out pooled particles for this frame's emit requests
+fn emit_particles(
+    mut events: EventReader<EmitParticles>,
+    mut pool: Option<ResMut<ParticlePool>>,
+    assets: Option<Res<ParticleAssets>>,
+    mut particles: Query<(&mut Particle, &mut Transform, &mut Visibility, &mut MeshMaterial3d<StandardMaterial>)>,
+    graphics: Res<GraphicsSettings>,
+) {
+    let (Some(pool), Some(assets)) = (pool.as_mut(), assets) else {
+        events.clear();
+        return;
+    };
+    let mut rng = rand::thread_rng();
+
+    for event in events.read() {
+        let style = style(event.particle_type);
+        if !event.position.is_finite() || !event.velocity.is_finite() {
+            continue;
+        }
+
+        // Lower presets thin every burst out, but never to nothing
+        let count = (event.count as f32 * graphics.particle_density).ceil() as u32;
+        for _ in 0..count {
+            let Some(entity) = pool.next(style.shape) else { break };
+            let Ok((mut particle, mut transform, mut visibility, mut material)) = particles.get_mut(entity) else { continue };
+
+            let jitter = if event.spread > 0.0 {
+                Vec3::new(
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                ) * event.spread
+            } else {
+                Vec3::ZERO
+            };
+            let (min_life, max_life) = style.lifetime;
+
+            *particle = Particle {
+                particle_type: event.particle_type,
+                active: true,
+                age: 0.0,
+                lifetime: if max_life > min_life { rng.gen_range(min_life..max_life) } else { min_life },
+                velocity: event.velocity + jitter,
+                size: event.size,
+                attach_to: event.attach_to.map(|parent| (parent, event.position)),
+                gradient_step: 0,
+            };
+            *transform = Transform::from_translation(event.position)
+                .with_scale(Vec3::splat(sample_scalar(style.scale, 0.0) * event.size));
+            *visibility = Visibility::Visible;
+            material.0 = assets.gradients[event.particle_type.index()][0].clone();
+        }
+    }
+}
+
+fn update_particles(
+    time: Res<Time>,
+    assets: Option<Res<ParticleAssets>>,
+    mut particles: Query<(&mut Particle, &mut Transform, &mut Visibility, &mut MeshMaterial3d<StandardMaterial>)>,
+    parents: Query<&GlobalTransform, Without<Particle>>,
+    camera_query: Query<&GlobalTransform, With<Camera3d>>,
+) {
+    let Some(assets) = assets else { return };
+    let camera_pos = camera_query.get_single().ok().map(|t| t.translation());
+    let dt = time.delta_secs();
+
+    for (mut particle, mut transform, mut visibility, mut material) in &mut particles {
+        if !particle.active {
+            continue;
+        }
+
+        particle.age += dt;
+        let age_t = particle.age / particle.lifetime;
+        if age_t >= 1.0 || !transform.translation.is_finite() {
+            particle.active = false;
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+
+        let style = style(particle.particle_type);
+
+        // Motion: attached particles ride along with their parent, others integrate
+        if let Some((parent, local_offset)) = particle.attach_to {
+            let Ok(parent_transform) = parents.get(parent) else {
+                particle.active = false;
+                *visibility = Visibility::Hidden;
+                continue;
+            };
+            transform.translation = parent_transform.transform_point(local_offset);
+        } else {
+            let drag = (1.0 - style.drag * dt).max(0.0);
+            particle.velocity *= drag;
+            particle.velocity.y -= style.gravity * dt;
+            let buoyancy = Vec3::Y * style.buoyancy * age_t;
+            transform.translation += (particle.velocity + buoyancy) * dt;
+        }
+
+        // Scale curve
+        let scale = sample_scalar(style.scale, age_t) * particle.size;
+        transform.scale = match style.shape {
+            ParticleShape::Streak => Vec3::new(1.0, scale, 1.0),
+            _ => Vec3::splat(scale),
+        };
+
+        // Color curve: step through the shared gradient
+        let step = ((age_t * (GRADIENT_STEPS - 1) as f32).round() as usize).min(GRADIENT_STEPS - 1);
+        if step != particle.gradient_step {
+            particle.gradient_step = step;
+            material.0 = assets.gradients[particle.particle_type.index()][step].clone();
+        }
+
+        // Orientation
+        match style.shape {
+            ParticleShape::Billboard => {
+                if let Some(cam) = camera_pos {
+                    transform.look_at(cam, Vec3::Y);
+                }
+            }
+            ParticleShape::Streak => {
+                if particle.velocity.length_squared() > 0.0001 {
+                    transform.rotation = Quat::from_rotation_arc(Vec3::Y, particle.velocity.normalize());
+                }
+            }
+            ParticleShape::Glow => {}
+        }
+    }
+}
@@ -59,3 +59,55 @@ pub fn create_grass_texture() -> Image {
 
     image
 }
+
+/// Alpha-cutout tree silhouette for distant foliage impostors.
+/// Brown trunk at the bottom, a noisy green canopy above, transparent elsewhere.
+pub fn create_tree_impostor_texture() -> Image {
+    const WIDTH: usize = 64;
+    const HEIGHT: usize = 128;
+
+    let mut data = Vec::with_capacity(WIDTH * HEIGHT * 4);
+    let mut rng = rand::thread_rng();
+
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            // Normalized coords: u in -1..1 across, v in 0..1 from the ground up
+            let u = (x as f32 / (WIDTH - 1) as f32) * 2.0 - 1.0;
+            let v = 1.0 - y as f32 / (HEIGHT - 1) as f32;
+
+            // Canopy: ellipse centered 60% up, slightly ragged edge
+            let cu = u / 0.9;
+            let cv = (v - 0.6) / 0.4;
+            let edge = 1.0 + rng.gen_range(-0.08..0.08);
+            let in_canopy = cu * cu + cv * cv < edge;
+            // Trunk: thin column from the ground into the canopy
+            let in_trunk = u.abs() < 0.1 && v < 0.35;
+
+            if in_canopy {
+                let intensity = rng.gen_range(0.75..1.1);
+                // Darker toward the bottom of the canopy (self-shadowing)
+                let shade = 0.7 + 0.3 * ((v - 0.2) / 0.8).clamp(0.0, 1.0);
+                data.push((25.0 * intensity * shade) as u8);
+                data.push((110.0 * intensity * shade) as u8);
+                data.push((25.0 * intensity * shade) as u8);
+                data.push(255);
+            } else if in_trunk {
+                data.extend_from_slice(&[80, 55, 30, 255]);
+            } else {
+                data.extend_from_slice(&[0, 0, 0, 0]);
+            }
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: WIDTH as u32,
+            height: HEIGHT as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD | RenderAssetUsages::MAIN_WORLD,
+    )
+}
@@ -0,0 +1,1344 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use avian3d::prelude::*;
+use bevy::{
+    input::mouse::{MouseMotion, MouseWheel},
+    math::DVec3,
+    prelude::*,
+    ui::RelativeCursorPosition,
+};
+use crate::{
+    controls::{Action, ActionState},
+    drone::{self, Drone},
+    effects::{EffectType, SpawnEffect},
+    lod::{LodCategory, LodGroup, LodVariant},
+    origin::WorldOrigin,
+    particles::{EmitParticles, ParticleType},
+    space::RocketEngine,
+    time_of_day::TimeOfDay,
+    GameState, PlayerInput, PlayerPlane, Projectile, WORLD_SEED,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+const REPLAY_DIR: &str = "replays";
+const MAGIC: [u8; 4] = *b"VRPL";
+const FORMAT_VERSION: u16 = 1;
+
+/// Samples per second of session time
+const SAMPLE_RATE: u16 = 20;
+/// Recordings kept in `replays/`; starting a new one deletes the oldest beyond this
+const MAX_RECORDINGS: usize = 20;
+/// How often the recording is flushed to disk; a crash loses at most this much (seconds)
+const FLUSH_INTERVAL: f32 = 1.0;
+/// Slack on top of the distance the jet could cover between two samples before
+/// the jump is logged as a reset (crash restart or NaN recovery), in meters
+const RESET_JUMP_MARGIN: f64 = 200.0;
+
+/// Playback speeds stepped through with `-` / `=`
+const PLAYBACK_SPEEDS: [f32; 7] = [0.125, 0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
+const NORMAL_SPEED_INDEX: usize = 3;
+/// Arrow keys jump this far (seconds)
+const SEEK_STEP: f32 = 5.0;
+
+const FREE_CAMERA_SPEED: f32 = 150.0; // m/s
+const FREE_CAMERA_BOOST: f32 = 8.0;   // Shift multiplier
+const FREE_CAMERA_SENSITIVITY: f32 = 0.003;
+
+// ============================================================================
+// RECORDING FORMAT
+// ============================================================================
+//
+// Little-endian throughout. Header: magic "VRPL", version (u16), world seed (u32),
+// sample rate (u16). Then frames back to back until the end of the file, each:
+//
+//   time, hour of day                                   f32 x2
+//   player position (absolute)                          f64 x3
+//   player rotation                                     i16 x4 (normalized quat)
+//   player velocity, angular velocity                   f32 x3 x2
+//   pitch, roll, yaw, throttle                          f32 x4
+//   flags (bit 0: rocket lit)                           u8
+//   drone count                                         u16
+//     id, position (relative to player), rotation,
+//     velocity                                          u64, f32 x3, i16 x4, f32 x3
+//   event count                                         u8
+//     kind, position (relative to player), size         u8, f32 x3, f32
+//
+// There is no frame count: a session cut short by a crash ends in a partial
+// frame, and everything before it still loads.
+
+/// Something that happened between two samples
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplayEventKind {
+    PlayerMissile,
+    PlayerGun,
+    DroneMissile,
+    DroneGun,
+    AirExplosion,
+    GroundExplosion,
+    AircraftCrash,
+    /// The jet jumped further than it could have flown (restart or NaN reset)
+    PlayerReset,
+}
+
+impl ReplayEventKind {
+    const ALL: [ReplayEventKind; 8] = [
+        ReplayEventKind::PlayerMissile,
+        ReplayEventKind::PlayerGun,
+        ReplayEventKind::DroneMissile,
+        ReplayEventKind::DroneGun,
+        ReplayEventKind::AirExplosion,
+        ReplayEventKind::GroundExplosion,
+        ReplayEventKind::AircraftCrash,
+        ReplayEventKind::PlayerReset,
+    ];
+
+    fn from_effect(effect: EffectType) -> Self {
+        match effect {
+            EffectType::AirExplosion => ReplayEventKind::AirExplosion,
+            EffectType::GroundExplosion => ReplayEventKind::GroundExplosion,
+            EffectType::AircraftCrash => ReplayEventKind::AircraftCrash,
+        }
+    }
+
+    fn effect(self) -> Option<EffectType> {
+        match self {
+            ReplayEventKind::AirExplosion => Some(EffectType::AirExplosion),
+            ReplayEventKind::GroundExplosion => Some(EffectType::GroundExplosion),
+            ReplayEventKind::AircraftCrash => Some(EffectType::AircraftCrash),
+            _ => None,
+        }
+    }
+
+    /// Worth a tick on the timeline
+    fn is_milestone(self) -> bool {
+        self.effect().is_some() || self == ReplayEventKind::PlayerReset
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ReplayEvent {
+    pub kind: ReplayEventKind,
+    /// Absolute world position
+    pub position: DVec3,
+    /// Effect scale (1.0 for weapons and resets)
+    pub size: f32,
+}
+
+/// The jet at one sample, with the exact inputs the flight model saw
+#[derive(Clone, Copy, Debug)]
+pub struct PlayerSample {
+    /// Absolute world position
+    pub position: DVec3,
+    pub rotation: Quat,
+    pub velocity: Vec3,
+    pub angular_velocity: Vec3,
+    pub pitch: f32,
+    pub roll: f32,
+    pub yaw: f32,
+    pub throttle: f32,
+    pub rocket: bool,
+}
+
+impl PlayerSample {
+    fn lerp(&self, next: &PlayerSample, t: f32) -> PlayerSample {
+        PlayerSample {
+            position: self.position.lerp(next.position, t as f64),
+            rotation: self.rotation.slerp(next.rotation, t),
+            velocity: self.velocity.lerp(next.velocity, t),
+            angular_velocity: self.angular_velocity.lerp(next.angular_velocity, t),
+            pitch: self.pitch.lerp(next.pitch, t),
+            roll: self.roll.lerp(next.roll, t),
+            yaw: self.yaw.lerp(next.yaw, t),
+            throttle: self.throttle.lerp(next.throttle, t),
+            rocket: self.rocket,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DroneSample {
+    /// Stable for the drone's lifetime (its entity id while recording)
+    pub id: u64,
+    /// Absolute world position
+    pub position: DVec3,
+    pub rotation: Quat,
+    pub velocity: Vec3,
+}
+
+#[derive(Clone, Debug)]
+pub struct ReplayFrame {
+    /// Session time (seconds of unpaused flight)
+    pub time: f32,
+    /// Time of day
+    pub hour: f32,
+    pub player: PlayerSample,
+    pub drones: Vec<DroneSample>,
+    /// Events since the previous frame
+    pub events: Vec<ReplayEvent>,
+}
+
+fn encode_header(out: &mut Vec<u8>) {
+    out.extend_from_slice(&MAGIC);
+    out.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    out.extend_from_slice(&WORLD_SEED.to_le_bytes());
+    out.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+}
+
+impl ReplayFrame {
+    fn encode(&self, out: &mut Vec<u8>) {
+        let base = self.player.position;
+        let relative = |position: DVec3| (position - base).as_vec3();
+
+        put_f32(out, self.time);
+        put_f32(out, self.hour);
+        for axis in base.to_array() {
+            out.extend_from_slice(&axis.to_le_bytes());
+        }
+        put_quat(out, self.player.rotation);
+        put_vec3(out, self.player.velocity);
+        put_vec3(out, self.player.angular_velocity);
+        for input in [self.player.pitch, self.player.roll, self.player.yaw, self.player.throttle] {
+            put_f32(out, input);
+        }
+        out.push(self.player.rocket as u8);
+
+        let drones = &self.drones[..self.drones.len().min(u16::MAX as usize)];
+        out.extend_from_slice(&(drones.len() as u16).to_le_bytes());
+        for drone in drones {
+            out.extend_from_slice(&drone.id.to_le_bytes());
+            put_vec3(out, relative(drone.position));
+            put_quat(out, drone.rotation);
+            put_vec3(out, drone.velocity);
+        }
+
+        let events = &self.events[..self.events.len().min(u8::MAX as usize)];
+        out.push(events.len() as u8);
+        for event in events {
+            out.push(ReplayEventKind::ALL.iter().position(|kind| *kind == event.kind).unwrap_or(0) as u8);
+            put_vec3(out, relative(event.position));
+            put_f32(out, event.size);
+        }
+    }
+
+    fn decode(input: &mut ByteReader) -> Option<ReplayFrame> {
+        let time = input.f32()?;
+        let hour = input.f32()?;
+        let base = DVec3::new(input.f64()?, input.f64()?, input.f64()?);
+        let player = PlayerSample {
+            position: base,
+            rotation: input.quat()?,
+            velocity: input.vec3()?,
+            angular_velocity: input.vec3()?,
+            pitch: input.f32()?,
+            roll: input.f32()?,
+            yaw: input.f32()?,
+            throttle: input.f32()?,
+            rocket: input.u8()? & 1 != 0,
+        };
+
+        let drone_count = input.u16()?;
+        let mut drones = Vec::with_capacity(drone_count as usize);
+        for _ in 0..drone_count {
+            drones.push(DroneSample {
+                id: input.u64()?,
+                position: base + input.vec3()?.as_dvec3(),
+                rotation: input.quat()?,
+                velocity: input.vec3()?,
+            });
+        }
+
+        let event_count = input.u8()?;
+        let mut events = Vec::with_capacity(event_count as usize);
+        for _ in 0..event_count {
+            let kind = *ReplayEventKind::ALL.get(input.u8()? as usize)?;
+            events.push(ReplayEvent {
+                kind,
+                position: base + input.vec3()?.as_dvec3(),
+                size: input.f32()?,
+            });
+        }
+
+        Some(ReplayFrame { time, hour, player, drones, events })
+    }
+}
+
+fn put_f32(out: &mut Vec<u8>, value: f32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_vec3(out: &mut Vec<u8>, value: Vec3) {
+    for axis in value.to_array() {
+        put_f32(out, axis);
+    }
+}
+
+/// Unit quaternion packed as four i16s (~0.002° resolution)
+fn put_quat(out: &mut Vec<u8>, value: Quat) {
+    for component in value.normalize().to_array() {
+        let packed = (component.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16;
+        out.extend_from_slice(&packed.to_le_bytes());
+    }
+}
+
+/// Cursor over a loaded recording; every read fails cleanly past the end
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl ByteReader<'_> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let (head, rest) = self.bytes.split_first_chunk::<N>()?;
+        self.bytes = rest;
+        Some(*head)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take::<1>().map(|[byte]| byte)
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.take().map(u16::from_le_bytes)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take().map(u32::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.take().map(u64::from_le_bytes)
+    }
+
+    fn f32(&mut self) -> Option<f32> {
+        self.take().map(f32::from_le_bytes)
+    }
+
+    fn f64(&mut self) -> Option<f64> {
+        self.take().map(f64::from_le_bytes)
+    }
+
+    fn vec3(&mut self) -> Option<Vec3> {
+        Some(Vec3::new(self.f32()?, self.f32()?, self.f32()?))
+    }
+
+    fn quat(&mut self) -> Option<Quat> {
+        let mut components = [0.0; 4];
+        for component in &mut components {
+            *component = self.take().map(i16::from_le_bytes)? as f32 / i16::MAX as f32;
+        }
+        Some(Quat::from_array(components).normalize())
+    }
+}
+
+/// A decoded recording.
+///
+/// Frames carry absolute positions and the stick/throttle inputs the flight model
+/// was given, so a recorded flight is also a reference trajectory: feed the
+/// inputs back through a changed flight model and compare where the jet ends up.
+pub struct Replay {
+    /// World seed the session was flown with (see `WORLD_SEED`)
+    pub seed: u32,
+    pub sample_rate: u16,
+    pub frames: Vec<ReplayFrame>,
+}
+
+impl Replay {
+    pub fn load(path: &Path) -> io::Result<Replay> {
+        Replay::parse(&std::fs::read(path)?)
+    }
+
+    fn parse(bytes: &[u8]) -> io::Result<Replay> {
+        let mut input = ByteReader { bytes };
+        let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+
+        if input.take::<4>() != Some(MAGIC) {
+            return Err(invalid("not a replay file"));
+        }
+        match input.u16() {
+            Some(FORMAT_VERSION) => {}
+            Some(version) => return Err(invalid(&format!("unsupported replay version {}", version))),
+            None => return Err(invalid("truncated header")),
+        }
+        let (Some(seed), Some(sample_rate)) = (input.u32(), input.u16()) else {
+            return Err(invalid("truncated header"));
+        };
+
+        let mut frames = Vec::new();
+        while let Some(frame) = ReplayFrame::decode(&mut input) {
+            frames.push(frame);
+        }
+        if frames.is_empty() {
+            return Err(invalid("no frames recorded"));
+        }
+        Ok(Replay { seed, sample_rate, frames })
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.frames.last().map_or(0.0, |frame| frame.time)
+    }
+
+    /// Index of the frame at or before `time`, and how far toward the next one it is
+    fn locate(&self, time: f32) -> (usize, f32) {
+        let next = self.frames.partition_point(|frame| frame.time <= time);
+        if next == 0 {
+            return (0, 0.0);
+        }
+        if next >= self.frames.len() {
+            return (self.frames.len() - 1, 0.0);
+        }
+        let (previous, following) = (&self.frames[next - 1], &self.frames[next]);
+        let span = following.time - previous.time;
+        let t = if span > 0.0 { ((time - previous.time) / span).clamp(0.0, 1.0) } else { 0.0 };
+        (next - 1, t)
+    }
+
+    /// The frames either side of `time` and the blend between them
+    fn sample_at(&self, time: f32) -> (&ReplayFrame, &ReplayFrame, f32) {
+        let (index, mut t) = self.locate(time);
+        let frame = &self.frames[index];
+        let next = self.frames.get(index + 1).unwrap_or(frame);
+        // Never glide across a reset; hold until the jump
+        if next.events.iter().any(|event| event.kind == ReplayEventKind::PlayerReset) {
+            t = 0.0;
+        }
+        (frame, next, t)
+    }
+}
+
+/// Delete all but the newest `keep` recordings in `dir`. Only touches our own
+/// `flight_<unix secs>.vrp` files, whose names sort chronologically.
+fn prune_recordings(dir: &Path, keep: usize) -> io::Result<()> {
+    let mut recordings: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension().is_some_and(|extension| extension == "vrp")
+                && path.file_stem().and_then(|stem| stem.to_str()).is_some_and(|stem| stem.starts_with("flight_"))
+        })
+        .collect();
+    recordings.sort();
+
+    let excess = recordings.len().saturating_sub(keep);
+    for path in &recordings[..excess] {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// RESOURCES & COMPONENTS
+// ============================================================================
+
+/// Streams the live session to `replays/` while flying
+#[derive(Resource, Default)]
+pub struct ReplayRecorder {
+    writer: Option<BufWriter<File>>,
+    pub path: Option<PathBuf>,
+    /// Session time: only runs while flying, so pauses and replays leave no gap
+    clock: f32,
+    next_sample: f32,
+    next_flush: f32,
+    pending: Vec<ReplayEvent>,
+    last_position: Option<DVec3>,
+    buffer: Vec<u8>,
+    /// Set after an I/O error so a read-only install doesn't spam the log
+    disabled: bool,
+}
+
+impl ReplayRecorder {
+    fn start(&mut self) -> io::Result<()> {
+        std::fs::create_dir_all(REPLAY_DIR)?;
+        if let Err(err) = prune_recordings(Path::new(REPLAY_DIR), MAX_RECORDINGS - 1) {
+            eprintln!("⚠️  REPLAY: Could not prune old recordings: {}", err);
+        }
+        let stamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let path = Path::new(REPLAY_DIR).join(format!("flight_{}.vrp", stamp));
+
+        let mut writer = BufWriter::new(File::create(&path)?);
+        self.buffer.clear();
+        encode_header(&mut self.buffer);
+        writer.write_all(&self.buffer)?;
+
+        println!("🎬 REPLAY: Recording to {}", path.display());
+        self.writer = Some(writer);
+        self.path = Some(path);
+        Ok(())
+    }
+
+    fn write(&mut self, frame: &ReplayFrame) -> io::Result<()> {
+        self.buffer.clear();
+        frame.encode(&mut self.buffer);
+        match self.writer.as_mut() {
+            Some(writer) => writer.write_all(&self.buffer),
+            None => Ok(()),
+        }
+    }
+
+    fn flush(&mut self) {
+        if let Some(writer) = self.writer.as_mut() {
+            if let Err(err) = writer.flush() {
+                eprintln!("⚠️  REPLAY: Could not flush recording: {}", err);
+            }
+        }
+    }
+
+    fn fail(&mut self, err: io::Error) {
+        eprintln!("❌ REPLAY: Recording stopped: {}", err);
+        self.writer = None;
+        self.disabled = true;
+    }
+}
+
+/// Replay file named with `--replay <file>`, opened once the world is up
+#[derive(Resource, Default)]
+struct ReplayRequest(Option<PathBuf>);
+
+/// The replay being watched
+#[derive(Resource)]
+struct ReplayPlayback {
+    replay: Replay,
+    time: f32,
+    speed_index: usize,
+    paused: bool,
+    /// Events up to here have been shown; a seek moves it without showing any
+    shown_until: f32,
+    ghosts: HashMap<u64, Entity>,
+    ghost_assets: Option<GhostAssets>,
+    camera_yaw: f32,
+    camera_pitch: f32,
+    camera_speed: f32,
+    /// Where the live session stood, restored when the viewer closes
+    resume: Option<LiveSession>,
+}
+
+/// The live jet (pose, stick, throttle, rocket) and clock, put back after the replay
+/// has driven them
+#[derive(Clone, Copy)]
+struct LiveSession {
+    player: PlayerSample,
+    hour: f32,
+}
+
+impl ReplayPlayback {
+    fn new(replay: Replay) -> Self {
+        let start = replay.frames[0].time;
+        Self {
+            replay,
+            time: start,
+            speed_index: NORMAL_SPEED_INDEX,
+            paused: false,
+            shown_until: start,
+            ghosts: HashMap::new(),
+            ghost_assets: None,
+            camera_yaw: 0.0,
+            camera_pitch: 0.0,
+            camera_speed: FREE_CAMERA_SPEED,
+            resume: None,
+        }
+    }
+
+    fn seek(&mut self, time: f32) {
+        let start = self.replay.frames[0].time;
+        self.time = time.clamp(start, self.replay.duration());
+        self.shown_until = self.time;
+    }
+}
+
+struct GhostAssets {
+    scene: Handle<Scene>,
+    marker_mesh: Handle<Mesh>,
+    marker_material: Handle<StandardMaterial>,
+}
+
+/// Stand-in for a recorded drone (no physics, no AI)
+#[derive(Component)]
+struct GhostDrone;
+
+/// Live combat entity tucked away while a replay is showing
+#[derive(Component)]
+struct HiddenForReplay;
+
+#[derive(Component)]
+struct ReplayUi;
+
+#[derive(Component)]
+struct TimelineBar;
+
+#[derive(Component)]
+struct TimelineFill;
+
+#[derive(Component)]
+struct ReplayLabel;
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Replay file named with `--replay <file>`, if any
+pub fn requested_on_command_line() -> Option<PathBuf> {
+    std::env::args().skip_while(|arg| arg != "--replay").nth(1).map(PathBuf::from)
+}
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplayRecorder>()
+            .insert_resource(ReplayRequest(requested_on_command_line()))
+            .add_systems(Update, toggle_replay)
+            .add_systems(Update, (
+                capture_replay_events,
+                record_replay_frame,
+            ).chain().run_if(in_state(GameState::Playing)))
+            .add_systems(OnEnter(GameState::Replay), (save_live_session, enter_replay).chain())
+            .add_systems(OnExit(GameState::Replay), (restore_live_session, exit_replay).chain())
+            .add_systems(Update, (
+                hide_live_drones,
+                replay_controls,
+                scrub_timeline,
+                advance_replay,
+                pose_replay_player,
+                apply_replay_frame,
+                fly_free_camera,
+                update_timeline,
+            ).chain().run_if(in_state(GameState::Replay)));
+    }
+}
+
+// ============================================================================
+// RECORDING
+// ============================================================================
+
+/// Weapon launches and explosions since the last sample
+fn capture_replay_events(
+    mut recorder: ResMut<ReplayRecorder>,
+    origin: Res<WorldOrigin>,
+    mut effects: EventReader<SpawnEffect>,
+    player_missiles: Query<&Transform, Added<Projectile>>,
+    player_rounds: Query<&Transform, Added<crate::Bullet>>,
+    drone_missiles: Query<&Transform, Added<drone::Missile>>,
+    drone_rounds: Query<&Transform, Added<drone::Bullet>>,
+) {
+    if recorder.disabled {
+        effects.clear();
+        return;
+    }
+    let mut push = |kind, local: Vec3, size| {
+        recorder.pending.push(ReplayEvent { kind, position: origin.to_absolute(local), size });
+    };
+    for transform in &player_missiles {
+        push(ReplayEventKind::PlayerMissile, transform.translation, 1.0);
+    }
+    for transform in &player_rounds {
+        push(ReplayEventKind::PlayerGun, transform.translation, 1.0);
+    }
+    for transform in &drone_missiles {
+        push(ReplayEventKind::DroneMissile, transform.translation, 1.0);
+    }
+    for transform in &drone_rounds {
+        push(ReplayEventKind::DroneGun, transform.translation, 1.0);
+    }
+    for effect in effects.read() {
+        push(ReplayEventKind::from_effect(effect.effect), effect.position, effect.size);
+    }
+}
+
+/// Sample the jet and every drone at `SAMPLE_RATE` and append to the recording
+fn record_replay_frame(
+    time: Res<Time>,
+    mut recorder: ResMut<ReplayRecorder>,
+    origin: Res<WorldOrigin>,
+    time_of_day: Res<TimeOfDay>,
+    player_query: Query<(&Transform, &LinearVelocity, &AngularVelocity, &PlayerInput, &RocketEngine), With<PlayerPlane>>,
+    drone_query: Query<(Entity, &Transform, &LinearVelocity), With<Drone>>,
+) {
+    if recorder.disabled {
+        return;
+    }
+    recorder.clock += time.delta_secs();
+    if recorder.clock < recorder.next_sample {
+        return;
+    }
+    let Ok((transform, velocity, angular_velocity, input, rocket)) = player_query.get_single() else { return };
+
+    if recorder.writer.is_none() {
+        if let Err(err) = recorder.start() {
+            recorder.fail(err);
+            return;
+        }
+    }
+    let interval = 1.0 / SAMPLE_RATE as f32;
+    recorder.next_sample = (recorder.next_sample + interval).max(recorder.clock);
+
+    let position = origin.to_absolute(transform.translation);
+    if let Some(last) = recorder.last_position {
+        let reach = velocity.0.length() as f64 * 2.0 * interval as f64 + RESET_JUMP_MARGIN;
+        if last.distance(position) > reach {
+            recorder.pending.push(ReplayEvent { kind: ReplayEventKind::PlayerReset, position, size: 1.0 });
+        }
+    }
+    recorder.last_position = Some(position);
+
+    let frame = ReplayFrame {
+        time: recorder.clock,
+        hour: time_of_day.hour,
+        player: PlayerSample {
+            position,
+            rotation: transform.rotation,
+            velocity: velocity.0,
+            angular_velocity: angular_velocity.0,
+            pitch: input.pitch,
+            roll: input.roll,
+            yaw: input.yaw,
+            throttle: input.throttle,
+            rocket: rocket.enabled,
+        },
+        drones: drone_query
+            .iter()
+            .map(|(entity, drone_transform, drone_velocity)| DroneSample {
+                id: entity.to_bits(),
+                position: origin.to_absolute(drone_transform.translation),
+                rotation: drone_transform.rotation,
+                velocity: drone_velocity.0,
+            })
+            .collect(),
+        events: std::mem::take(&mut recorder.pending),
+    };
+
+    if let Err(err) = recorder.write(&frame) {
+        recorder.fail(err);
+        return;
+    }
+    if recorder.clock >= recorder.next_flush {
+        recorder.next_flush = recorder.clock + FLUSH_INTERVAL;
+        recorder.flush();
+    }
+}
+
+// ============================================================================
+// PLAYBACK
+// ============================================================================
+
+/// `F8` opens the session recorded so far (or `--replay <file>` on startup) and closes it again
+fn toggle_replay(
+    mut commands: Commands,
+    actions: Res<ActionState>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut recorder: ResMut<ReplayRecorder>,
+    mut request: ResMut<ReplayRequest>,
+) {
+    match state.get() {
+        GameState::Playing => {
+            let path = if let Some(path) = request.0.take() {
+                path
+            } else if actions.just_pressed(Action::Replay) {
+                recorder.flush();
+                let Some(path) = recorder.path.clone() else {
+                    println!("🎬 REPLAY: Nothing recorded yet");
+                    return;
+                };
+                path
+            } else {
+                return;
+            };
+
+            match Replay::load(&path) {
+                Ok(replay) => {
+                    println!("🎬 REPLAY: Playing {} ({} frames at {} Hz, {:.1}s) - F8 to return",
+                        path.display(), replay.frames.len(), replay.sample_rate, replay.duration());
+                    if replay.seed != WORLD_SEED {
+                        eprintln!("⚠️  REPLAY: Recorded with world seed {}, this build uses {} - terrain will differ",
+                            replay.seed, WORLD_SEED);
+                    }
+                    commands.insert_resource(ReplayPlayback::new(replay));
+                    next_state.set(GameState::Replay);
+                }
+                Err(err) => eprintln!("❌ REPLAY: Could not load {}: {}", path.display(), err),
+            }
+        }
+        GameState::Replay if actions.just_pressed(Action::Replay) => {
+            next_state.set(GameState::Playing);
+        }
+        _ => {}
+    }
+}
+
+/// Remember everything about the live session that playback is about to overwrite
+fn save_live_session(
+    mut playback: ResMut<ReplayPlayback>,
+    origin: Res<WorldOrigin>,
+    time_of_day: Res<TimeOfDay>,
+    player_query: Query<(&Transform, &LinearVelocity, &AngularVelocity, &PlayerInput, &RocketEngine), With<PlayerPlane>>,
+    camera_query: Query<&Transform, With<Camera3d>>,
+) {
+    if let Ok(camera) = camera_query.get_single() {
+        let (yaw, pitch, _) = camera.rotation.to_euler(EulerRot::YXZ);
+        playback.camera_yaw = yaw;
+        playback.camera_pitch = pitch;
+    }
+    if let Ok((transform, velocity, angular_velocity, input, rocket)) = player_query.get_single() {
+        playback.resume = Some(LiveSession {
+            player: PlayerSample {
+                position: origin.to_absolute(transform.translation),
+                rotation: transform.rotation,
+                velocity: velocity.0,
+                angular_velocity: angular_velocity.0,
+                pitch: input.pitch,
+                roll: input.roll,
+                yaw: input.yaw,
+                throttle: input.throttle,
+                rocket: rocket.enabled,
+            },
+            hour: time_of_day.hour,
+        });
+    }
+}
+
+/// Everything live that would fly through the replay: drones, their weapons and ours
+type LiveCombatQuery<'w, 's> = Query<'w, 's, Entity, Or<(
+    With<Drone>,
+    With<drone::Missile>,
+    With<drone::Bullet>,
+    With<Projectile>,
+    With<crate::Bullet>,
+)>>;
+
+/// Freeze the live session and build the viewer
+fn enter_replay(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut physics_time: ResMut<Time<Physics>>,
+    mut playback: ResMut<ReplayPlayback>,
+    live_query: LiveCombatQuery,
+) {
+    physics_time.pause();
+
+    for entity in &live_query {
+        commands.entity(entity).insert((Visibility::Hidden, HiddenForReplay));
+    }
+
+    playback.ghost_assets = Some(GhostAssets {
+        scene: asset_server.load("models/drone.glb#Scene0"),
+        marker_mesh: meshes.add(Cuboid::new(3.0, 1.5, 4.5)),
+        marker_material: materials.add(StandardMaterial {
+            base_color: Color::srgba(0.8, 0.1, 0.1, 0.5),
+            emissive: LinearRgba::rgb(2.0, 0.0, 0.0),
+            ..default()
+        }),
+    });
+
+    spawn_timeline(&mut commands, &playback.replay);
+}
+
+/// Put the live session back exactly as it was: pose, stick, throttle, rocket and clock
+fn restore_live_session(
+    playback: Res<ReplayPlayback>,
+    origin: Res<WorldOrigin>,
+    mut time_of_day: ResMut<TimeOfDay>,
+    mut player_query: ReplayPlayerQuery,
+) {
+    let Some(resume) = playback.resume else { return };
+    if let Ok((mut transform, mut velocity, mut angular_velocity, mut input, mut rocket)) = player_query.get_single_mut() {
+        let player = resume.player;
+        transform.translation = origin.to_local(player.position);
+        transform.rotation = player.rotation;
+        velocity.0 = player.velocity;
+        angular_velocity.0 = player.angular_velocity;
+        input.pitch = player.pitch;
+        input.roll = player.roll;
+        input.yaw = player.yaw;
+        input.throttle = player.throttle;
+        rocket.enabled = player.rocket;
+    }
+    time_of_day.hour = resume.hour;
+}
+
+/// Tear the viewer down and hand control back
+fn exit_replay(
+    mut commands: Commands,
+    mut physics_time: ResMut<Time<Physics>>,
+    playback: Res<ReplayPlayback>,
+    hidden_query: Query<Entity, With<HiddenForReplay>>,
+    ui_query: Query<Entity, With<ReplayUi>>,
+) {
+    for &ghost in playback.ghosts.values() {
+        commands.entity(ghost).despawn_recursive();
+    }
+    for entity in &ui_query {
+        commands.entity(entity).despawn_recursive();
+    }
+    for entity in &hidden_query {
+        commands.entity(entity).insert(Visibility::Inherited).remove::<HiddenForReplay>();
+    }
+
+    commands.remove_resource::<ReplayPlayback>();
+    physics_time.unpause();
+    println!("🎬 REPLAY: Closed, back to the live session");
+}
+
+/// Patrols spawned by chunks streaming in during the replay aren't part of it
+fn hide_live_drones(mut commands: Commands, new_drones: Query<Entity, Added<Drone>>) {
+    for entity in &new_drones {
+        commands.entity(entity).insert((Visibility::Hidden, HiddenForReplay));
+    }
+}
+
+/// Space pauses, arrows seek, `-`/`=` change speed, Home rewinds
+fn replay_controls(keyboard: Res<ButtonInput<KeyCode>>, mut playback: ResMut<ReplayPlayback>) {
+    if keyboard.just_pressed(KeyCode::Space) {
+        // Play again from the top once the end has been reached
+        if playback.paused && playback.time >= playback.replay.duration() {
+            let start = playback.replay.frames[0].time;
+            playback.seek(start);
+        }
+        playback.paused = !playback.paused;
+    }
+    if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        let time = playback.time - SEEK_STEP;
+        playback.seek(time);
+    }
+    if keyboard.just_pressed(KeyCode::ArrowRight) {
+        let time = playback.time + SEEK_STEP;
+        playback.seek(time);
+    }
+    if keyboard.just_pressed(KeyCode::Home) {
+        let start = playback.replay.frames[0].time;
+        playback.seek(start);
+    }
+    if keyboard.just_pressed(KeyCode::Minus) {
+        playback.speed_index = playback.speed_index.saturating_sub(1);
+    }
+    if keyboard.just_pressed(KeyCode::Equal) {
+        playback.speed_index = (playback.speed_index + 1).min(PLAYBACK_SPEEDS.len() - 1);
+    }
+}
+
+/// Click or drag on the timeline to jump there
+fn scrub_timeline(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    bar_query: Query<&RelativeCursorPosition, With<TimelineBar>>,
+    mut playback: ResMut<ReplayPlayback>,
+) {
+    if !mouse_buttons.pressed(MouseButton::Left) {
+        return;
+    }
+    let Ok(cursor) = bar_query.get_single() else { return };
+    let Some(position) = cursor.normalized.filter(|_| cursor.mouse_over()) else { return };
+    let start = playback.replay.frames[0].time;
+    let time = start + position.x.clamp(0.0, 1.0) * (playback.replay.duration() - start);
+    playback.seek(time);
+}
+
+fn advance_replay(time: Res<Time>, mut playback: ResMut<ReplayPlayback>) {
+    if playback.paused {
+        return;
+    }
+    playback.time += time.delta_secs() * PLAYBACK_SPEEDS[playback.speed_index];
+    if playback.time >= playback.replay.duration() {
+        playback.time = playback.replay.duration();
+        playback.paused = true;
+    }
+}
+
+/// Everything on the jet that playback drives (and `restore_live_session` puts back)
+type ReplayPlayerQuery<'w, 's> = Query<'w, 's, (
+    &'static mut Transform,
+    &'static mut LinearVelocity,
+    &'static mut AngularVelocity,
+    &'static mut PlayerInput,
+    &'static mut RocketEngine,
+), With<PlayerPlane>>;
+
+/// Pose the jet and set the clock for the current replay time
+fn pose_replay_player(
+    playback: Res<ReplayPlayback>,
+    origin: Res<WorldOrigin>,
+    mut time_of_day: ResMut<TimeOfDay>,
+    mut player_query: ReplayPlayerQuery,
+) {
+    let (frame, next, t) = playback.replay.sample_at(playback.time);
+
+    let sample = frame.player.lerp(&next.player, t);
+    if let Ok((mut transform, mut velocity, mut angular_velocity, mut input, mut rocket)) = player_query.get_single_mut() {
+        transform.translation = origin.to_local(sample.position);
+        transform.rotation = sample.rotation;
+        velocity.0 = sample.velocity;
+        angular_velocity.0 = sample.angular_velocity;
+        input.pitch = sample.pitch;
+        input.roll = sample.roll;
+        input.yaw = sample.yaw;
+        input.throttle = sample.throttle;
+        rocket.enabled = sample.rocket;
+    }
+    // Wrapping past midnight would sweep the sun backwards through the day
+    time_of_day.hour = if (next.hour - frame.hour).abs() < 12.0 { frame.hour.lerp(next.hour, t) } else { frame.hour };
+}
+
+/// Pose the drones for the current replay time, and re-trigger the explosions and
+/// weapon launches played past since last frame
+fn apply_replay_frame(
+    mut commands: Commands,
+    mut playback: ResMut<ReplayPlayback>,
+    origin: Res<WorldOrigin>,
+    mut effects: EventWriter<SpawnEffect>,
+    mut particles: EventWriter<EmitParticles>,
+    mut ghost_query: Query<&mut Transform, (With<GhostDrone>, Without<PlayerPlane>)>,
+) {
+    let playback = &mut *playback;
+    let replay = &playback.replay;
+    let (frame, next, t) = replay.sample_at(playback.time);
+
+    // Drones: interpolate those present in both frames, spawn and retire ghosts to match
+    let mut seen = Vec::with_capacity(frame.drones.len());
+    for drone in &frame.drones {
+        let following = next.drones.iter().find(|other| other.id == drone.id).unwrap_or(drone);
+        let transform = Transform {
+            translation: origin.to_local(drone.position.lerp(following.position, t as f64)),
+            rotation: drone.rotation.slerp(following.rotation, t),
+            scale: Vec3::ONE,
+        };
+        seen.push(drone.id);
+
+        match playback.ghosts.get(&drone.id).and_then(|&ghost| ghost_query.get_mut(ghost).ok()) {
+            Some(mut ghost_transform) => *ghost_transform = transform,
+            None => {
+                if let Some(assets) = playback.ghost_assets.as_ref() {
+                    let ghost = spawn_ghost_drone(&mut commands, assets, transform);
+                    playback.ghosts.insert(drone.id, ghost);
+                }
+            }
+        }
+    }
+    playback.ghosts.retain(|id, ghost| {
+        let keep = seen.contains(id);
+        if !keep {
+            commands.entity(*ghost).despawn_recursive();
+        }
+        keep
+    });
+
+    // Events only while playing forward; seeking skips them
+    if playback.time > playback.shown_until {
+        let from = playback.shown_until;
+        for frame in replay.frames.iter().filter(|frame| frame.time > from && frame.time <= playback.time) {
+            for event in &frame.events {
+                let position = origin.to_local(event.position);
+                match event.kind.effect() {
+                    Some(effect) => {
+                        effects.send(SpawnEffect::new(effect, position, event.size));
+                    }
+                    // Resets only show as a tick on the timeline
+                    None if event.kind == ReplayEventKind::PlayerReset => {}
+                    None => {
+                        particles.send(EmitParticles::new(ParticleType::MuzzleFlash, position));
+                    }
+                }
+            }
+        }
+    }
+    playback.shown_until = playback.time;
+}
+
+fn spawn_ghost_drone(commands: &mut Commands, assets: &GhostAssets, transform: Transform) -> Entity {
+    commands.spawn((
+        GhostDrone,
+        LodGroup::new(LodCategory::Drone),
+        transform,
+        Visibility::default(),
+    ))
+    .with_children(|parent| {
+        parent.spawn((
+            LodVariant::levels(0, 1),
+            SceneRoot(assets.scene.clone()),
+            Transform::from_scale(Vec3::splat(15.0)),
+        ));
+        parent.spawn((
+            LodVariant::levels(0, 3),
+            Mesh3d(assets.marker_mesh.clone()),
+            MeshMaterial3d(assets.marker_material.clone()),
+            Transform::IDENTITY,
+        ));
+    })
+    .id()
+}
+
+/// Free-flying spectator camera: hold the right mouse button to look around,
+/// WASD to move, Q/E down/up, Shift to go fast, mouse wheel to change speed
+fn fly_free_camera(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    mut playback: ResMut<ReplayPlayback>,
+    mut camera_query: Query<&mut Transform, With<Camera3d>>,
+) {
+    let Ok(mut camera) = camera_query.get_single_mut() else { return };
+
+    let look: Vec2 = mouse_motion.read().map(|motion| motion.delta).sum();
+    if mouse_buttons.pressed(MouseButton::Right) {
+        playback.camera_yaw -= look.x * FREE_CAMERA_SENSITIVITY;
+        playback.camera_pitch = (playback.camera_pitch - look.y * FREE_CAMERA_SENSITIVITY).clamp(-1.5, 1.5);
+    }
+    for wheel in mouse_wheel.read() {
+        playback.camera_speed = (playback.camera_speed * 1.2_f32.powf(wheel.y)).clamp(5.0, 5000.0);
+    }
+    camera.rotation = Quat::from_euler(EulerRot::YXZ, playback.camera_yaw, playback.camera_pitch, 0.0);
+
+    let mut direction = Vec3::ZERO;
+    for (key, axis) in [
+        (KeyCode::KeyW, camera.forward().as_vec3()),
+        (KeyCode::KeyS, camera.back().as_vec3()),
+        (KeyCode::KeyA, camera.left().as_vec3()),
+        (KeyCode::KeyD, camera.right().as_vec3()),
+        (KeyCode::KeyQ, Vec3::NEG_Y),
+        (KeyCode::KeyE, Vec3::Y),
+    ] {
+        if keyboard.pressed(key) {
+            direction += axis;
+        }
+    }
+    let boost = if keyboard.pressed(KeyCode::ShiftLeft) { FREE_CAMERA_BOOST } else { 1.0 };
+    camera.translation += direction.normalize_or_zero() * playback.camera_speed * boost * time.delta_secs();
+}
+
+// ============================================================================
+// TIMELINE UI
+// ============================================================================
+
+fn spawn_timeline(commands: &mut Commands, replay: &Replay) {
+    let start = replay.frames[0].time;
+    let span = (replay.duration() - start).max(0.001);
+
+    commands.spawn((
+        ReplayUi,
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(20.0),
+            left: Val::Percent(5.0),
+            width: Val::Percent(90.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+    ))
+    .with_children(|parent| {
+        parent.spawn((
+            ReplayLabel,
+            Text::new("REPLAY"),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 1.0, 0.0)),
+        ));
+
+        parent.spawn((
+            TimelineBar,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(14.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            RelativeCursorPosition::default(),
+        ))
+        .with_children(|bar| {
+            bar.spawn((
+                TimelineFill,
+                Node {
+                    width: Val::Percent(0.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.0, 1.0, 0.0, 0.6)),
+            ));
+
+            // Ticks where things blew up or the jet was reset
+            for frame in &replay.frames {
+                for event in frame.events.iter().filter(|event| event.kind.is_milestone()) {
+                    let color = if event.kind == ReplayEventKind::PlayerReset {
+                        Color::srgb(1.0, 0.0, 1.0)
+                    } else {
+                        Color::srgb(1.0, 0.4, 0.0)
+                    };
+                    bar.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Percent((frame.time - start) / span * 100.0),
+                            width: Val::Px(2.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(color),
+                    ));
+                }
+            }
+        });
+    });
+}
+
+fn update_timeline(
+    playback: Res<ReplayPlayback>,
+    mut fill_query: Query<&mut Node, With<TimelineFill>>,
+    mut label_query: Query<&mut Text, With<ReplayLabel>>,
+) {
+    let start = playback.replay.frames[0].time;
+    let span = (playback.replay.duration() - start).max(0.001);
+    if let Ok(mut fill) = fill_query.get_single_mut() {
+        fill.width = Val::Percent((playback.time - start) / span * 100.0);
+    }
+
+    let clock = |seconds: f32| format!("{:02}:{:04.1}", (seconds / 60.0) as u32, seconds % 60.0);
+    if let Ok(mut label) = label_query.get_single_mut() {
+        let status = if playback.paused { "  PAUSED" } else { "" };
+        label.0 = format!(
+            "REPLAY {} / {}  x{}{}   [Space] pause  [←/→] seek  [-/=] speed  [RMB] look  [WASD/QE] fly  [F8] exit",
+            clock(playback.time - start),
+            clock(span),
+            PLAYBACK_SPEEDS[playback.speed_index],
+            status,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_frame(time: f32, events: Vec<ReplayEvent>) -> ReplayFrame {
+        let position = DVec3::new(120_000.5 + time as f64 * 250.0, 1500.25, -80_000.75);
+        ReplayFrame {
+            time,
+            hour: 14.5 + time / 3600.0,
+            player: PlayerSample {
+                position,
+                rotation: Quat::from_euler(EulerRot::YXZ, 0.3 + time, -0.1, 0.05),
+                velocity: Vec3::new(0.0, 2.0, -250.0),
+                angular_velocity: Vec3::new(0.01, -0.2, 0.0),
+                pitch: 0.25,
+                roll: -0.5,
+                yaw: 0.1,
+                throttle: 0.8,
+                rocket: time > 0.05,
+            },
+            drones: vec![
+                DroneSample {
+                    id: 42,
+                    position: position + DVec3::new(900.0, -50.0, 1200.0),
+                    rotation: Quat::from_rotation_y(1.2),
+                    velocity: Vec3::new(-120.0, 0.0, 30.0),
+                },
+                DroneSample {
+                    id: u64::MAX,
+                    position: position + DVec3::new(-3000.0, 400.0, 0.0),
+                    rotation: Quat::IDENTITY,
+                    velocity: Vec3::ZERO,
+                },
+            ],
+            events,
+        }
+    }
+
+    fn encode_session(frames: &[ReplayFrame]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        encode_header(&mut bytes);
+        for frame in frames {
+            frame.encode(&mut bytes);
+        }
+        bytes
+    }
+
+    fn assert_quat_near(a: Quat, b: Quat) {
+        // Packed to i16; q and -q are the same rotation
+        assert!(a.dot(b).abs() > 1.0 - 1e-6, "{:?} != {:?}", a, b);
+    }
+
+    fn assert_frames_match(decoded: &ReplayFrame, original: &ReplayFrame) {
+        assert_eq!(decoded.time, original.time);
+        assert_eq!(decoded.hour, original.hour);
+
+        let (player, expected) = (&decoded.player, &original.player);
+        assert_eq!(player.position, expected.position);
+        assert_quat_near(player.rotation, expected.rotation);
+        assert_eq!(player.velocity, expected.velocity);
+        assert_eq!(player.angular_velocity, expected.angular_velocity);
+        assert_eq!(
+            [player.pitch, player.roll, player.yaw, player.throttle],
+            [expected.pitch, expected.roll, expected.yaw, expected.throttle],
+        );
+        assert_eq!(player.rocket, expected.rocket);
+
+        assert_eq!(decoded.drones.len(), original.drones.len());
+        for (drone, expected) in decoded.drones.iter().zip(&original.drones) {
+            assert_eq!(drone.id, expected.id);
+            // Stored as f32 offsets from the jet
+            assert!(drone.position.distance(expected.position) < 1e-3);
+            assert_quat_near(drone.rotation, expected.rotation);
+            assert_eq!(drone.velocity, expected.velocity);
+        }
+
+        assert_eq!(decoded.events.len(), original.events.len());
+        for (event, expected) in decoded.events.iter().zip(&original.events) {
+            assert_eq!(event.kind, expected.kind);
+            assert!(event.position.distance(expected.position) < 1e-3);
+            assert_eq!(event.size, expected.size);
+        }
+    }
+
+    #[test]
+    fn frames_and_events_round_trip() {
+        let base = DVec3::new(120_000.0, 1500.0, -80_000.0);
+        let frames = vec![
+            sample_frame(0.0, Vec::new()),
+            sample_frame(0.05, vec![
+                ReplayEvent { kind: ReplayEventKind::PlayerMissile, position: base, size: 1.0 },
+                ReplayEvent { kind: ReplayEventKind::DroneGun, position: base + DVec3::X * 500.0, size: 1.0 },
+            ]),
+            sample_frame(0.1, ReplayEventKind::ALL
+                .iter()
+                .map(|&kind| ReplayEvent { kind, position: base - DVec3::Y * 1500.0, size: 2.5 })
+                .collect()),
+        ];
+
+        let replay = Replay::parse(&encode_session(&frames)).expect("recording should load");
+        assert_eq!(replay.seed, WORLD_SEED);
+        assert_eq!(replay.sample_rate, SAMPLE_RATE);
+        assert_eq!(replay.frames.len(), frames.len());
+        for (decoded, original) in replay.frames.iter().zip(&frames) {
+            assert_frames_match(decoded, original);
+        }
+    }
+
+    #[test]
+    fn partial_last_frame_is_dropped() {
+        let frames = vec![sample_frame(0.0, Vec::new()), sample_frame(0.05, Vec::new())];
+        let mut bytes = encode_session(&frames);
+        bytes.truncate(bytes.len() - 7);
+
+        let replay = Replay::parse(&bytes).expect("frames before the crash should load");
+        assert_eq!(replay.frames.len(), 1);
+        assert_frames_match(&replay.frames[0], &frames[0]);
+    }
+
+    #[test]
+    fn rejects_foreign_files() {
+        assert!(Replay::parse(b"PK\x03\x04 not a replay").is_err());
+
+        let mut header_only = Vec::new();
+        encode_header(&mut header_only);
+        assert!(Replay::parse(&header_only).is_err());
+    }
+}
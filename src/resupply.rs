@@ -0,0 +1,533 @@
+use avian3d::prelude::*;
+use bevy::{math::DVec3, prelude::*};
+use crate::{
+    airfield,
+    assets::GameAssets,
+    audio::{AudioBus, PlaySound},
+    engine_audio::EngineSpool,
+    landing_gear::LandingGear,
+    origin::WorldOrigin,
+    space, GameState, PlayerInput, PlayerPlane,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Internal jet fuel (kg): roughly ten minutes in afterburner
+pub const FUEL_CAPACITY_KG: f32 = 3200.0;
+/// Fuel flow (kg/s) at idle, at full military power, and extra with the burner lit
+const IDLE_FUEL_FLOW: f32 = 0.2;
+const MIL_FUEL_FLOW: f32 = 1.2;
+const AFTERBURNER_FUEL_FLOW: f32 = 3.8;
+
+/// Weapons load after a rearm
+pub const MISSILE_CAPACITY: u32 = 120;
+pub const GUN_ROUNDS: u32 = 1200;
+
+/// The tanker's racetrack: 12 km north of the home field, legs running east-west
+const TANKER_ANCHOR: DVec3 = DVec3::new(0.0, 4000.0, -12000.0);
+const TANKER_LEG: f32 = 16000.0;
+const TANKER_TURN_RADIUS: f32 = 3000.0;
+const TANKER_SPEED: f32 = 160.0; // m/s
+/// Bank in the turns, and how fast the tanker rolls into and out of them (rad/s)
+const TANKER_TURN_BANK: f32 = 0.7;
+const TANKER_ROLL_RATE: f32 = 0.15;
+
+/// Boom hinge under the tail (tanker space, -Z forward) and its trail angle when stowed
+const BOOM_PIVOT: Vec3 = Vec3::new(0.0, -2.0, 16.0);
+const BOOM_TRAIL_ANGLE: f32 = 0.52; // ~30° below the fuselage
+/// Telescoping length: stowed/nominal, and the limits the operator can reach
+const BOOM_NOMINAL_LENGTH: f32 = 12.0;
+const BOOM_MIN_LENGTH: f32 = 8.0;
+const BOOM_MAX_LENGTH: f32 = 18.0;
+/// How far off the trail angle the operator can swing the boom (rad)
+const BOOM_MAX_SWING: f32 = 0.45;
+/// How fast the operator flies the boom toward the receptacle
+const BOOM_SLEW_RATE: f32 = 3.0;
+
+/// Refueling receptacle on the jet's spine, behind the cockpit
+const RECEPTACLE_OFFSET: Vec3 = Vec3::new(0.0, 1.2, -1.0);
+/// Closer than this to the contact point and the director lights come on
+const PRE_CONTACT_RANGE: f32 = 60.0;
+/// Half-size of the contact box around the nominal contact point (across, up/down, fore/aft)
+const CONTACT_BOX: Vec3 = Vec3::new(2.5, 2.0, 3.0);
+/// Relative speed (m/s) the receptacle must hold to latch, and to stay latched
+const CONTACT_CLOSURE: f32 = 3.0;
+const DISCONNECT_CLOSURE: f32 = 6.0;
+/// Once latched the boom tolerates this much more of the box before it pulls out
+const DISCONNECT_MARGIN: f32 = 2.0;
+/// Seconds steady in the box before the boom operator plugs in
+const CONTACT_SETTLE: f32 = 0.5;
+/// Boom transfer rate (kg/s)
+const BOOM_TRANSFER_RATE: f32 = 40.0;
+
+/// Ground crew: how still the jet must be, how long they take to show up, and
+/// how fast they pump fuel and load the rails and the gun
+const SERVICE_MAX_SPEED: f32 = 1.0;
+const SERVICE_MAX_THROTTLE: f32 = 0.3;
+const SERVICE_DELAY: f32 = 3.0;
+const SERVICE_FUEL_RATE: f32 = 100.0; // kg/s
+const SERVICE_PROPELLANT_RATE: f32 = 100.0; // kg/s of rocket propellant
+const SERVICE_MISSILE_RATE: f32 = 10.0; // per second
+const SERVICE_ROUNDS_RATE: f32 = 200.0; // per second
+/// Tanks fuller than this aren't worth calling the crew out for
+const SERVICE_TOP_UP: f32 = 0.98;
+
+// ============================================================================
+// COMPONENTS & RESOURCES
+// ============================================================================
+
+/// Internal jet fuel. Lives on the player; the turbine flames out when it runs dry.
+#[derive(Component)]
+pub struct FuelTank {
+    /// Remaining fuel (kg)
+    pub fuel: f32,
+}
+
+impl Default for FuelTank {
+    fn default() -> Self {
+        Self { fuel: FUEL_CAPACITY_KG }
+    }
+}
+
+impl FuelTank {
+    /// Remaining fuel as a fraction of a full tank
+    pub fn fraction(&self) -> f32 {
+        self.fuel / FUEL_CAPACITY_KG
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fuel <= 0.0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.fuel >= FUEL_CAPACITY_KG
+    }
+
+    fn burn(&mut self, kg: f32) {
+        self.fuel = (self.fuel - kg).max(0.0);
+    }
+
+    /// Takes on up to `kg` of fuel; returns how much fit
+    fn fill(&mut self, kg: f32) -> f32 {
+        let taken = kg.min(FUEL_CAPACITY_KG - self.fuel).max(0.0);
+        self.fuel += taken;
+        taken
+    }
+}
+
+/// Missiles on the rails and rounds in the gun. Lives on the player.
+#[derive(Component)]
+pub struct Stores {
+    pub missiles: u32,
+    pub rounds: u32,
+}
+
+impl Default for Stores {
+    fn default() -> Self {
+        Self { missiles: MISSILE_CAPACITY, rounds: GUN_ROUNDS }
+    }
+}
+
+impl Stores {
+    pub fn is_full(&self) -> bool {
+        self.missiles >= MISSILE_CAPACITY && self.rounds >= GUN_ROUNDS
+    }
+}
+
+/// The AI tanker flying its racetrack
+#[derive(Component)]
+pub struct Tanker {
+    /// Distance flown around the racetrack (m)
+    distance: f32,
+    bank: f32,
+    pub velocity: Vec3,
+    /// Pilot director lights under the nose, colored for the receiver
+    director_lights: Handle<StandardMaterial>,
+}
+
+/// Telescoping boom (child of the tanker, hinged at `BOOM_PIVOT`)
+#[derive(Component)]
+pub struct TankerBoom;
+
+/// Where the receiver is relative to the boom
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BoomState {
+    #[default]
+    Clear,
+    /// Close behind the tanker: director lights on, boom tracking
+    PreContact,
+    /// Plugged in and taking fuel
+    Contact,
+}
+
+/// Resupply status for the HUD
+#[derive(Resource, Default)]
+pub struct Resupply {
+    pub boom: BoomState,
+    /// Distance to the tanker (m)
+    pub tanker_range: f32,
+    /// Ground crew working on the jet
+    pub servicing: bool,
+    settle: f32,
+    crew_delay: f32,
+    missiles_due: f32,
+    rounds_due: f32,
+}
+
+pub struct ResupplyPlugin;
+
+impl Plugin for ResupplyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Resupply>()
+            .add_systems(OnEnter(GameState::Spawning), spawn_tanker)
+            .add_systems(Update, (
+                burn_fuel.after(crate::read_player_input),
+                (fly_tanker, boom_contact, steer_boom).chain(),
+                ground_service,
+            ).run_if(in_state(GameState::Playing)));
+    }
+}
+
+/// Once around the racetrack (m)
+fn racetrack_lap() -> f32 {
+    2.0 * (TANKER_LEG + std::f32::consts::PI * TANKER_TURN_RADIUS)
+}
+
+/// Position, direction of flight and turn (+1 right, 0 straight) `distance` meters around the racetrack
+fn racetrack_pose(distance: f32) -> (DVec3, Vec3, f32) {
+    let half_leg = TANKER_LEG as f64 / 2.0;
+    let radius = TANKER_TURN_RADIUS as f64;
+    let turn_length = std::f32::consts::PI * TANKER_TURN_RADIUS;
+    let s = distance.rem_euclid(racetrack_lap());
+    let (offset, direction, turn) = if s < TANKER_LEG {
+        // Eastbound leg on the north side
+        (DVec3::new(s as f64 - half_leg, 0.0, -radius), Vec3::X, 0.0)
+    } else if s < TANKER_LEG + turn_length {
+        let a = (s - TANKER_LEG) / TANKER_TURN_RADIUS;
+        let offset = DVec3::new(half_leg + radius * a.sin() as f64, 0.0, -radius * a.cos() as f64);
+        (offset, Vec3::new(a.cos(), 0.0, a.sin()), 1.0)
+    } else if s < 2.0 * TANKER_LEG + turn_length {
+        // Westbound leg on the south side
+        let t = s - TANKER_LEG - turn_length;
+        (DVec3::new(half_leg - t as f64, 0.0, radius), Vec3::NEG_X, 0.0)
+    } else {
+        let a = (s - 2.0 * TANKER_LEG - turn_length) / TANKER_TURN_RADIUS;
+        let offset = DVec3::new(-half_leg - radius * a.sin() as f64, 0.0, radius * a.cos() as f64);
+        (offset, Vec3::new(-a.cos(), 0.0, -a.sin()), 1.0)
+    };
+    (TANKER_ANCHOR + offset, direction, turn)
+}
+
+/// Boom direction (tanker space) when trailed and stowed
+fn boom_trail() -> Vec3 {
+    Vec3::new(0.0, -BOOM_TRAIL_ANGLE.sin(), BOOM_TRAIL_ANGLE.cos())
+}
+
+/// Where the receptacle has to be for the boom to plug in (tanker space)
+fn contact_point() -> Vec3 {
+    BOOM_PIVOT + boom_trail() * BOOM_NOMINAL_LENGTH
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// Put the tanker on station. Built from primitives until a proper model exists.
+fn spawn_tanker(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    origin: Res<WorldOrigin>,
+) {
+    let (position, direction, _) = racetrack_pose(0.0);
+    let paint = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.55, 0.57, 0.6),
+        perceptual_roughness: 0.7,
+        ..default()
+    });
+    let director_lights = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.05, 0.05, 0.05),
+        unlit: true,
+        ..default()
+    });
+    // Anti-collision beacon, so the tanker can be picked out at range and at night
+    let beacon = materials.add(StandardMaterial {
+        base_color: Color::srgb(1.0, 0.1, 0.05),
+        unlit: true,
+        ..default()
+    });
+    let along_z = Quat::from_rotation_x(std::f32::consts::FRAC_PI_2);
+
+    let mut parts = vec![
+        // Fuselage, wing, tailplane and fin
+        (meshes.add(Capsule3d::new(2.2, 36.0)), paint.clone(), Transform::from_rotation(along_z)),
+        (meshes.add(Cuboid::new(46.0, 0.5, 7.0)), paint.clone(), Transform::from_xyz(0.0, -0.5, 1.0)),
+        (meshes.add(Cuboid::new(16.0, 0.4, 4.0)), paint.clone(), Transform::from_xyz(0.0, 1.0, 17.0)),
+        (meshes.add(Cuboid::new(0.5, 8.0, 5.0)), paint.clone(), Transform::from_xyz(0.0, 5.0, 17.0)),
+        (meshes.add(Cuboid::new(2.0, 0.3, 0.6)), director_lights.clone(), Transform::from_xyz(0.0, -2.3, -10.0)),
+        (meshes.add(Cuboid::new(0.6, 0.6, 0.6)), beacon, Transform::from_xyz(0.0, 2.4, -4.0)),
+    ];
+    let engine = meshes.add(Cylinder::new(1.0, 4.5));
+    for x in [-16.0, -9.0, 9.0, 16.0] {
+        parts.push((engine.clone(), paint.clone(), Transform::from_xyz(x, -1.8, -1.0).with_rotation(along_z)));
+    }
+    let boom_mesh = meshes.add(Cuboid::new(0.35, 0.35, 1.0));
+
+    commands.spawn((
+        Tanker {
+            distance: 0.0,
+            bank: 0.0,
+            velocity: direction * TANKER_SPEED,
+            director_lights,
+        },
+        Transform::from_translation(origin.to_local(position)).looking_to(direction, Vec3::Y),
+        Visibility::default(),
+    ))
+    .with_children(|parent| {
+        for (mesh, material, transform) in parts {
+            parent.spawn((Mesh3d(mesh), MeshMaterial3d(material), transform));
+        }
+        // Scaled along Z by the telescoping length; the tube runs aft from the hinge
+        parent.spawn((
+            TankerBoom,
+            Transform::from_translation(BOOM_PIVOT)
+                .with_rotation(Quat::from_rotation_arc(Vec3::Z, boom_trail()))
+                .with_scale(Vec3::new(1.0, 1.0, BOOM_NOMINAL_LENGTH)),
+            Visibility::default(),
+        ))
+        .with_children(|boom| {
+            boom.spawn((Mesh3d(boom_mesh), MeshMaterial3d(paint), Transform::from_xyz(0.0, 0.0, 0.5)));
+        });
+    });
+
+    println!("⛽ TANKER: On station {:.0} km north of {} at {:.0} m, {:.0} m/s",
+        -TANKER_ANCHOR.z / 1000.0, airfield::home_airfield().name(), TANKER_ANCHOR.y, TANKER_SPEED);
+}
+
+/// Burn fuel with the throttle and the afterburner
+fn burn_fuel(
+    time: Res<Time>,
+    mut player_query: Query<(&PlayerInput, &EngineSpool, &mut FuelTank), With<PlayerPlane>>,
+) {
+    let Ok((input, spool, mut tank)) = player_query.get_single_mut() else { return };
+    if spool.flamed_out {
+        return;
+    }
+    let throttle = input.throttle.clamp(0.0, 1.0);
+    let flow = IDLE_FUEL_FLOW + (MIL_FUEL_FLOW - IDLE_FUEL_FLOW) * throttle + AFTERBURNER_FUEL_FLOW * spool.afterburner;
+    tank.burn(flow * time.delta_secs());
+}
+
+/// Fly the racetrack at constant speed, rolling into the turns
+fn fly_tanker(
+    time: Res<Time>,
+    origin: Res<WorldOrigin>,
+    mut tanker_query: Query<(&mut Tanker, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+    for (mut tanker, mut transform) in &mut tanker_query {
+        tanker.distance = (tanker.distance + TANKER_SPEED * dt) % racetrack_lap();
+        let (position, direction, turn) = racetrack_pose(tanker.distance);
+        let target_bank = turn * TANKER_TURN_BANK;
+        let max_roll = TANKER_ROLL_RATE * dt;
+        tanker.bank += (target_bank - tanker.bank).clamp(-max_roll, max_roll);
+        tanker.velocity = direction * TANKER_SPEED;
+
+        // Right wing down in the (right-hand) turns
+        let heading = Transform::IDENTITY.looking_to(direction, Vec3::Y).rotation;
+        *transform = Transform::from_translation(origin.to_local(position))
+            .with_rotation(heading * Quat::from_rotation_z(-tanker.bank));
+    }
+}
+
+/// Latch the boom when the receptacle holds steady in the contact box, pass fuel
+/// while it stays there, and pull out when the receiver wanders off
+fn boom_contact(
+    time: Res<Time>,
+    tanker_query: Query<(&Transform, &Tanker)>,
+    mut player_query: Query<(&Transform, &LinearVelocity, &mut FuelTank), With<PlayerPlane>>,
+    mut resupply: ResMut<Resupply>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut play_sound: EventWriter<PlaySound>,
+    sounds: Res<GameAssets>,
+) {
+    let dt = time.delta_secs();
+    let Ok((player_transform, velocity, mut tank)) = player_query.get_single_mut() else { return };
+    let Ok((tanker_transform, tanker)) = tanker_query.get_single() else { return };
+
+    let receptacle = player_transform.transform_point(RECEPTACLE_OFFSET);
+    let offset = tanker_transform.rotation.inverse() * (receptacle - tanker_transform.transform_point(contact_point()));
+    let closure = (velocity.0 - tanker.velocity).length();
+    let in_box = |margin: f32| offset.abs().cmple(CONTACT_BOX * margin).all();
+    resupply.tanker_range = player_transform.translation.distance(tanker_transform.translation);
+
+    let previous = resupply.boom;
+    if resupply.boom == BoomState::Contact {
+        tank.fill(BOOM_TRANSFER_RATE * dt);
+        // Out of the envelope, closing too fast, or topped off
+        let disconnect = !in_box(DISCONNECT_MARGIN) || closure > DISCONNECT_CLOSURE || tank.is_full();
+        if disconnect {
+            resupply.boom = BoomState::PreContact;
+            resupply.settle = 0.0;
+        }
+    } else {
+        resupply.boom = if offset.length() < PRE_CONTACT_RANGE { BoomState::PreContact } else { BoomState::Clear };
+        let steady = in_box(1.0) && closure < CONTACT_CLOSURE && !tank.is_full();
+        resupply.settle = if steady { resupply.settle + dt } else { 0.0 };
+        if resupply.settle > CONTACT_SETTLE {
+            resupply.boom = BoomState::Contact;
+            // Nozzle latching into the receptacle
+            play_sound.send(PlaySound::new(sounds.afterburner_thump.clone(), AudioBus::Ambient)
+                .with_volume(0.4)
+                .with_speed(1.4));
+        }
+    }
+
+    // Director lights: off, amber while the receiver closes in, green in contact
+    if resupply.boom != previous {
+        if let Some(material) = materials.get_mut(&tanker.director_lights) {
+            material.base_color = match resupply.boom {
+                BoomState::Clear => Color::srgb(0.05, 0.05, 0.05),
+                BoomState::PreContact => Color::srgb(1.0, 0.6, 0.0),
+                BoomState::Contact => Color::srgb(0.1, 1.0, 0.2),
+            };
+        }
+    }
+}
+
+/// The boom operator swings and telescopes the boom onto the receptacle when
+/// it is within reach, and trails it otherwise
+fn steer_boom(
+    time: Res<Time>,
+    resupply: Res<Resupply>,
+    tanker_query: Query<&Transform, (With<Tanker>, Without<TankerBoom>)>,
+    player_query: Query<&Transform, (With<PlayerPlane>, Without<TankerBoom>)>,
+    mut boom_query: Query<&mut Transform, With<TankerBoom>>,
+) {
+    let Ok(tanker_transform) = tanker_query.get_single() else { return };
+    let Ok(mut boom) = boom_query.get_single_mut() else { return };
+
+    let mut target = (boom_trail(), BOOM_NOMINAL_LENGTH);
+    if resupply.boom != BoomState::Clear {
+        if let Ok(player_transform) = player_query.get_single() {
+            let receptacle = player_transform.transform_point(RECEPTACLE_OFFSET);
+            let reach = tanker_transform.rotation.inverse() * (receptacle - tanker_transform.translation) - BOOM_PIVOT;
+            let length = reach.length();
+            let direction = reach / length.max(0.01);
+            if direction.angle_between(boom_trail()) < BOOM_MAX_SWING && length > BOOM_MIN_LENGTH * 0.5 {
+                target = (direction, length.clamp(BOOM_MIN_LENGTH, BOOM_MAX_LENGTH));
+            }
+        }
+    }
+
+    let blend = 1.0 - (-BOOM_SLEW_RATE * time.delta_secs()).exp();
+    let rotation = Quat::from_rotation_arc(Vec3::Z, target.0);
+    boom.rotation = boom.rotation.slerp(rotation, blend);
+    boom.scale.z += (target.1 - boom.scale.z) * blend;
+}
+
+/// Whether the jet is parked, and everything the ground crew tops up
+type ServiceQuery<'w, 's> = Query<'w, 's, (
+    &'static Transform,
+    &'static LinearVelocity,
+    &'static PlayerInput,
+    &'static LandingGear,
+    &'static mut FuelTank,
+    &'static mut Stores,
+    &'static mut space::RocketEngine,
+), With<PlayerPlane>>;
+
+/// Stopped on an airfield with the engine throttled back, the ground crew refuels
+/// the jet (and the rocket) and reloads the rails and the gun
+fn ground_service(
+    time: Res<Time>,
+    origin: Res<WorldOrigin>,
+    mut player_query: ServiceQuery,
+    mut resupply: ResMut<Resupply>,
+) {
+    let dt = time.delta_secs();
+    let Ok((transform, velocity, input, gear, mut tank, mut stores, mut rocket)) = player_query.get_single_mut() else { return };
+
+    let parked = gear.on_ground && velocity.length() < SERVICE_MAX_SPEED && input.throttle < SERVICE_MAX_THROTTLE;
+    let absolute = origin.to_absolute(transform.translation);
+    if !parked || airfield::airfield_at(absolute.x, absolute.z).is_none() {
+        // Rolled off or throttled up: service interrupted
+        resupply.servicing = false;
+        resupply.crew_delay = 0.0;
+        return;
+    }
+
+    let needs_service = tank.fraction() < SERVICE_TOP_UP || !stores.is_full() || rocket.fuel_fraction() < SERVICE_TOP_UP;
+    if !resupply.servicing {
+        if !needs_service {
+            return;
+        }
+        resupply.crew_delay += dt;
+        if resupply.crew_delay < SERVICE_DELAY {
+            return;
+        }
+        resupply.servicing = true;
+        resupply.missiles_due = 0.0;
+        resupply.rounds_due = 0.0;
+    }
+
+    tank.fill(SERVICE_FUEL_RATE * dt);
+    rocket.refill(SERVICE_PROPELLANT_RATE * dt);
+    resupply.missiles_due += SERVICE_MISSILE_RATE * dt;
+    resupply.rounds_due += SERVICE_ROUNDS_RATE * dt;
+    let missiles = resupply.missiles_due as u32;
+    let rounds = resupply.rounds_due as u32;
+    resupply.missiles_due -= missiles as f32;
+    resupply.rounds_due -= rounds as f32;
+    stores.missiles = (stores.missiles + missiles).min(MISSILE_CAPACITY);
+    stores.rounds = (stores.rounds + rounds).min(GUN_ROUNDS);
+
+    if tank.is_full() && stores.is_full() && rocket.fuel_fraction() >= 1.0 {
+        resupply.servicing = false;
+        resupply.crew_delay = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tank_fills_to_capacity_and_burns_to_empty() {
+        let mut tank = FuelTank::default();
+        assert!(tank.is_full());
+        assert_eq!(tank.fill(100.0), 0.0);
+
+        tank.burn(1000.0);
+        assert_eq!(tank.fill(600.0), 600.0);
+        assert_eq!(tank.fill(600.0), 400.0);
+        assert!(tank.is_full());
+
+        tank.burn(FUEL_CAPACITY_KG + 1.0);
+        assert!(tank.is_empty());
+        assert_eq!(tank.fuel, 0.0);
+    }
+
+    #[test]
+    fn racetrack_is_continuous_and_flown_along_its_direction() {
+        const STEP: f32 = 10.0;
+        let lap = racetrack_lap();
+        let mut previous = racetrack_pose(0.0);
+        let mut distance = STEP;
+        while distance <= lap {
+            let pose = racetrack_pose(distance);
+            let moved = (pose.0 - previous.0).as_vec3();
+            // Chord of a turn is a hair shorter than the arc
+            assert!(moved.length() > STEP * 0.99 && moved.length() < STEP * 1.001, "jump at {} m: {:?}", distance, moved);
+            assert!(moved.normalize().dot(pose.1) > 0.99, "flying sideways at {} m", distance);
+            assert_eq!(pose.0.y, TANKER_ANCHOR.y);
+            previous = pose;
+            distance += STEP;
+        }
+
+        let (start, end) = (racetrack_pose(0.0).0, racetrack_pose(lap).0);
+        assert!(start.distance(end) < 0.1, "{:?} != {:?}", start, end);
+    }
+}
@@ -0,0 +1,234 @@
+use std::{fmt::Write as _, io, path::Path};
+
+use bevy::{
+    prelude::*,
+    window::{MonitorSelection, PresentMode, PrimaryWindow, WindowMode},
+};
+use crate::{audio::AudioBus, drone::CombatDirector};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Settings file, loaded at startup and rewritten whenever the settings screen changes something
+pub const SETTINGS_PATH: &str = "config/settings.cfg";
+
+// ============================================================================
+// SETTINGS
+// ============================================================================
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    /// Name in the settings file
+    fn key(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    /// Drones in the swarm a restart throws at the player
+    pub fn swarm_size(self) -> usize {
+        match self {
+            Difficulty::Easy => 10,
+            Difficulty::Normal => 20,
+            Difficulty::Hard => 30,
+        }
+    }
+
+    /// Enemy missiles allowed in the air at once
+    pub fn max_enemy_missiles(self) -> usize {
+        match self {
+            Difficulty::Easy => 1,
+            Difficulty::Normal => 2,
+            Difficulty::Hard => 4,
+        }
+    }
+}
+
+/// Player preferences, persisted to `config/settings.cfg`
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct Settings {
+    // --- Display ---
+    pub fullscreen: bool,
+    pub vsync: bool,
+    // --- Audio (0..1) ---
+    pub master_volume: f32,
+    pub engine_volume: f32,
+    pub weapons_volume: f32,
+    pub ambient_volume: f32,
+    pub voice_volume: f32,
+    // --- Controls ---
+    /// Multiplier on the mouse-aim turn rate
+    pub mouse_aim_sensitivity: f32,
+    /// Let the jet fly a terrain-avoidance recovery by itself
+    pub auto_gcas: bool,
+    // --- Gameplay ---
+    pub difficulty: Difficulty,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            fullscreen: false,
+            vsync: true,
+            master_volume: 0.8,
+            engine_volume: 1.0,
+            weapons_volume: 1.0,
+            ambient_volume: 1.0,
+            voice_volume: 1.0,
+            mouse_aim_sensitivity: 1.0,
+            auto_gcas: true,
+            difficulty: Difficulty::Normal,
+        }
+    }
+}
+
+impl Settings {
+    /// Volume multiplier for everything on a mixer bus
+    pub fn bus_gain(&self, bus: AudioBus) -> f32 {
+        let bus_volume = match bus {
+            AudioBus::Engine => self.engine_volume,
+            AudioBus::Weapons => self.weapons_volume,
+            AudioBus::Ambient => self.ambient_volume,
+            AudioBus::Voice => self.voice_volume,
+        };
+        self.master_volume * bus_volume
+    }
+
+    /// Defaults overlaid with whatever the file sets. A missing file is written
+    /// out with the defaults so there is something to edit.
+    fn load(path: &Path) -> Settings {
+        let mut settings = Settings::default();
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                match settings.save(path) {
+                    Ok(()) => println!("⚙️  SETTINGS: Wrote defaults to {}", path.display()),
+                    Err(err) => eprintln!("⚠️  SETTINGS: Could not write {}: {}", path.display(), err),
+                }
+                return settings;
+            }
+            Err(err) => {
+                eprintln!("⚠️  SETTINGS: Could not read {}: {} - using defaults", path.display(), err);
+                return settings;
+            }
+        };
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((name, value)) = line.split_once('=') else {
+                eprintln!("⚠️  SETTINGS: {}:{}: expected `name = value`", path.display(), number + 1);
+                continue;
+            };
+            let (name, value) = (name.trim(), value.trim());
+            let volume = || value.parse::<f32>().ok().filter(|v| v.is_finite()).map(|v| v.clamp(0.0, 1.0));
+
+            let parsed = match name {
+                "fullscreen" => value.parse().ok().map(|v| settings.fullscreen = v),
+                "vsync" => value.parse().ok().map(|v| settings.vsync = v),
+                "master_volume" => volume().map(|v| settings.master_volume = v),
+                "engine_volume" => volume().map(|v| settings.engine_volume = v),
+                "weapons_volume" => volume().map(|v| settings.weapons_volume = v),
+                "ambient_volume" => volume().map(|v| settings.ambient_volume = v),
+                "voice_volume" => volume().map(|v| settings.voice_volume = v),
+                "mouse_aim_sensitivity" => value.parse::<f32>().ok()
+                    .filter(|v| v.is_finite())
+                    .map(|v| settings.mouse_aim_sensitivity = v.clamp(0.25, 3.0)),
+                "auto_gcas" => value.parse().ok().map(|v| settings.auto_gcas = v),
+                "difficulty" => Difficulty::ALL.into_iter()
+                    .find(|difficulty| difficulty.key() == value)
+                    .map(|v| settings.difficulty = v),
+                _ => {
+                    eprintln!("⚠️  SETTINGS: {}:{}: unknown setting `{}`", path.display(), number + 1, name);
+                    continue;
+                }
+            };
+            if parsed.is_none() {
+                eprintln!("⚠️  SETTINGS: {}:{}: bad value `{}` for {}", path.display(), number + 1, value, name);
+            }
+        }
+        println!("⚙️  SETTINGS: Loaded {}", path.display());
+        settings
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut text = String::new();
+        text.push_str("# ViperEye settings (Settings in the main or pause menu)\n\n");
+        let _ = writeln!(text, "fullscreen = {}", self.fullscreen);
+        let _ = writeln!(text, "vsync = {}", self.vsync);
+        let _ = writeln!(text, "master_volume = {:.2}", self.master_volume);
+        let _ = writeln!(text, "engine_volume = {:.2}", self.engine_volume);
+        let _ = writeln!(text, "weapons_volume = {:.2}", self.weapons_volume);
+        let _ = writeln!(text, "ambient_volume = {:.2}", self.ambient_volume);
+        let _ = writeln!(text, "voice_volume = {:.2}", self.voice_volume);
+        let _ = writeln!(text, "mouse_aim_sensitivity = {:.2}", self.mouse_aim_sensitivity);
+        let _ = writeln!(text, "auto_gcas = {}", self.auto_gcas);
+        let _ = writeln!(text, "difficulty = {}", self.difficulty.key());
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, text)
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        // Applied on the first frame (resource_changed sees the insert) and after every edit
+        app.insert_resource(Settings::load(Path::new(SETTINGS_PATH)))
+            .add_systems(Update, apply_settings.run_if(resource_changed::<Settings>));
+    }
+}
+
+/// Push the settings into the window and the enemy (the audio mixer reads
+/// the volumes itself every frame)
+fn apply_settings(
+    settings: Res<Settings>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut combat_director: ResMut<CombatDirector>,
+) {
+    if let Ok(mut window) = windows.get_single_mut() {
+        let mode = if settings.fullscreen {
+            WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+        } else {
+            WindowMode::Windowed
+        };
+        if window.mode != mode {
+            window.mode = mode;
+        }
+        let present_mode = if settings.vsync { PresentMode::AutoVsync } else { PresentMode::AutoNoVsync };
+        if window.present_mode != present_mode {
+            window.present_mode = present_mode;
+        }
+    }
+
+    combat_director.max_missiles = settings.difficulty.max_enemy_missiles();
+}
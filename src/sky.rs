@@ -0,0 +1,134 @@
+use bevy::{
+    pbr::{MaterialPipeline, MaterialPipelineKey, NotShadowCaster},
+    prelude::*,
+    render::{
+        camera::Exposure,
+        mesh::MeshVertexBufferLayoutRef,
+        render_resource::{AsBindGroup, RenderPipelineDescriptor, ShaderRef, SpecializedMeshPipelineError},
+    },
+};
+use crate::{
+    biome::smoothstep,
+    time_of_day::{TimeOfDay, DAY_EV100},
+    weather::Weather,
+    GameState, world_active,
+};
+
+// ============================================================================
+// SKY MATERIAL
+// ============================================================================
+
+/// Single-scattering Rayleigh/Mie atmosphere drawn on a camera-centered dome.
+/// The vertex shader pins the dome to the far plane, so it sits behind everything
+/// regardless of altitude; from orbit it shows the curved limb of the planet.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+pub struct SkyMaterial {
+    /// xyz: unit vector toward the sun, w: sun disc brightness
+    #[uniform(0)]
+    pub sun: Vec4,
+    /// Fog color the sky blends into near the horizon (a: blend strength)
+    #[uniform(0)]
+    pub horizon_color: LinearRgba,
+    /// x: camera altitude (m), y: 1 / daytime exposure, z: overcast (0..1), w: star visibility
+    #[uniform(0)]
+    pub params: Vec4,
+}
+
+impl Material for SkyMaterial {
+    fn vertex_shader() -> ShaderRef {
+        "shaders/sky.wgsl".into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        "shaders/sky.wgsl".into()
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline<Self>,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayoutRef,
+        _key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        // Seen from inside, and never occludes anything
+        descriptor.primitive.cull_mode = None;
+        if let Some(depth_stencil) = descriptor.depth_stencil.as_mut() {
+            depth_stencil.depth_write_enabled = false;
+        }
+        Ok(())
+    }
+}
+
+/// The sky dome (follows the camera)
+#[derive(Component)]
+struct SkyDome;
+
+pub struct SkyPlugin;
+
+impl Plugin for SkyPlugin {
+    fn build(&self, app: &mut App) {
+        // No prepass/shadows: the dome must not write scene depth or cast shadows
+        app.add_plugins(MaterialPlugin::<SkyMaterial> {
+            prepass_enabled: false,
+            shadows_enabled: false,
+            ..default()
+        })
+        .add_systems(OnEnter(GameState::Spawning), setup_sky)
+        .add_systems(Update, update_sky.run_if(world_active));
+    }
+}
+
+fn setup_sky(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<SkyMaterial>>,
+) {
+    commands.spawn((
+        SkyDome,
+        // Radius only matters for culling: the shader pushes it to the far plane
+        Mesh3d(meshes.add(Sphere::new(1000.0).mesh().ico(4).unwrap())),
+        MeshMaterial3d(materials.add(SkyMaterial {
+            sun: TimeOfDay::default().sun_direction().extend(20.0),
+            horizon_color: LinearRgba::from(Color::srgb(0.5, 0.6, 0.8)),
+            params: Vec4::new(0.0, 1.0 / Exposure { ev100: DAY_EV100 }.exposure(), 0.0, 0.0),
+        })),
+        Transform::default(),
+        NotShadowCaster,
+    ));
+    eprintln!("🌌 STARTUP: Atmospheric scattering sky created");
+}
+
+/// The camera the dome follows, with the fog it blends into
+type SkyCameraQuery<'w, 's> = Query<'w, 's, (&'static Transform, &'static DistanceFog), (With<Camera3d>, Without<SkyDome>)>;
+
+/// Keep the dome on the camera and feed it the sun, fog and weather
+fn update_sky(
+    time_of_day: Res<TimeOfDay>,
+    weather: Res<Weather>,
+    camera_query: SkyCameraQuery,
+    mut dome_query: Query<(&mut Transform, &MeshMaterial3d<SkyMaterial>), With<SkyDome>>,
+    mut materials: ResMut<Assets<SkyMaterial>>,
+) {
+    let Ok((camera, fog)) = camera_query.get_single() else { return };
+    let Ok((mut dome_transform, material)) = dome_query.get_single_mut() else { return };
+    dome_transform.translation = camera.translation;
+
+    let Some(sky) = materials.get_mut(&material.0) else { return };
+    let altitude = camera.translation.y.max(0.0);
+    let sun_dir = time_of_day.sun_direction();
+
+    // Overcast hides the sun disc and flattens the sky, but only from below the clouds
+    let below_clouds = 1.0 - smoothstep(weather.cloud_base, weather.cloud_base + weather.cloud_thickness, altitude);
+    let overcast = (weather.coverage - 0.5).max(0.0) * 2.0 * below_clouds;
+
+    sky.sun = sun_dir.extend(20.0 * (1.0 - overcast * 0.95));
+    // Blend into the fog only while there is enough air for fog to make sense
+    let mut horizon = LinearRgba::from(fog.color);
+    horizon.alpha = 1.0 - smoothstep(8000.0, 20000.0, altitude);
+    sky.horizon_color = horizon;
+
+    let night = 1.0 - smoothstep(-0.2, 0.0, sun_dir.y);
+    let space = smoothstep(15000.0, 40000.0, altitude);
+    sky.params.x = altitude;
+    sky.params.z = overcast;
+    sky.params.w = night.max(space) * (1.0 - overcast);
+}
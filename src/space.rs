@@ -0,0 +1,262 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use crate::{
+    biome::smoothstep,
+    particles::{EmitParticles, ParticleType},
+    FlightControlComputer, GameState, PlayerInput, PlayerPlane, MASS_KG,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Where the game's "space" begins (GAME_DESIGN.md: 1/4 of the real Kármán line).
+/// Wings and jet intake have nothing left to work with above this.
+pub const KARMAN_LINE: f32 = 25_000.0;
+/// Aerodynamic forces start thinning out here
+pub const UPPER_ATMOSPHERE: f32 = 15_000.0;
+/// Beyond this the jet is considered lost and is reset
+pub const ALTITUDE_LIMIT: f32 = 1_000_000.0;
+
+/// Planet radius used for gravity falloff and orbital speed. The terrain is flat,
+/// so this is a 1/10 scale Earth: orbit is ~2.4 km/s instead of ~7.8 km/s.
+const PLANET_RADIUS: f32 = 637_100.0;
+const SURFACE_GRAVITY: f32 = 9.81;
+
+const ROCKET_THRUST: f32 = 400_000.0; // Newtons (~4.5 g on the jet)
+const ROCKET_PROPELLANT_KG: f32 = 4000.0;
+const ROCKET_BURN_RATE: f32 = 40.0; // kg/s at full throttle (100 s of burn)
+
+/// Angular acceleration the attitude thrusters give at full stick (rad/s²)
+const RCS_ANGULAR_ACCEL: f32 = 1.2;
+const RCS_BURN_RATE: f32 = 0.5; // kg/s per axis at full deflection
+
+/// Density scale height for re-entry heating (meters)
+const HEATING_SCALE_HEIGHT: f32 = 7000.0;
+/// Heating index where the plasma sheath first shows, and where it is fully developed
+const HEATING_ONSET: f32 = 0.15;
+const HEATING_FULL: f32 = 1.0;
+/// Thermal lag of the airframe (fraction per second toward the current heating)
+const HEAT_SOAK_RATE: f32 = 1.5;
+
+// ============================================================================
+// COMPONENTS
+// ============================================================================
+
+/// Booster rocket strapped to the jet (secret `R` key). Unlike the turbine it
+/// works without air, but only while there is propellant left.
+#[derive(Component)]
+pub struct RocketEngine {
+    pub enabled: bool,
+    /// Remaining propellant (kg)
+    pub propellant: f32,
+}
+
+impl Default for RocketEngine {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            propellant: ROCKET_PROPELLANT_KG,
+        }
+    }
+}
+
+impl RocketEngine {
+    /// Remaining propellant as a fraction of a full tank
+    pub fn fuel_fraction(&self) -> f32 {
+        self.propellant / ROCKET_PROPELLANT_KG
+    }
+
+    /// Tops the propellant up by `kg` (ground crew), never past a full tank
+    pub fn refill(&mut self, kg: f32) {
+        self.propellant = (self.propellant + kg).min(ROCKET_PROPELLANT_KG);
+    }
+
+    /// Burns `kg` of propellant; returns the fraction actually available (0..1)
+    fn burn(&mut self, kg: f32) -> f32 {
+        if kg <= 0.0 {
+            return 1.0;
+        }
+        let available = self.propellant.min(kg);
+        self.propellant -= available;
+        available / kg
+    }
+}
+
+/// How hot the airframe is from compression heating (0 = cold, 1 = full plasma sheath)
+#[derive(Component, Default)]
+pub struct ReentryHeat {
+    pub glow: f32,
+}
+
+/// Point light on the nose that glows with `ReentryHeat`
+#[derive(Component)]
+pub struct ReentryGlow;
+
+pub struct SpacePlugin;
+
+impl Plugin for SpacePlugin {
+    fn build(&self, app: &mut App) {
+        // After the flight model: it clears the jet's forces each frame and only
+        // steers with aerodynamic authority, which these systems fill in above it
+        app.add_systems(Update, (
+            apply_rocket_thrust,
+            reaction_control,
+            apply_orbital_gravity,
+            update_reentry_heating,
+        ).after(crate::arcade_flight_physics).run_if(in_state(GameState::Playing)));
+    }
+}
+
+// ============================================================================
+// ATMOSPHERE & GRAVITY
+// ============================================================================
+
+/// How much air the wings and intake have to work with: 1 below the upper
+/// atmosphere, fading to 0 at the Kármán line
+pub fn atmosphere_factor(altitude: f32) -> f32 {
+    1.0 - smoothstep(UPPER_ATMOSPHERE, KARMAN_LINE, altitude)
+}
+
+/// Upward acceleration that turns the physics engine's constant 9.81 m/s² into
+/// inverse-square gravity minus the centrifugal term of horizontal speed around
+/// the (virtual) curved planet. Fast enough sideways and the jet stops falling.
+pub fn gravity_relief(altitude: f32, velocity: Vec3) -> f32 {
+    let radius = PLANET_RADIUS + altitude.max(0.0);
+    let gravity = SURFACE_GRAVITY * (PLANET_RADIUS / radius).powi(2);
+    let horizontal_sq = velocity.x * velocity.x + velocity.z * velocity.z;
+    (SURFACE_GRAVITY - gravity + horizontal_sq / radius).min(2.0 * SURFACE_GRAVITY)
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// Rocket thrust along the nose, burning propellant with the throttle
+fn apply_rocket_thrust(
+    time: Res<Time>,
+    mut player_query: Query<(&PlayerInput, &Transform, &mut ExternalForce, &mut RocketEngine), With<PlayerPlane>>,
+) {
+    for (input, transform, mut ext_force, mut rocket) in &mut player_query {
+        if !rocket.enabled {
+            continue;
+        }
+        let throttle = input.throttle.clamp(0.0, 1.0);
+        let available = rocket.burn(throttle * ROCKET_BURN_RATE * time.delta_secs());
+        if rocket.propellant <= 0.0 {
+            rocket.enabled = false; // Burnt out; the HUD shows RKT: 0%
+        }
+        ext_force.apply_force(transform.forward().as_vec3() * throttle * ROCKET_THRUST * available);
+    }
+}
+
+/// Attitude thrusters take over as the air runs out. They accelerate rotation
+/// instead of setting a rate, so a spin keeps going until it is countered; with
+/// SAS on, releasing the stick fires them to null the rotation.
+fn reaction_control(
+    time: Res<Time>,
+    mut particles: EventWriter<EmitParticles>,
+    mut player_query: Query<(
+        &PlayerInput,
+        &Transform,
+        &mut AngularVelocity,
+        &FlightControlComputer,
+        &mut RocketEngine,
+    ), With<PlayerPlane>>,
+) {
+    let dt = time.delta_secs();
+    for (input, transform, mut ang_vel, fbw, mut rocket) in &mut player_query {
+        let authority = 1.0 - atmosphere_factor(transform.translation.y);
+        if authority <= 0.0 || rocket.propellant <= 0.0 {
+            continue;
+        }
+
+        let right = transform.right().as_vec3();
+        let up = transform.up().as_vec3();
+        let forward = transform.forward().as_vec3();
+
+        // Per-axis command in the jet's frame (pitch, yaw, roll)
+        let mut command = Vec3::new(input.pitch, input.yaw, input.roll);
+        if fbw.sas_enabled {
+            let local_rate = Vec3::new(ang_vel.0.dot(right), ang_vel.0.dot(up), ang_vel.0.dot(forward));
+            for axis in 0..3 {
+                if command[axis].abs() < 0.05 {
+                    command[axis] = (-local_rate[axis] / (RCS_ANGULAR_ACCEL * dt).max(0.001)).clamp(-1.0, 1.0);
+                }
+            }
+        }
+        let usage = command.abs().element_sum();
+        if usage < 0.01 {
+            continue;
+        }
+
+        let available = rocket.burn(usage * RCS_BURN_RATE * dt);
+        let accel = (right * command.x + up * command.y + forward * command.z) * RCS_ANGULAR_ACCEL * authority * available;
+        ang_vel.0 += accel * dt;
+
+        // Puffs from the nose and wingtip quads on the side opposite the push
+        if rand::random::<f32>() < usage * 0.5 {
+            let nose = transform.translation + forward * 6.0;
+            let tip = transform.translation + right * 4.5 * command.z.signum();
+            for (position, push) in [(nose, up * -command.x + right * -command.y), (tip, up * -command.z.abs())] {
+                if push.length_squared() > 0.01 {
+                    particles.send(EmitParticles::new(ParticleType::RcsPuff, position).with_velocity(push * 15.0, 2.0));
+                }
+            }
+        }
+    }
+}
+
+/// Curved-planet gravity and centrifugal relief on top of the flat-world gravity
+fn apply_orbital_gravity(
+    mut player_query: Query<(&Transform, &LinearVelocity, &mut ExternalForce), With<PlayerPlane>>,
+) {
+    for (transform, velocity, mut ext_force) in &mut player_query {
+        let relief = gravity_relief(transform.translation.y, velocity.0);
+        if relief.is_finite() {
+            ext_force.apply_force(Vec3::Y * relief * MASS_KG);
+        }
+    }
+}
+
+/// Compression heating grows with air density and the cube of speed; the airframe
+/// soaks it up with a lag and sheds a plasma sheath while hot
+fn update_reentry_heating(
+    time: Res<Time>,
+    mut particles: EventWriter<EmitParticles>,
+    mut player_query: Query<(Entity, &Transform, &LinearVelocity, &mut ReentryHeat), With<PlayerPlane>>,
+    mut glow_query: Query<&mut PointLight, With<ReentryGlow>>,
+) {
+    let Ok((entity, transform, velocity, mut heat)) = player_query.get_single_mut() else { return };
+    let altitude = transform.translation.y.max(0.0);
+    let speed = velocity.0.length();
+
+    let density = (-altitude / HEATING_SCALE_HEIGHT).exp();
+    let heating = density * (speed / 1000.0).powi(3);
+    let target = smoothstep(HEATING_ONSET, HEATING_FULL, heating);
+    let rate = (HEAT_SOAK_RATE * time.delta_secs()).min(1.0);
+    heat.glow += (target - heat.glow) * rate;
+
+    for mut light in &mut glow_query {
+        light.intensity = heat.glow * 2_000_000.0;
+    }
+
+    if heat.glow < 0.05 || speed < 1.0 {
+        return;
+    }
+    // Sheath hugs the leading surfaces and streams off behind the jet
+    let flow = -velocity.0 / speed;
+    let count = (heat.glow * 6.0).ceil() as u32;
+    particles.send(
+        EmitParticles::new(ParticleType::ReentryPlasma, transform.rotation.inverse() * -flow * 4.0)
+            .attached_to(entity)
+            .with_count(count)
+            .with_size(0.5 + heat.glow),
+    );
+    particles.send(
+        EmitParticles::new(ParticleType::ReentryPlasma, transform.translation)
+            .with_velocity(velocity.0 + flow * 150.0, 20.0)
+            .with_count(count)
+            .with_size(heat.glow * 2.0),
+    );
+}
@@ -0,0 +1,260 @@
+use bevy::{color::Mix, prelude::*, render::camera::Exposure};
+use crate::{
+    biome::smoothstep,
+    controls::{Action, ActionState},
+    graphics::GraphicsSettings,
+    GameState, world_active,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Direct sunlight at noon (matches the camera's `Exposure::SUNLIGHT`)
+const SUN_ILLUMINANCE: f32 = 100_000.0;
+/// Artistic moonlight: far brighter than reality so night flying stays playable
+const MOON_ILLUMINANCE: f32 = 1_500.0;
+/// Camera exposure by day and by night (lower EV100 = more sensitive)
+pub const DAY_EV100: f32 = 15.0;
+const NIGHT_EV100: f32 = 10.0;
+/// The moon disc sits this far from the camera (the sky itself is drawn behind everything)
+const MOON_DISTANCE: f32 = 18000.0;
+const LANTERN_INTENSITY: f32 = 400_000.0;
+
+// ============================================================================
+// RESOURCES & COMPONENTS
+// ============================================================================
+
+/// Game clock driving the sun, moon, sky colors and night lighting.
+/// Missions set the start hour and time scale; `[` / `]` slow down / speed up time.
+#[derive(Resource, Clone, Debug)]
+pub struct TimeOfDay {
+    /// Hour of day in 0..24
+    pub hour: f32,
+    /// Game seconds per real second (1.0 = real time, 60.0 = a full day in 24 minutes)
+    pub time_scale: f32,
+    /// Tilt of the sun's arc away from vertical (radians); 0.0 = sun passes overhead
+    pub axial_tilt: f32,
+}
+
+impl Default for TimeOfDay {
+    fn default() -> Self {
+        Self::new(14.0, 1.0)
+    }
+}
+
+impl TimeOfDay {
+    pub fn new(hour: f32, time_scale: f32) -> Self {
+        Self { hour: hour.rem_euclid(24.0), time_scale, axial_tilt: 0.6 }
+    }
+
+    /// Unit vector pointing from the world toward the sun. Rises in +X at 06:00, sets in -X at 18:00.
+    pub fn sun_direction(&self) -> Vec3 {
+        let angle = (self.hour - 6.0) / 24.0 * std::f32::consts::TAU;
+        Vec3::new(
+            angle.cos(),
+            angle.sin() * self.axial_tilt.cos(),
+            -angle.sin() * self.axial_tilt.sin(),
+        ).normalize()
+    }
+
+    /// Moon rides opposite the sun
+    pub fn moon_direction(&self) -> Vec3 {
+        -self.sun_direction()
+    }
+
+    /// 1.0 in full daylight, 0.0 at night, blending through twilight
+    pub fn daylight(&self) -> f32 {
+        smoothstep(-0.1, 0.15, self.sun_direction().y)
+    }
+
+    /// 0.0 away from the horizon, peaking at 1.0 while the sun is rising or setting
+    pub fn twilight(&self) -> f32 {
+        let elevation = self.sun_direction().y;
+        (1.0 - (elevation / 0.25).abs()).clamp(0.0, 1.0)
+    }
+
+    /// Horizon sky color for the current time (fog, clear color and sky tint)
+    pub fn sky_color(&self) -> Color {
+        let day = LinearRgba::from(Color::srgb(0.5, 0.6, 0.8));
+        let dusk = LinearRgba::from(Color::srgb(0.85, 0.5, 0.35));
+        let night = LinearRgba::from(Color::srgb(0.02, 0.03, 0.07));
+        let base = night.mix(&day, self.daylight());
+        Color::from(base.mix(&dusk, self.twilight() * 0.7))
+    }
+
+    fn sun_color(&self) -> Color {
+        // Warm, low sun near the horizon; white at midday
+        let warmth = smoothstep(0.5, 0.0, self.sun_direction().y);
+        Color::srgb(1.0, 1.0 - warmth * 0.35, 1.0 - warmth * 0.6)
+    }
+
+    /// "HH:MM" for the HUD
+    pub fn clock_string(&self) -> String {
+        let minutes = (self.hour * 60.0) as u32;
+        format!("{:02}:{:02}", minutes / 60 % 24, minutes % 60)
+    }
+}
+
+/// The main `DirectionalLight` (spawned in `setup_scene`)
+#[derive(Component)]
+pub struct SunLight;
+
+/// Soft directional light from the moon, no shadows
+#[derive(Component)]
+struct MoonLight;
+
+/// Visual moon disc (follows the camera like the sun disc)
+#[derive(Component)]
+struct MoonMarker;
+
+/// Village lantern light, switched on after dusk
+#[derive(Component)]
+pub struct Lantern;
+
+pub struct TimeOfDayPlugin;
+
+impl Plugin for TimeOfDayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimeOfDay>()
+            .add_systems(OnEnter(GameState::Spawning), setup_night_sky)
+            .add_systems(Update, (
+                time_of_day_controls,
+                advance_time_of_day,
+                update_sun_and_moon,
+                update_sky_lighting,
+                update_lanterns,
+            ).chain().run_if(world_active));
+    }
+}
+
+// ============================================================================
+// SETUP
+// ============================================================================
+
+fn setup_night_sky(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        MoonLight,
+        DirectionalLight {
+            illuminance: 0.0,
+            color: Color::srgb(0.7, 0.8, 1.0),
+            shadows_enabled: false,
+            ..default()
+        },
+        Transform::default(),
+    ));
+
+    commands.spawn((
+        MoonMarker,
+        Mesh3d(meshes.add(Sphere::new(700.0))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(0.85, 0.87, 0.9),
+            emissive: LinearRgba::rgb(60.0, 62.0, 70.0),
+            unlit: true,
+            fog_enabled: false,
+            ..default()
+        })),
+        Transform::default(),
+    ));
+
+    eprintln!("🌙 STARTUP: Moon created");
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+fn time_of_day_controls(
+    actions: Res<ActionState>,
+    mut time_of_day: ResMut<TimeOfDay>,
+) {
+    if actions.just_pressed(Action::TimeFaster) {
+        time_of_day.time_scale = (time_of_day.time_scale * 4.0).clamp(1.0, 3600.0);
+        println!("🕐 Time x{:.0} ({})", time_of_day.time_scale, time_of_day.clock_string());
+    }
+    if actions.just_pressed(Action::TimeSlower) {
+        time_of_day.time_scale = (time_of_day.time_scale / 4.0).max(1.0);
+        println!("🕐 Time x{:.0} ({})", time_of_day.time_scale, time_of_day.clock_string());
+    }
+}
+
+fn advance_time_of_day(time: Res<Time>, mut time_of_day: ResMut<TimeOfDay>) {
+    let hours = time.delta_secs() * time_of_day.time_scale / 3600.0;
+    time_of_day.hour = (time_of_day.hour + hours).rem_euclid(24.0);
+}
+
+/// The camera the moon disc is placed around
+type SkyCameraQuery<'w, 's> = Query<'w, 's, &'static Transform,
+    (With<Camera3d>, Without<MoonMarker>, Without<SunLight>, Without<MoonLight>)>;
+/// The sun's or moon's directional light, aimed each frame
+type SunLightQuery<'w, 's> = Query<'w, 's, (&'static mut DirectionalLight, &'static mut Transform),
+    (With<SunLight>, Without<MoonLight>, Without<MoonMarker>)>;
+type MoonLightQuery<'w, 's> = Query<'w, 's, (&'static mut DirectionalLight, &'static mut Transform),
+    (With<MoonLight>, Without<SunLight>, Without<MoonMarker>)>;
+
+/// Aim the sun/moon lights and keep the moon disc at the matching spot in the sky.
+/// The sun disc itself is drawn by the sky shader.
+fn update_sun_and_moon(
+    time_of_day: Res<TimeOfDay>,
+    camera_query: SkyCameraQuery,
+    mut sun_light: SunLightQuery,
+    mut moon_light: MoonLightQuery,
+    mut moon_disc: Query<(&mut Transform, &mut Visibility), With<MoonMarker>>,
+    graphics: Res<GraphicsSettings>,
+) {
+    let sun_dir = time_of_day.sun_direction();
+    let moon_dir = time_of_day.moon_direction();
+    let daylight = time_of_day.daylight();
+
+    // DirectionalLight shines along its forward (-Z): point it from the sky toward the ground
+    if let Ok((mut light, mut transform)) = sun_light.get_single_mut() {
+        *transform = Transform::default().looking_to(-sun_dir, Vec3::Y);
+        light.illuminance = SUN_ILLUMINANCE * daylight;
+        light.color = time_of_day.sun_color();
+        light.shadows_enabled = graphics.shadows && daylight > 0.05;
+    }
+    if let Ok((mut light, mut transform)) = moon_light.get_single_mut() {
+        *transform = Transform::default().looking_to(-moon_dir, Vec3::Y);
+        light.illuminance = MOON_ILLUMINANCE * smoothstep(0.0, 0.2, moon_dir.y) * (1.0 - daylight);
+    }
+
+    let Ok(cam) = camera_query.get_single() else { return };
+    if let Ok((mut transform, mut visibility)) = moon_disc.get_single_mut() {
+        transform.translation = cam.translation + moon_dir * MOON_DISTANCE;
+        *visibility = if moon_dir.y > -0.1 { Visibility::Inherited } else { Visibility::Hidden };
+    }
+}
+
+/// Ambient light and camera exposure follow the sun
+fn update_sky_lighting(
+    time_of_day: Res<TimeOfDay>,
+    mut ambient: ResMut<AmbientLight>,
+    mut exposure_query: Query<&mut Exposure, With<Camera3d>>,
+) {
+    let daylight = time_of_day.daylight();
+
+    ambient.brightness = 150.0 + (2500.0 - 150.0) * daylight;
+    ambient.color = Color::from(
+        LinearRgba::from(Color::srgb(0.3, 0.35, 0.6)).mix(&LinearRgba::from(Color::srgb(0.8, 0.85, 1.0)), daylight),
+    );
+
+    if let Ok(mut exposure) = exposure_query.get_single_mut() {
+        exposure.ev100 = NIGHT_EV100 + (DAY_EV100 - NIGHT_EV100) * daylight;
+    }
+}
+
+fn update_lanterns(
+    time_of_day: Res<TimeOfDay>,
+    mut lanterns: Query<&mut PointLight, With<Lantern>>,
+) {
+    let intensity = LANTERN_INTENSITY * (1.0 - time_of_day.daylight());
+    for mut light in &mut lanterns {
+        if light.intensity != intensity {
+            light.intensity = intensity;
+        }
+    }
+}
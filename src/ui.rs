@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use crate::{PlayerPlane, drone::Drone, GameState, assets::GameAssets};
+use crate::{PlayerPlane, PlayerInput, drone::Drone, GameState, world_active, assets::GameAssets, space, gpws, landing_gear, resupply};
 use avian3d::prelude::LinearVelocity;
 
 #[derive(Component)]
@@ -11,12 +11,29 @@ pub struct AltText;
 #[derive(Component)]
 pub struct ThreatText;
 
+/// Rocket propellant / RCS readout (blank until the rocket is lit or the air runs out)
 #[derive(Component)]
-pub struct PauseText;
+pub struct SpaceText;
 
+/// Radar altitude (height above the ground below) and the Auto-GCAS flag
+#[derive(Component)]
+pub struct AglText;
+
+/// Gear, flaps and brakes, plus the grade of the last touchdown for a few seconds
+#[derive(Component)]
+pub struct GearText;
+
+/// Jet fuel and weapons remaining, with the tanker range or boom/ground-crew status
+#[derive(Component)]
+pub struct FuelText;
+
+/// Seconds the touchdown grade stays on the HUD
+const TOUCHDOWN_READOUT_TIME: f32 = 8.0;
+
+/// Ground proximity level the altitude readout is colored for
 #[derive(Component)]
 pub struct AltitudeWarningState {
-    pub is_warning: bool,
+    pub alert: gpws::TerrainAlert,
 }
 
 pub struct UiPlugin;
@@ -25,9 +42,12 @@ impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(GameState::Spawning), setup_hud)
            .add_systems(Update, (
-               update_hud.run_if(in_state(GameState::Playing)),
-               update_pause_visibility, // Runs always to toggle visibility
-           ));
+               update_hud,
+               update_agl_readout,
+               update_gear_readout,
+               update_fuel_readout,
+               update_space_readout,
+           ).run_if(world_active));
     }
 }
 
@@ -61,7 +81,7 @@ fn setup_hud(
             SpeedText,
         ));
 
-        // Altitude text (changes to red when low)
+        // Altitude text (amber/red with the ground proximity alert)
         parent.spawn((
             Text::new("ALT: 0 m"),
             TextFont {
@@ -70,7 +90,51 @@ fn setup_hud(
             },
             TextColor(Color::srgb(0.0, 1.0, 0.0)), // Start green
             AltText,
-            AltitudeWarningState { is_warning: false },
+            AltitudeWarningState { alert: gpws::TerrainAlert::Clear },
+        ));
+
+        // Radar altitude
+        parent.spawn((
+            Text::new("AGL: ---"),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.0, 1.0, 0.0)), // HUD Green
+            AglText,
+        ));
+
+        // Landing configuration
+        parent.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.0, 1.0, 0.0)), // HUD Green
+            GearText,
+        ));
+
+        // Fuel & stores
+        parent.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.0, 1.0, 0.0)), // HUD Green
+            FuelText,
+        ));
+
+        // Rocket / space readout
+        parent.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.6, 0.1)), // Rocket orange
+            SpaceText,
         ));
     });
 
@@ -90,24 +154,6 @@ fn setup_hud(
         },
         ThreatText,
     ));
-
-    // Center: Pause Indicator (initially hidden)
-    commands.spawn((
-        Text::new("PAUSED\n\nPress P to Resume"),
-        TextFont {
-            font_size: 40.0,
-            ..default()
-        },
-        TextColor(Color::srgb(1.0, 1.0, 0.0)), // Yellow warning
-        Node {
-            position_type: PositionType::Absolute,
-            top: Val::Percent(40.0),
-            left: Val::Percent(50.0),
-            ..default()
-        },
-        Visibility::Hidden, // Start hidden
-        PauseText,
-    ));
 }
 
 fn update_hud(
@@ -116,6 +162,7 @@ fn update_hud(
     mut threat_query: Query<&mut Text, (With<ThreatText>, Without<SpeedText>, Without<AltText>)>,
     player_query: Query<(&Transform, &LinearVelocity), With<PlayerPlane>>,
     drone_query: Query<&Drone>,
+    proximity: Res<gpws::GroundProximity>,
 ) {
     // Update Flight Data
     if let Ok((transform, velocity)) = player_query.get_single() {
@@ -131,17 +178,14 @@ fn update_hud(
         if let Ok((mut text, mut color, mut warning_state)) = alt_query.get_single_mut() {
             text.0 = format!("ALT: {:.0} m", altitude);
 
-            // WARNING THRESHOLD: Same as old audio trigger
-            const ALTITUDE_WARNING_THRESHOLD: f32 = 100.0;
-            let should_warn = altitude < ALTITUDE_WARNING_THRESHOLD;
-
+            // Colored by the ground proximity computer (terrain ahead), not absolute height
             // Only update color if state changed (performance)
-            if should_warn != warning_state.is_warning {
-                warning_state.is_warning = should_warn;
-                color.0 = if should_warn {
-                    Color::srgb(1.0, 0.0, 0.0) // Red warning
-                } else {
-                    Color::srgb(0.0, 1.0, 0.0) // Green normal
+            if proximity.alert != warning_state.alert {
+                warning_state.alert = proximity.alert;
+                color.0 = match proximity.alert {
+                    gpws::TerrainAlert::Clear => Color::srgb(0.0, 1.0, 0.0), // Green normal
+                    gpws::TerrainAlert::Caution => Color::srgb(1.0, 0.7, 0.0), // Amber caution
+                    gpws::TerrainAlert::Warning => Color::srgb(1.0, 0.0, 0.0), // Red warning
                 };
             }
         }
@@ -154,15 +198,101 @@ fn update_hud(
     }
 }
 
-fn update_pause_visibility(
-    game_state: Res<State<GameState>>,
-    mut pause_query: Query<&mut Visibility, With<PauseText>>,
+/// Radar altimeter only reads within range of the ground
+fn update_agl_readout(
+    mut agl_query: Query<&mut Text, With<AglText>>,
+    proximity: Res<gpws::GroundProximity>,
 ) {
-    if let Ok(mut visibility) = pause_query.get_single_mut() {
-        *visibility = if game_state.get() == &GameState::Paused {
-            Visibility::Visible
-        } else {
-            Visibility::Hidden
-        };
+    let Ok(mut text) = agl_query.get_single_mut() else { return };
+    let mut readout = if proximity.agl < gpws::RADAR_ALTIMETER_RANGE {
+        format!("AGL: {:.0} m", proximity.agl)
+    } else {
+        "AGL: ---".to_string()
+    };
+    if proximity.gcas_active {
+        readout.push_str(" | AUTO-GCAS");
+    }
+    if text.0 != readout {
+        text.0 = readout;
+    }
+}
+
+/// Gear position, flaps and brakes; blank when cleaned up in flight
+fn update_gear_readout(
+    mut gear_query: Query<&mut Text, With<GearText>>,
+    player_query: Query<(&landing_gear::LandingGear, &PlayerInput), With<PlayerPlane>>,
+    time: Res<Time>,
+) {
+    let Ok((gear, input)) = player_query.get_single() else { return };
+    let Ok(mut text) = gear_query.get_single_mut() else { return };
+
+    let mut parts = Vec::new();
+    if gear.locked_down() {
+        parts.push("GEAR DN".to_string());
+    } else if gear.down || gear.extension > 0.0 {
+        parts.push("GEAR TRANSIT".to_string());
+    }
+    if gear.flaps > 0.01 {
+        parts.push(format!("FLAPS {:.0}%", gear.flaps * 100.0));
+    }
+    if input.brake > 0.0 {
+        parts.push(if gear.on_ground { "BRAKES" } else { "SPD BRK" }.to_string());
+    }
+    if let Some(touchdown) = gear.last_touchdown {
+        if time.elapsed_secs() - touchdown.time < TOUCHDOWN_READOUT_TIME {
+            parts.push(format!("TD: {} {:.1} m/s", touchdown.grade.label(), touchdown.sink_rate));
+        }
+    }
+    let readout = parts.join(" | ");
+    if text.0 != readout {
+        text.0 = readout;
+    }
+}
+
+/// Fuel and weapons, then whatever is topping them up (or where the tanker is)
+fn update_fuel_readout(
+    mut fuel_query: Query<&mut Text, With<FuelText>>,
+    player_query: Query<(&resupply::FuelTank, &resupply::Stores), With<PlayerPlane>>,
+    resupply: Res<resupply::Resupply>,
+) {
+    let Ok((tank, stores)) = player_query.get_single() else { return };
+    let Ok(mut text) = fuel_query.get_single_mut() else { return };
+
+    let status = if resupply.servicing {
+        "GROUND CREW".to_string()
+    } else {
+        match resupply.boom {
+            resupply::BoomState::Contact => "BOOM CONTACT".to_string(),
+            resupply::BoomState::PreContact => "PRE-CONTACT".to_string(),
+            resupply::BoomState::Clear => format!("TKR {:.1} km", resupply.tanker_range / 1000.0),
+        }
+    };
+    let readout = format!("FUEL: {:.0} kg | MSL {} | GUN {} | {}", tank.fuel, stores.missiles, stores.rounds, status);
+    if text.0 != readout {
+        text.0 = readout;
+    }
+}
+
+/// Rocket propellant, RCS and heating only matter high up or under rocket power
+fn update_space_readout(
+    mut space_query: Query<&mut Text, With<SpaceText>>,
+    player_query: Query<(&Transform, &space::RocketEngine, &space::ReentryHeat), With<PlayerPlane>>,
+) {
+    let Ok((transform, rocket, heat)) = player_query.get_single() else { return };
+    let Ok(mut text) = space_query.get_single_mut() else { return };
+
+    let altitude = transform.translation.y;
+    let mut readout = String::new();
+    if rocket.enabled || altitude > space::UPPER_ATMOSPHERE {
+        readout = format!("RKT: {:.0}%", rocket.fuel_fraction() * 100.0);
+    }
+    if altitude > space::UPPER_ATMOSPHERE {
+        readout.push_str(" | RCS");
+    }
+    if heat.glow > 0.3 {
+        readout.push_str(" | HEAT");
+    }
+    if text.0 != readout {
+        text.0 = readout;
     }
 }
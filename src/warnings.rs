@@ -0,0 +1,368 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use crate::{
+    audio::MixerSound,
+    camera::CameraRig,
+    drone,
+    engine_audio::EngineSpool,
+    gpws::{GroundProximity, TerrainAlert},
+    landing_gear::LandingGear,
+    resupply::FuelTank,
+    space, GameState, PlayerPlane, WarningSound,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Fuel fraction that counts as bingo (jet fuel, or rocket propellant while it burns)
+const BINGO_FRACTION: f32 = 0.15;
+/// Load factor limits for OVER-G (positive, negative)
+const OVER_G_LIMIT: f32 = 9.0;
+const NEGATIVE_G_LIMIT: f32 = -3.0;
+/// Airspeed below which the wings quit (m/s), and the AGL under which it's a landing instead
+const STALL_SPEED: f32 = 55.0;
+const STALL_MIN_AGL: f32 = 20.0;
+/// Low, slow and descending with the gear not down looks like a gear-up landing
+const GEAR_WARNING_AGL: f32 = 150.0;
+const GEAR_WARNING_SPEED: f32 = 110.0;
+/// A launch stays on the board this long so it can wait out a more urgent callout
+const MISSILE_LAUNCH_LATCH: f32 = 1.0;
+/// Warning loop level on the voice bus
+const WARNING_VOLUME: f32 = 0.8;
+
+// ============================================================================
+// CALLOUTS
+// ============================================================================
+
+/// Cockpit warnings, most important first
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Callout {
+    PullUp,
+    EngineFire,
+    MissileLaunch,
+    OverG,
+    Stall,
+    LandingGear,
+    Altitude,
+    BingoFuel,
+}
+
+impl Callout {
+    const ALL: [Callout; 8] = [
+        Callout::PullUp,
+        Callout::EngineFire,
+        Callout::MissileLaunch,
+        Callout::OverG,
+        Callout::Stall,
+        Callout::LandingGear,
+        Callout::Altitude,
+        Callout::BingoFuel,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Higher preempts lower
+    fn priority(self) -> u8 {
+        (Self::ALL.len() - self.index()) as u8
+    }
+
+    pub fn text(self) -> &'static str {
+        match self {
+            Callout::PullUp => "PULL UP",
+            Callout::EngineFire => "ENGINE FIRE",
+            Callout::MissileLaunch => "MISSILE LAUNCH",
+            Callout::OverG => "OVER-G",
+            Callout::Stall => "STALL",
+            Callout::LandingGear => "LANDING GEAR",
+            Callout::Altitude => "ALTITUDE",
+            Callout::BingoFuel => "BINGO FUEL",
+        }
+    }
+
+    /// Seconds one callout lasts
+    fn duration(self) -> f32 {
+        match self {
+            Callout::PullUp | Callout::EngineFire | Callout::MissileLaunch => 1.2,
+            _ => 1.0,
+        }
+    }
+
+    /// Seconds before the same callout may repeat (counted from its start)
+    fn cooldown(self) -> f32 {
+        match self {
+            Callout::PullUp => 1.5, // Nags nonstop until the nose comes up
+            Callout::EngineFire => 6.0,
+            Callout::MissileLaunch => 2.0,
+            Callout::OverG => 4.0,
+            Callout::Stall => 2.5,
+            Callout::LandingGear => 4.0,
+            Callout::Altitude => 6.0,
+            Callout::BingoFuel => 30.0,
+        }
+    }
+
+    /// Playback speed of the warning loop, so each callout has its own tone
+    fn tone(self) -> f32 {
+        match self {
+            Callout::PullUp => 1.4,
+            Callout::EngineFire => 0.7,
+            Callout::MissileLaunch => 1.8,
+            Callout::OverG => 1.1,
+            Callout::Stall => 0.9,
+            Callout::LandingGear => 1.0,
+            Callout::Altitude => 1.2,
+            Callout::BingoFuel => 0.8,
+        }
+    }
+}
+
+/// Which warning conditions hold this frame and what is being called out
+#[derive(Resource, Default)]
+pub struct VoiceWarnings {
+    active: [bool; Callout::ALL.len()],
+    /// When each callout last started (elapsed seconds)
+    last_played: [Option<f32>; Callout::ALL.len()],
+    /// The callout playing now and its seconds left
+    current: Option<(Callout, f32)>,
+}
+
+impl VoiceWarnings {
+    pub fn is_active(&self, callout: Callout) -> bool {
+        self.active[callout.index()]
+    }
+
+    /// The callout playing right now
+    pub fn current(&self) -> Option<Callout> {
+        self.current.map(|(callout, _)| callout)
+    }
+
+    fn set(&mut self, callout: Callout, active: bool) {
+        self.active[callout.index()] = active;
+    }
+
+    fn ready(&self, callout: Callout, now: f32) -> bool {
+        self.is_active(callout)
+            && self.last_played[callout.index()].is_none_or(|t| now - t >= callout.cooldown())
+    }
+
+    /// Most important callout that is active and off cooldown
+    fn next(&self, now: f32) -> Option<Callout> {
+        Callout::ALL.into_iter().find(|callout| self.ready(*callout, now))
+    }
+
+    /// Let the current callout finish unless something more important is ready,
+    /// then start the most important waiting one
+    fn advance(&mut self, now: f32, dt: f32) {
+        if let Some((callout, remaining)) = self.current {
+            let remaining = remaining - dt;
+            let preempted = self.next(now).is_some_and(|next| next.priority() > callout.priority());
+            self.current = (remaining > 0.0 && !preempted).then_some((callout, remaining));
+        }
+
+        if self.current.is_none() {
+            if let Some(next) = self.next(now) {
+                self.last_played[next.index()] = Some(now);
+                self.current = Some((next, next.duration()));
+            }
+        }
+    }
+}
+
+/// Big callout text in the middle of the HUD
+#[derive(Component)]
+struct CalloutText;
+
+pub struct WarningsPlugin;
+
+impl Plugin for WarningsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<VoiceWarnings>()
+            .add_systems(OnEnter(GameState::Spawning), setup_callout_text)
+            .add_systems(Update, (
+                detect_warnings.after(crate::gpws::update_ground_proximity),
+                run_callouts,
+                play_warning_tone,
+            ).chain().run_if(in_state(GameState::Playing)))
+            .add_systems(Update, update_callout_text);
+    }
+}
+
+fn setup_callout_text(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Percent(30.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+    ))
+    .with_children(|parent| {
+        parent.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 36.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.0, 0.0)), // Warning Red
+            CalloutText,
+        ));
+    });
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// The jet state every warning condition is read from
+type WarningInputsQuery<'w, 's> = Query<'w, 's, (
+    &'static Transform,
+    &'static LinearVelocity,
+    &'static EngineSpool,
+    &'static space::RocketEngine,
+    &'static LandingGear,
+    &'static FuelTank,
+), With<PlayerPlane>>;
+
+/// Evaluate every warning condition for this frame
+fn detect_warnings(
+    time: Res<Time>,
+    rig: Res<CameraRig>,
+    proximity: Res<GroundProximity>,
+    mut warnings: ResMut<VoiceWarnings>,
+    player_query: WarningInputsQuery,
+    new_missiles: Query<(), Added<drone::Missile>>,
+    mut launch_latch: Local<f32>,
+) {
+    let Ok((transform, velocity, spool, rocket, gear, tank)) = player_query.get_single() else { return };
+    let speed = velocity.length();
+
+    // Every drone missile is fired at the player
+    *launch_latch = if new_missiles.is_empty() { (*launch_latch - time.delta_secs()).max(0.0) } else { MISSILE_LAUNCH_LATCH };
+
+    let air = space::atmosphere_factor(transform.translation.y);
+    // Terrain callouts come from the ground proximity computer (gpws.rs)
+    warnings.set(Callout::PullUp, proximity.alert == TerrainAlert::Warning);
+    warnings.set(Callout::EngineFire, spool.fire > 0.0);
+    warnings.set(Callout::MissileLaunch, *launch_latch > 0.0);
+    warnings.set(Callout::OverG, rig.g_load > OVER_G_LIMIT || rig.g_load < NEGATIVE_G_LIMIT);
+    warnings.set(Callout::Stall, speed < STALL_SPEED && proximity.agl > STALL_MIN_AGL && air > 0.5);
+    warnings.set(Callout::LandingGear, !gear.locked_down() && !gear.on_ground
+        && proximity.agl < GEAR_WARNING_AGL && speed < GEAR_WARNING_SPEED && velocity.y < 0.0);
+    warnings.set(Callout::Altitude, proximity.alert == TerrainAlert::Caution);
+    warnings.set(Callout::BingoFuel, tank.fraction() < BINGO_FRACTION
+        || (rocket.enabled && rocket.fuel_fraction() < BINGO_FRACTION));
+}
+
+fn run_callouts(time: Res<Time>, mut warnings: ResMut<VoiceWarnings>) {
+    warnings.advance(time.elapsed_secs(), time.delta_secs());
+}
+
+/// Drive the cockpit warning loop with the current callout's tone
+fn play_warning_tone(
+    warnings: Res<VoiceWarnings>,
+    mut warning_query: Query<(&mut MixerSound, Option<&AudioSink>), With<WarningSound>>,
+) {
+    for (mut sound, sink) in &mut warning_query {
+        sound.playing = warnings.current.is_some();
+        sound.volume = WARNING_VOLUME;
+        if let (Some(callout), Some(sink)) = (warnings.current(), sink) {
+            sink.set_speed(callout.tone());
+        }
+    }
+}
+
+/// Show the callout being spoken (hidden in menus and replays)
+fn update_callout_text(
+    state: Res<State<GameState>>,
+    warnings: Res<VoiceWarnings>,
+    mut text_query: Query<&mut Text, With<CalloutText>>,
+) {
+    let Ok(mut text) = text_query.get_single_mut() else { return };
+    let wanted = match warnings.current() {
+        Some(callout) if *state.get() == GameState::Playing => callout.text(),
+        _ => "",
+    };
+    if text.0 != wanted {
+        text.0 = wanted.to_string();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DT: f32 = 0.1;
+
+    /// Step the callout queue from `from` to `to` seconds, a frame at a time
+    fn run(warnings: &mut VoiceWarnings, from: f32, to: f32) -> f32 {
+        let mut now = from;
+        while now < to - 1e-4 {
+            now += DT;
+            warnings.advance(now, DT);
+        }
+        now
+    }
+
+    #[test]
+    fn most_important_callout_goes_first() {
+        let mut warnings = VoiceWarnings::default();
+        warnings.set(Callout::Altitude, true);
+        warnings.set(Callout::PullUp, true);
+        warnings.advance(0.0, DT);
+        assert_eq!(warnings.current(), Some(Callout::PullUp));
+    }
+
+    #[test]
+    fn more_urgent_callout_preempts() {
+        let mut warnings = VoiceWarnings::default();
+        warnings.set(Callout::BingoFuel, true);
+        warnings.advance(0.0, DT);
+        assert_eq!(warnings.current(), Some(Callout::BingoFuel));
+
+        warnings.set(Callout::MissileLaunch, true);
+        run(&mut warnings, 0.0, 0.2);
+        assert_eq!(warnings.current(), Some(Callout::MissileLaunch));
+    }
+
+    #[test]
+    fn less_urgent_callout_waits_its_turn() {
+        let mut warnings = VoiceWarnings::default();
+        warnings.set(Callout::EngineFire, true);
+        warnings.advance(0.0, DT);
+        warnings.set(Callout::EngineFire, false);
+        warnings.set(Callout::Stall, true);
+
+        let now = run(&mut warnings, 0.0, Callout::EngineFire.duration() - 2.0 * DT);
+        assert_eq!(warnings.current(), Some(Callout::EngineFire));
+        run(&mut warnings, now, Callout::EngineFire.duration() + DT);
+        assert_eq!(warnings.current(), Some(Callout::Stall));
+    }
+
+    #[test]
+    fn callout_repeats_only_after_its_cooldown() {
+        let mut warnings = VoiceWarnings::default();
+        warnings.set(Callout::OverG, true);
+        warnings.advance(0.0, DT);
+        let cooldown = Callout::OverG.cooldown();
+
+        let now = run(&mut warnings, 0.0, Callout::OverG.duration() + DT);
+        assert_eq!(warnings.current(), None, "finished, still cooling down");
+        let now = run(&mut warnings, now, cooldown - DT);
+        assert_eq!(warnings.current(), None);
+        run(&mut warnings, now, cooldown + DT);
+        assert_eq!(warnings.current(), Some(Callout::OverG));
+    }
+
+    #[test]
+    fn cleared_condition_is_not_called_out() {
+        let mut warnings = VoiceWarnings::default();
+        warnings.set(Callout::Stall, true);
+        warnings.set(Callout::Stall, false);
+        warnings.advance(0.0, DT);
+        assert_eq!(warnings.current(), None);
+    }
+}
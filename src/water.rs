@@ -0,0 +1,310 @@
+use avian3d::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
+use noise::{NoiseFn, Perlin};
+use rand::Rng;
+use crate::{
+    airfield::{MissionStart, PlayerSpawn},
+    assets::GameAssets,
+    audio::{AudioBus, PlaySound},
+    biome::smoothstep,
+    landing_gear::LandingGear,
+    origin::WorldOrigin,
+    GameState, PlayerPlane, world_active, CHUNK_SIZE,
+};
+
+// ============================================================================
+// WATER LEVELS
+// ============================================================================
+
+/// Global water table. Everything below floods: deep canyon basins become lakes,
+/// low-lying flats become coastline, and carved river channels fill from here.
+pub const SEA_LEVEL: f32 = -40.0;
+
+/// Faster than this and the water is as hard as concrete
+const DITCH_MAX_SPEED: f32 = 90.0;
+/// Vertical m/s beyond which a water landing is a crash
+const DITCH_MAX_SINK_RATE: f32 = 15.0;
+
+/// River channels are carved this far below sea level so they always hold water
+const RIVER_DEPTH: f32 = 15.0;
+/// Half-width of the river channel in noise units (~45m at the river scale)
+const RIVER_HALF_WIDTH: f32 = 0.015;
+/// Width of the sloped bank outside the channel (noise units)
+const RIVER_BANK: f32 = 0.02;
+const RIVER_SEED: u32 = 4444;
+
+/// Lower the terrain into a river channel along the zero-contour of the river noise.
+/// `mask` fades rivers out (0.0) where the biome shouldn't have them.
+pub fn carve_river(world_x: f64, world_z: f64, height: f32, mask: f32) -> f32 {
+    if mask <= 0.0 {
+        return height;
+    }
+    let perlin = Perlin::new(RIVER_SEED);
+    let n = (perlin.get([world_x * 0.00035, world_z * 0.00035]) as f32).abs();
+    // 1.0 inside the channel, easing to 0.0 across the bank
+    let channel = 1.0 - smoothstep(RIVER_HALF_WIDTH, RIVER_HALF_WIDTH + RIVER_BANK, n);
+    let bed = SEA_LEVEL - RIVER_DEPTH;
+    // Only ever dig down, never raise terrain that is already below the bed
+    height + (bed - height).min(0.0) * channel * mask
+}
+
+/// Water surface height at a position, if that spot is under water
+pub fn water_surface_at(world_x: f64, world_z: f64) -> Option<f32> {
+    (crate::get_terrain_height(world_x, world_z) < SEA_LEVEL).then_some(SEA_LEVEL)
+}
+
+// ============================================================================
+// COMPONENTS & RESOURCES
+// ============================================================================
+
+/// Marker for per-chunk water surfaces
+#[derive(Component)]
+pub struct Water;
+
+/// A single spray droplet thrown up by a splash (falls back under gravity)
+#[derive(Component)]
+struct SplashDroplet {
+    velocity: Vec3,
+    lifetime: f32,
+}
+
+/// Expanding foam ring left on the surface after an impact
+#[derive(Component)]
+struct SplashRing {
+    age: f32,
+    max_age: f32,
+    max_radius: f32,
+}
+
+/// Shared water/splash assets so chunks and effects don't allocate per spawn
+#[derive(Resource)]
+pub struct WaterAssets {
+    pub surface_mesh: Handle<Mesh>,
+    pub surface_material: Handle<StandardMaterial>,
+    droplet_mesh: Handle<Mesh>,
+    droplet_material: Handle<StandardMaterial>,
+    ring_mesh: Handle<Mesh>,
+    ring_material: Handle<StandardMaterial>,
+}
+
+/// Commands plus the shared splash assets, for systems that throw up spray
+#[derive(SystemParam)]
+struct Splashes<'w, 's> {
+    commands: Commands<'w, 's>,
+    assets: Res<'w, WaterAssets>,
+}
+
+impl Splashes<'_, '_> {
+    fn spawn(&mut self, position: Vec3, intensity: f32) {
+        spawn_splash(&mut self.commands, &self.assets, position, intensity);
+    }
+}
+
+pub struct WaterPlugin;
+
+impl Plugin for WaterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Spawning), setup_water_assets)
+            // Between the safety resets and the land contact, which leaves water to us
+            .add_systems(PreUpdate, water_impact
+                .after(crate::check_ground_collision)
+                .before(crate::landing_gear::wheel_contact)
+                .run_if(in_state(GameState::Playing)))
+            .add_systems(Update, (
+                update_splash_droplets,
+                update_splash_rings,
+            ).run_if(world_active));
+    }
+}
+
+fn setup_water_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Glossy, slightly translucent surface: reflections come from the camera's environment map
+    let surface_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(0.05, 0.22, 0.32, 0.85),
+        perceptual_roughness: 0.04,
+        metallic: 0.0,
+        reflectance: 1.0,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+
+    commands.insert_resource(WaterAssets {
+        surface_mesh: meshes.add(Plane3d::default().mesh().size(CHUNK_SIZE, CHUNK_SIZE)),
+        surface_material,
+        droplet_mesh: meshes.add(Sphere::new(0.6)),
+        droplet_material: materials.add(StandardMaterial {
+            base_color: Color::srgba(0.9, 0.95, 1.0, 0.8),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        }),
+        ring_mesh: meshes.add(Annulus::new(0.8, 1.0)),
+        ring_material: materials.add(StandardMaterial {
+            base_color: Color::srgba(1.0, 1.0, 1.0, 0.6),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            double_sided: true,
+            cull_mode: None,
+            ..default()
+        }),
+    });
+    eprintln!("🌊 STARTUP: Water assets created (sea level {:.0} m)", SEA_LEVEL);
+}
+
+/// Add a water surface to a chunk whose terrain dips below sea level.
+/// `min_height` is the lowest terrain sample in the chunk.
+pub fn spawn_water_in_chunk(
+    commands: &mut Commands,
+    water: &WaterAssets,
+    chunk_entity: Entity,
+    min_height: f32,
+) {
+    if min_height >= SEA_LEVEL {
+        return;
+    }
+
+    commands.entity(chunk_entity).with_children(|parent| {
+        parent.spawn((
+            Water,
+            crate::ChunkEntity,
+            Mesh3d(water.surface_mesh.clone()),
+            MeshMaterial3d(water.surface_material.clone()),
+            Transform::from_xyz(0.0, SEA_LEVEL, 0.0),
+            GlobalTransform::default(),
+            Visibility::default(),
+            InheritedVisibility::default(),
+        ));
+    });
+}
+
+/// Spawn a splash at the water surface. `intensity` ~1.0 for a bullet, ~10+ for an aircraft.
+pub fn spawn_splash(
+    commands: &mut Commands,
+    water: &WaterAssets,
+    position: Vec3,
+    intensity: f32,
+) {
+    let mut rng = rand::thread_rng();
+    let surface = Vec3::new(position.x, SEA_LEVEL, position.z);
+    let droplet_count = (6.0 * intensity).clamp(4.0, 120.0) as usize;
+
+    for _ in 0..droplet_count {
+        let spread = rng.gen_range(0.0..std::f32::consts::TAU);
+        let outward = rng.gen_range(2.0..8.0) * intensity.sqrt();
+        let velocity = Vec3::new(
+            spread.cos() * outward,
+            rng.gen_range(10.0..25.0) * intensity.sqrt(),
+            spread.sin() * outward,
+        );
+
+        commands.spawn((
+            SplashDroplet { velocity, lifetime: rng.gen_range(1.0..2.5) },
+            Mesh3d(water.droplet_mesh.clone()),
+            MeshMaterial3d(water.droplet_material.clone()),
+            Transform::from_translation(surface).with_scale(Vec3::splat(intensity.sqrt().max(0.5))),
+            GlobalTransform::default(),
+            Visibility::default(),
+            InheritedVisibility::default(),
+        ));
+    }
+
+    // Foam ring lies flat on the surface (Annulus is built in the XY plane)
+    commands.spawn((
+        SplashRing { age: 0.0, max_age: 3.0, max_radius: 8.0 * intensity.sqrt() },
+        Mesh3d(water.ring_mesh.clone()),
+        MeshMaterial3d(water.ring_material.clone()),
+        Transform::from_translation(surface + Vec3::Y * 0.2)
+            .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+            .with_scale(Vec3::splat(0.1)),
+        GlobalTransform::default(),
+        Visibility::default(),
+        InheritedVisibility::default(),
+    ));
+}
+
+/// Over a lake/river the surface is the water, not the bed below it: skim and
+/// slow down when ditching gently, reset like a ground crash when coming in hard
+fn water_impact(
+    mut player_query: Query<(&mut Transform, &mut LinearVelocity, &mut AngularVelocity, &mut LandingGear), With<PlayerPlane>>,
+    mut splashes: Splashes,
+    sounds: Res<GameAssets>,
+    mut play_sound: EventWriter<PlaySound>,
+    origin: Res<WorldOrigin>,
+    mission_start: Res<MissionStart>,
+    mut was_on_water: Local<bool>,
+) {
+    let Ok((mut transform, mut velocity, mut ang_vel, mut gear)) = player_query.get_single_mut() else { return };
+    if !transform.translation.is_finite() {
+        return;
+    }
+
+    let absolute = origin.to_absolute(transform.translation);
+    let Some(surface) = water_surface_at(absolute.x, absolute.z)
+        .filter(|surface| transform.translation.y <= surface + 2.0)
+    else {
+        *was_on_water = false;
+        return;
+    };
+
+    let impact_speed = velocity.length();
+    let sink_rate = -velocity.0.y;
+
+    if impact_speed > DITCH_MAX_SPEED || sink_rate > DITCH_MAX_SINK_RATE {
+        println!("🌊 WATER IMPACT! Speed: {:.0} m/s, sink {:.0} m/s", impact_speed, sink_rate);
+        splashes.spawn(transform.translation, 20.0);
+        play_sound.send(PlaySound::new(sounds.crash.clone(), AudioBus::Weapons));
+
+        // Same full reset as a ground crash
+        PlayerSpawn::new(*mission_start).place(&origin, &mut transform, &mut velocity, &mut ang_vel, &mut gear);
+    } else {
+        // Ditching: skim on the surface while the water bleeds off speed
+        if !*was_on_water {
+            println!("🌊 DITCHED at {:.0} m/s", impact_speed);
+            splashes.spawn(transform.translation, 8.0);
+        }
+        transform.translation.y = surface + 2.0;
+        velocity.0.y = velocity.0.y.max(0.0);
+        velocity.0 *= 0.98; // Heavy water drag
+    }
+    *was_on_water = true;
+}
+
+fn update_splash_droplets(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Transform, &mut SplashDroplet)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut transform, mut droplet) in &mut query {
+        droplet.velocity.y -= 9.81 * dt;
+        transform.translation += droplet.velocity * dt;
+        droplet.lifetime -= dt;
+
+        // Gone once it falls back into the water or times out
+        if droplet.lifetime <= 0.0 || transform.translation.y < SEA_LEVEL - 1.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn update_splash_rings(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Transform, &mut SplashRing)>,
+) {
+    for (entity, mut transform, mut ring) in &mut query {
+        ring.age += time.delta_secs();
+        if ring.age >= ring.max_age {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        // Ease-out expansion
+        let t = ring.age / ring.max_age;
+        let radius = ring.max_radius * (1.0 - (1.0 - t).powi(3));
+        transform.scale = Vec3::splat(radius.max(0.1));
+    }
+}
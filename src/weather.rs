@@ -0,0 +1,258 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use rand::Rng;
+use crate::{
+    biome::smoothstep,
+    controls::{Action, ActionState},
+    origin::WorldOrigin,
+    particles::{EmitParticles, ParticleType},
+    world_active, PlayerPlane,
+};
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// Peak gust speed (m/s) at full turbulence, doubled inside the cloud layer
+const GUST_STRENGTH: f32 = 8.0;
+/// Raindrops per second around the camera at full precipitation
+const RAIN_RATE: f32 = 600.0;
+const RAIN_FALL_SPEED: f32 = 12.0;
+/// How quickly the weather blends to a newly selected preset (fraction per second)
+const WEATHER_BLEND_RATE: f32 = 0.15;
+
+// ============================================================================
+// RESOURCES & COMPONENTS
+// ============================================================================
+
+/// Named weather setups; `F6` cycles through them in flight
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeatherPreset {
+    Clear,
+    Scattered,
+    Overcast,
+    Storm,
+}
+
+impl WeatherPreset {
+    const ALL: [WeatherPreset; 4] = [
+        WeatherPreset::Clear,
+        WeatherPreset::Scattered,
+        WeatherPreset::Overcast,
+        WeatherPreset::Storm,
+    ];
+}
+
+/// Current atmospheric conditions. Missions insert their own; everything that
+/// cares about clouds, rain, fog or wind reads it from here.
+#[derive(Resource, Clone, Debug)]
+pub struct Weather {
+    /// Altitude of the cloud layer's bottom (meters)
+    pub cloud_base: f32,
+    /// Depth of the cloud layer (meters)
+    pub cloud_thickness: f32,
+    /// Fraction of the sky covered by clouds (0..1)
+    pub coverage: f32,
+    /// Rain intensity below the cloud base (0..1)
+    pub precipitation: f32,
+    /// Distance at which fog fully hides the world (meters)
+    pub visibility: f32,
+    /// Mean wind near the ground (m/s, world space)
+    pub wind: Vec3,
+    /// Gustiness (0..1)
+    pub turbulence: f32,
+}
+
+impl Default for Weather {
+    fn default() -> Self {
+        Self::preset(WeatherPreset::Scattered)
+    }
+}
+
+impl Weather {
+    pub fn preset(preset: WeatherPreset) -> Self {
+        match preset {
+            WeatherPreset::Clear => Self {
+                cloud_base: 1500.0,
+                cloud_thickness: 400.0,
+                coverage: 0.15,
+                precipitation: 0.0,
+                visibility: 14000.0,
+                wind: Vec3::new(4.0, 0.0, 2.0),
+                turbulence: 0.05,
+            },
+            WeatherPreset::Scattered => Self {
+                cloud_base: 800.0,
+                cloud_thickness: 700.0,
+                coverage: 0.45,
+                precipitation: 0.0,
+                visibility: 12000.0,
+                wind: Vec3::new(8.0, 0.0, 3.0),
+                turbulence: 0.2,
+            },
+            WeatherPreset::Overcast => Self {
+                cloud_base: 700.0,
+                cloud_thickness: 800.0,
+                coverage: 0.85,
+                precipitation: 0.3,
+                visibility: 7000.0,
+                wind: Vec3::new(12.0, 0.0, -4.0),
+                turbulence: 0.4,
+            },
+            WeatherPreset::Storm => Self {
+                cloud_base: 500.0,
+                cloud_thickness: 1200.0,
+                coverage: 1.0,
+                precipitation: 1.0,
+                visibility: 3500.0,
+                wind: Vec3::new(20.0, 0.0, -8.0),
+                turbulence: 0.9,
+            },
+        }
+    }
+
+    /// Blend every field toward `target` by `t` (0..1)
+    fn lerp(&self, target: &Weather, t: f32) -> Weather {
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        Weather {
+            cloud_base: mix(self.cloud_base, target.cloud_base),
+            cloud_thickness: mix(self.cloud_thickness, target.cloud_thickness),
+            coverage: mix(self.coverage, target.coverage),
+            precipitation: mix(self.precipitation, target.precipitation),
+            visibility: mix(self.visibility, target.visibility),
+            wind: self.wind.lerp(target.wind, t),
+            turbulence: mix(self.turbulence, target.turbulence),
+        }
+    }
+
+    /// How deep into the cloud layer an altitude is, weighted by coverage (0 = clear air)
+    pub fn cloud_density_at(&self, altitude: f32) -> f32 {
+        let top = self.cloud_base + self.cloud_thickness;
+        let inside = smoothstep(self.cloud_base - 50.0, self.cloud_base + 50.0, altitude)
+            * (1.0 - smoothstep(top - 50.0, top + 50.0, altitude));
+        inside * self.coverage
+    }
+
+    /// Wind including gusts at a point. Mean wind grows with height up to twice
+    /// its surface value at 3 km.
+    pub fn wind_at(&self, position: Vec3, time: f32) -> Vec3 {
+        let shear = 1.0 + (position.y.max(0.0) / 3000.0).min(1.0);
+        self.wind * shear + self.gust_at(position, time)
+    }
+
+    /// Zero-mean turbulent part of the wind. Smooth in space and time so aircraft
+    /// feel bumps rather than jitter; rougher inside clouds.
+    pub fn gust_at(&self, position: Vec3, time: f32) -> Vec3 {
+        let strength = self.turbulence * GUST_STRENGTH * (1.0 + self.cloud_density_at(position.y));
+        if strength <= 0.0 {
+            return Vec3::ZERO;
+        }
+        let p = position * 0.01;
+        Vec3::new(
+            (p.z + time * 1.3).sin() * (p.y * 0.5 + time * 0.7).cos(),
+            (p.x * 0.8 + time * 1.9).sin() * 0.6,
+            (p.x + time * 1.1).cos() * (p.z * 0.7 - time * 0.9).sin(),
+        ) * strength
+    }
+}
+
+/// Preset the weather is blending toward (set by `F6`)
+#[derive(Resource, Default)]
+struct WeatherTarget(Option<Weather>);
+
+pub struct WeatherPlugin;
+
+impl Plugin for WeatherPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Weather>()
+            .init_resource::<WeatherTarget>()
+            .add_systems(Update, (
+                weather_controls,
+                blend_weather,
+                emit_precipitation,
+            ).chain().run_if(world_active));
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// F6 (by default) cycles the weather preset; conditions blend over the next several seconds
+fn weather_controls(
+    actions: Res<ActionState>,
+    mut target: ResMut<WeatherTarget>,
+    mut presses: Local<usize>,
+) {
+    if actions.just_pressed(Action::CycleWeather) {
+        // Steps Scattered (the default) -> Overcast -> Storm -> Clear -> ...
+        *presses += 1;
+        let preset = WeatherPreset::ALL[(*presses + 1) % WeatherPreset::ALL.len()];
+        target.0 = Some(Weather::preset(preset));
+        println!("🌦️  Weather changing to {:?}", preset);
+    }
+}
+
+fn blend_weather(
+    time: Res<Time>,
+    mut weather: ResMut<Weather>,
+    mut target: ResMut<WeatherTarget>,
+) {
+    let Some(goal) = target.0.as_ref() else { return };
+    let t = (WEATHER_BLEND_RATE * time.delta_secs()).min(1.0);
+    let next = weather.lerp(goal, t);
+
+    // Snap once close enough so change detection settles
+    if (next.coverage - goal.coverage).abs() < 0.005
+        && (next.visibility - goal.visibility).abs() < 10.0
+        && (next.cloud_base - goal.cloud_base).abs() < 1.0
+    {
+        *weather = goal.clone();
+        target.0 = None;
+    } else {
+        *weather = next;
+    }
+}
+
+/// Rain streaks in a box around (and ahead of) the camera while below the cloud base
+fn emit_precipitation(
+    time: Res<Time>,
+    weather: Res<Weather>,
+    origin: Res<WorldOrigin>,
+    camera_query: Query<&GlobalTransform, With<Camera3d>>,
+    player_query: Query<&LinearVelocity, With<PlayerPlane>>,
+    mut particles: EventWriter<EmitParticles>,
+    mut accumulator: Local<f32>,
+) {
+    if weather.precipitation <= 0.01 {
+        *accumulator = 0.0;
+        return;
+    }
+    let Ok(camera) = camera_query.get_single() else { return };
+    let camera_pos = camera.translation();
+    if camera_pos.y > weather.cloud_base {
+        return;
+    }
+
+    *accumulator += weather.precipitation * RAIN_RATE * time.delta_secs();
+    let count = accumulator.floor();
+    *accumulator -= count;
+
+    // Lead the box by the aircraft's motion so drops are waiting where the camera will be
+    let lead = player_query.get_single().map(|v| v.0 * 0.5).unwrap_or(Vec3::ZERO);
+    // Gusts at the absolute position, so they don't jump when the origin shifts
+    let velocity = weather.wind_at(origin.to_absolute(camera_pos).as_vec3(), time.elapsed_secs()) + Vec3::NEG_Y * RAIN_FALL_SPEED;
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..count as u32 {
+        let offset = Vec3::new(
+            rng.gen_range(-80.0..80.0),
+            rng.gen_range(-10.0..40.0),
+            rng.gen_range(-80.0..80.0),
+        );
+        particles.send(
+            EmitParticles::new(ParticleType::Rain, camera_pos + lead + offset)
+                .with_velocity(velocity, 0.5),
+        );
+    }
+}